@@ -0,0 +1,122 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use profile::prelude::*;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+const ACCOUNT_COUNT: u32 = 2_000;
+const PERSONA_COUNT: u32 = 500;
+
+/// A mainnet Profile with thousands of entities all created with the
+/// placeholder device factor source.
+fn large_profile() -> Profile {
+    let private = PrivateHierarchicalDeterministicFactorSource::placeholder();
+    let accounts = private
+        .derive_entity_creation_factor_instances(
+            NetworkID::Mainnet,
+            0..ACCOUNT_COUNT,
+        )
+        .into_iter()
+        .enumerate()
+        .map(|(i, instance)| {
+            Account::new(
+                instance,
+                DisplayName::new(&format!("Account {}", i)).unwrap(),
+                AppearanceID::from_number_of_accounts_on_network(i),
+            )
+        });
+    let personas = private
+        .derive_entity_creation_factor_instances(
+            NetworkID::Mainnet,
+            0..PERSONA_COUNT,
+        )
+        .into_iter()
+        .enumerate()
+        .map(|(i, instance)| {
+            Persona::new(
+                instance,
+                DisplayName::new(&format!("Persona {}", i)).unwrap(),
+                PersonaData::default(),
+            )
+        });
+
+    let mut profile = Profile::new(private, "Bench");
+    profile.networks = ProfileNetworks::with_network(ProfileNetwork::new(
+        NetworkID::Mainnet,
+        Accounts::from_iter(accounts),
+        Personas::from_iter(personas),
+        AuthorizedDapps::default(),
+    ));
+    profile
+}
+
+/// Saves into memory, so that updating the Wallet does not measure I/O.
+#[derive(Debug, Default)]
+struct InMemoryStorage {
+    storage: RwLock<HashMap<SecureStorageKey, Vec<u8>>>,
+}
+
+impl SecureStorage for InMemoryStorage {
+    fn load_data(&self, key: SecureStorageKey) -> Result<Option<Vec<u8>>> {
+        Ok(self.storage.read().unwrap().get(&key).cloned())
+    }
+
+    fn save_data(&self, key: SecureStorageKey, data: Vec<u8>) -> Result<()> {
+        self.storage.write().unwrap().insert(key, data);
+        Ok(())
+    }
+
+    fn delete_data_for_key(&self, key: SecureStorageKey) -> Result<()> {
+        self.storage.write().unwrap().remove(&key);
+        Ok(())
+    }
+}
+
+fn index(c: &mut Criterion) {
+    let profile = large_profile();
+    c.bench_function("index large Profile", |b| {
+        b.iter(|| black_box(profile.index()))
+    });
+
+    let index = profile.index();
+    let network = &profile.networks[0];
+    c.bench_function("look up every entity of large Profile", |b| {
+        b.iter(|| {
+            for account in network.accounts.iter() {
+                black_box(index.account(&account.address));
+            }
+            for persona in network.personas.iter() {
+                black_box(index.persona(&persona.address));
+            }
+        })
+    });
+}
+
+/// Every update of Profile by the Wallet rebuilds the whole index, i.e. is
+/// O(n) in the number of entities, this measures a single update including
+/// the rebuild, but excluding saving.
+fn update(c: &mut Criterion) {
+    let profile = large_profile();
+    let address = profile.networks[0].accounts[0].address.clone();
+    let wallet = Wallet::by_importing_profile(
+        profile,
+        Arc::new(InMemoryStorage::default()),
+        ProfileImportPolicy::Accept,
+    )
+    .unwrap();
+    wallet
+        .set_persistence_policy(ProfilePersistencePolicy::Explicit)
+        .unwrap();
+    let name = DisplayName::new("Renamed").unwrap();
+    c.bench_function("rename account of large Profile", |b| {
+        b.iter(|| {
+            wallet
+                .change_name_of_account(address.clone(), name.clone())
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, index, update);
+criterion_main!(benches);
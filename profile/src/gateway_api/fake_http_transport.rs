@@ -0,0 +1,65 @@
+#![cfg(test)]
+use crate::prelude::*;
+
+use std::sync::RwLock;
+
+/// Used for testing - a transport which responds with canned responses per
+/// URL path and records all requests.
+#[derive(Debug)]
+pub struct FakeHttpTransport {
+    pub responses: RwLock<HashMap<String, Vec<HttpResponse>>>,
+    pub requests: RwLock<Vec<HttpRequest>>,
+}
+
+impl FakeHttpTransport {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            responses: RwLock::new(HashMap::new()),
+            requests: RwLock::new(Vec::new()),
+        })
+    }
+
+    /// Enqueues a response for requests to `path`, responses to the same
+    /// path are returned in the order they were enqueued.
+    pub fn respond(
+        &self,
+        path: &str,
+        status_code: u16,
+        json: serde_json::Value,
+    ) {
+        self.responses
+            .write()
+            .unwrap()
+            .entry(path.to_owned())
+            .or_default()
+            .push(HttpResponse {
+                status_code,
+                body: serde_json::to_vec(&json).unwrap(),
+            });
+    }
+
+    /// The JSON bodies of all requests made, in order.
+    pub fn request_bodies(&self) -> Vec<serde_json::Value> {
+        self.requests
+            .read()
+            .unwrap()
+            .iter()
+            .map(|r| serde_json::from_slice(&r.body).unwrap())
+            .collect()
+    }
+}
+
+impl HttpTransport for FakeHttpTransport {
+    fn execute_request(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let path = request.url.path().to_owned();
+        self.requests.write().unwrap().push(request);
+        let mut responses = self.responses.write().unwrap();
+        match responses.get_mut(&path) {
+            Some(queue) if !queue.is_empty() => Ok(queue.remove(0)),
+            _ => Ok(HttpResponse {
+                status_code: 404,
+                body: Vec::new(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,607 @@
+use crate::prelude::*;
+
+/// A client of the Gateway API of `gateway`, executing requests using the
+/// `HttpTransport` of the Wallet Client.
+#[derive(Debug, uniffi::Object)]
+pub struct GatewayClient {
+    transport: Arc<dyn HttpTransport>,
+    pub gateway: Gateway,
+}
+
+//========
+// CONSTRUCTOR
+//========
+#[uniffi::export]
+impl GatewayClient {
+    #[uniffi::constructor]
+    pub fn new(transport: Arc<dyn HttpTransport>, gateway: Gateway) -> Self {
+        Self { transport, gateway }
+    }
+}
+
+//========
+// GET - Gateway API
+//========
+#[uniffi::export]
+impl GatewayClient {
+    /// The status of the Gateway, fails if the Gateway is not on the network
+    /// of `gateway`.
+    pub fn gateway_status(&self) -> Result<GatewayStatus> {
+        let status: GatewayStatus =
+            self.post("status/gateway-status", &json!({}))?;
+        self.validate_ledger_state(&status.ledger_state)?;
+        Ok(status)
+    }
+
+    /// The details of the accounts at `addresses`, with the first page of
+    /// their balances, fails without sending any request if an address is
+    /// not on the network of `gateway`.
+    pub fn entity_details(
+        &self,
+        addresses: Vec<AccountAddress>,
+    ) -> Result<Vec<EntityDetails>> {
+        addresses
+            .iter()
+            .try_for_each(|a| self.validate_network(a.network_id))?;
+        let response: EntityDetailsResponse = self.post(
+            "state/entity/details",
+            &EntityDetailsRequest { addresses },
+        )?;
+        self.validate_ledger_state(&response.ledger_state)?;
+        Ok(response
+            .items
+            .into_iter()
+            .map(EntityDetails::from)
+            .collect())
+    }
+
+    /// All fungible balances of the account at `address`, following all
+    /// pages.
+    pub fn fungible_balances(
+        &self,
+        address: AccountAddress,
+    ) -> Result<Vec<FungibleResourceBalance>> {
+        self.all_pages("state/entity/page/fungibles/", address)
+    }
+
+    /// All non fungible balances of the account at `address`, following
+    /// all pages.
+    pub fn non_fungible_balances(
+        &self,
+        address: AccountAddress,
+    ) -> Result<Vec<NonFungibleResourceBalance>> {
+        self.all_pages("state/entity/page/non-fungibles/", address)
+    }
+
+    /// The current epoch, used to construct the header of a transaction, see
+    /// [`TransactionHeader::new`].
+    pub fn current_epoch(&self) -> Result<u64> {
+        let response: LedgerStateResponse =
+            self.post("transaction/construction", &json!({}))?;
+        self.validate_ledger_state(&response.ledger_state)?;
+        Ok(response.ledger_state.epoch)
+    }
+
+    /// Submits the notarized transaction to the network.
+    pub fn submit_transaction(
+        &self,
+        notarized_transaction: NotarizedTransaction,
+    ) -> Result<TransactionSubmitResponse> {
+        self.validate_network(notarized_transaction.intent.network_id())?;
+        self.post(
+            "transaction/submit",
+            &TransactionSubmitRequest {
+                notarized_transaction_hex: notarized_transaction
+                    .compiled
+                    .to_hex(),
+            },
+        )
+    }
+
+    /// The status of the transaction identified by `intent_hash`.
+    pub fn transaction_status(
+        &self,
+        intent_hash: IntentHash,
+    ) -> Result<TransactionStatusResponse> {
+        self.validate_network(intent_hash.network_id)?;
+        self.post(
+            "transaction/status",
+            &TransactionStatusRequest {
+                intent_hash: intent_hash.bech32_encoded_tx_id,
+            },
+        )
+    }
+}
+
+impl GatewayClient {
+    fn url(&self, path: &str) -> Result<Url> {
+        let url = format!(
+            "{}/{}",
+            self.gateway.url.as_str().trim_end_matches('/'),
+            path
+        );
+        Url::parse(&url).map_err(|_| CommonError::InvalidURL(url))
+    }
+
+    /// POSTs `request` as JSON to `path` and deserializes the JSON response,
+    /// any status code other than 200 is an error.
+    fn post<Request, Response>(
+        &self,
+        path: &str,
+        request: &Request,
+    ) -> Result<Response>
+    where
+        Request: Serialize,
+        Response: for<'a> Deserialize<'a>,
+    {
+        let body = serde_json::to_vec(request)
+            .map_err(|_| CommonError::FailedToSerializeToJSON)?;
+        let request = HttpRequest {
+            url: self.url(path)?,
+            method: HttpMethod::Post,
+            headers: HashMap::from([(
+                "Content-Type".to_owned(),
+                "application/json".to_owned(),
+            )]),
+            body,
+        };
+        let response = self.transport.execute_request(request)?;
+
+        if response.status_code != 200 {
+            let message =
+                serde_json::from_slice::<GatewayErrorResponse>(&response.body)
+                    .map(|e| e.message)
+                    .unwrap_or_default();
+            return Err(CommonError::GatewayRequestFailed {
+                status_code: response.status_code,
+                message,
+            });
+        }
+
+        serde_json::from_slice(&response.body).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: response.body.len(),
+                type_name: std::any::type_name::<Response>().to_string(),
+            }
+        })
+    }
+
+    /// The items of all pages of `path` for the account at `address`. All
+    /// pages after the first are requested at the ledger state of the first
+    /// one, so that items cannot be missed or duplicated when the ledger
+    /// changes while paging. Fails if a cursor is returned more than once,
+    /// instead of paging forever, and without sending any request if
+    /// `address` is not on the network of `gateway`.
+    fn all_pages<T>(
+        &self,
+        path: &str,
+        address: AccountAddress,
+    ) -> Result<Vec<T>>
+    where
+        T: for<'a> Deserialize<'a>,
+    {
+        self.validate_network(address.network_id)?;
+        let mut items = Vec::new();
+        let mut cursor = None;
+        let mut at_ledger_state = None;
+        let mut seen_cursors = HashSet::<String>::new();
+        loop {
+            let page: GatewayPage<T> = self.post(
+                path,
+                &EntityPageRequest {
+                    address: address.clone(),
+                    cursor,
+                    at_ledger_state: at_ledger_state.clone(),
+                },
+            )?;
+            if let (None, Some(ledger_state)) =
+                (&at_ledger_state, &page.ledger_state)
+            {
+                self.validate_ledger_state(ledger_state)?;
+                at_ledger_state = Some(LedgerStateSelector {
+                    state_version: ledger_state.state_version,
+                });
+            }
+            items.extend(page.items);
+            cursor = match page.next_cursor {
+                None => return Ok(items),
+                Some(next) if !seen_cursors.insert(next.clone()) => {
+                    return Err(CommonError::GatewayRepeatedPageCursor(next))
+                }
+                next => next,
+            };
+        }
+    }
+
+    fn validate_ledger_state(&self, ledger_state: &LedgerState) -> Result<()> {
+        let expected = &self.gateway.network.logical_name;
+        if &ledger_state.network != expected {
+            return Err(CommonError::GatewayNetworkMismatch {
+                expected: expected.clone(),
+                found: ledger_state.network.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    fn validate_network(&self, network_id: NetworkID) -> Result<()> {
+        let expected = self.gateway.network.id;
+        if network_id != expected {
+            return Err(CommonError::GatewayNetworkMismatch {
+                expected: self.gateway.network.logical_name.clone(),
+                found: network_id.logical_name(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn ledger_state(network: &str) -> serde_json::Value {
+        json!({
+            "network": network,
+            "state_version": 1234,
+            "proposer_round_timestamp": "2024-01-01T00:00:00.000Z",
+            "epoch": 5678,
+            "round": 9
+        })
+    }
+
+    fn notarized() -> NotarizedTransaction {
+        let notary = EphemeralNotary::generate();
+        let mut header = TransactionHeader::placeholder();
+        header.notary_public_key = notary.public_key();
+        let intent = TransactionIntent::new(
+            header,
+            TransactionManifest::set_account_metadata(
+                &AccountAddress::placeholder_mainnet(),
+                "name",
+                MetadataValue::Str {
+                    value: "Savings".to_owned(),
+                },
+            )
+            .unwrap(),
+            Message::None,
+        )
+        .unwrap();
+        notary.notarize(intent, Vec::new()).unwrap()
+    }
+
+    fn sut() -> (GatewayClient, Arc<FakeHttpTransport>) {
+        let transport = FakeHttpTransport::new();
+        (
+            GatewayClient::new(transport.clone(), Gateway::mainnet()),
+            transport,
+        )
+    }
+
+    #[test]
+    fn gateway_status() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/status/gateway-status",
+            200,
+            json!({ "ledger_state": ledger_state("mainnet") }),
+        );
+        let status = sut.gateway_status().unwrap();
+        assert_eq!(status.ledger_state.epoch, 5678);
+
+        let requests = transport.requests.read().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, HttpMethod::Post);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://mainnet.radixdlt.com/status/gateway-status"
+        );
+        assert_eq!(
+            requests[0].headers.get("Content-Type").unwrap(),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn gateway_on_other_network() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/status/gateway-status",
+            200,
+            json!({ "ledger_state": ledger_state("stokenet") }),
+        );
+        assert_eq!(
+            sut.gateway_status(),
+            Err(CommonError::GatewayNetworkMismatch {
+                expected: "mainnet".to_owned(),
+                found: "stokenet".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn entity_details() {
+        let (sut, transport) = sut();
+        let account = AccountAddress::placeholder_mainnet().address;
+        let xrd = ResourceAddress::placeholder_mainnet_xrd().address;
+        let nft = ResourceAddress::placeholder_other().address;
+        transport.respond(
+            "/state/entity/details",
+            200,
+            json!({
+                "ledger_state": ledger_state("mainnet"),
+                "items": [{
+                    "address": account,
+                    "fungible_resources": {
+                        "total_count": 1,
+                        "items": [{
+                            "aggregation_level": "Global",
+                            "resource_address": xrd,
+                            "amount": "1337.5"
+                        }]
+                    },
+                    "non_fungible_resources": {
+                        "total_count": 1,
+                        "items": [{
+                            "aggregation_level": "Global",
+                            "resource_address": nft,
+                            "amount": 3
+                        }]
+                    }
+                }]
+            }),
+        );
+        let details = sut
+            .entity_details(vec![AccountAddress::placeholder_mainnet()])
+            .unwrap();
+        assert_eq!(
+            details,
+            vec![EntityDetails {
+                address: AccountAddress::placeholder_mainnet(),
+                fungible_resources: vec![FungibleResourceBalance {
+                    resource_address: xrd.parse().unwrap(),
+                    amount: Decimal::try_from_str("1337.5").unwrap(),
+                }],
+                non_fungible_resources: vec![NonFungibleResourceBalance {
+                    resource_address: nft.parse().unwrap(),
+                    amount: 3,
+                }],
+            }]
+        );
+        assert_eq!(
+            transport.request_bodies(),
+            vec![json!({ "addresses": [account] })]
+        );
+    }
+
+    #[test]
+    fn fungible_balances_follows_pages() {
+        let (sut, transport) = sut();
+        let account = AccountAddress::placeholder_mainnet().address;
+        let xrd = ResourceAddress::placeholder_mainnet_xrd().address;
+        let other = ResourceAddress::placeholder_other().address;
+        let path = "/state/entity/page/fungibles/";
+        transport.respond(
+            path,
+            200,
+            json!({
+                "ledger_state": ledger_state("mainnet"),
+                "items": [{ "resource_address": xrd, "amount": "1" }],
+                "next_cursor": "page_2"
+            }),
+        );
+        transport.respond(
+            path,
+            200,
+            json!({
+                "ledger_state": ledger_state("mainnet"),
+                "items": [{ "resource_address": other, "amount": "2" }]
+            }),
+        );
+
+        let balances = sut
+            .fungible_balances(AccountAddress::placeholder_mainnet())
+            .unwrap();
+        assert_eq!(
+            balances
+                .into_iter()
+                .map(|b| b.resource_address.address)
+                .collect_vec(),
+            vec![xrd, other]
+        );
+        assert_eq!(
+            transport.request_bodies(),
+            vec![
+                json!({ "address": account }),
+                json!({
+                    "address": account,
+                    "cursor": "page_2",
+                    "at_ledger_state": { "state_version": 1234 }
+                })
+            ]
+        );
+    }
+
+    #[test]
+    fn fungible_balances_fails_on_repeated_cursor() {
+        let (sut, transport) = sut();
+        let path = "/state/entity/page/fungibles/";
+        for _ in 0..3 {
+            transport.respond(
+                path,
+                200,
+                json!({
+                    "ledger_state": ledger_state("mainnet"),
+                    "items": [],
+                    "next_cursor": "page_2"
+                }),
+            );
+        }
+        assert_eq!(
+            sut.fungible_balances(AccountAddress::placeholder_mainnet()),
+            Err(CommonError::GatewayRepeatedPageCursor("page_2".to_owned()))
+        );
+        assert_eq!(transport.request_bodies().len(), 2);
+    }
+
+    #[test]
+    fn fungible_balances_on_other_network() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/state/entity/page/fungibles/",
+            200,
+            json!({
+                "ledger_state": ledger_state("stokenet"),
+                "items": []
+            }),
+        );
+        assert_eq!(
+            sut.fungible_balances(AccountAddress::placeholder_mainnet()),
+            Err(CommonError::GatewayNetworkMismatch {
+                expected: "mainnet".to_owned(),
+                found: "stokenet".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn account_on_other_network() {
+        let (sut, transport) = sut();
+        let mismatch = CommonError::GatewayNetworkMismatch {
+            expected: "mainnet".to_owned(),
+            found: "stokenet".to_owned(),
+        };
+        assert_eq!(
+            sut.entity_details(vec![
+                AccountAddress::placeholder_mainnet(),
+                AccountAddress::placeholder_stokenet(),
+            ]),
+            Err(mismatch.clone())
+        );
+        assert_eq!(
+            sut.fungible_balances(AccountAddress::placeholder_stokenet()),
+            Err(mismatch.clone())
+        );
+        assert_eq!(
+            sut.non_fungible_balances(AccountAddress::placeholder_stokenet()),
+            Err(mismatch)
+        );
+        assert!(transport.requests.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn non_fungible_balances() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/state/entity/page/non-fungibles/",
+            200,
+            json!({
+                "items": [{
+                    "resource_address": ResourceAddress::placeholder_other().address,
+                    "amount": 7
+                }]
+            }),
+        );
+        let balances = sut
+            .non_fungible_balances(AccountAddress::placeholder_mainnet())
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].amount, 7);
+    }
+
+    #[test]
+    fn current_epoch() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/transaction/construction",
+            200,
+            json!({ "ledger_state": ledger_state("mainnet") }),
+        );
+        assert_eq!(sut.current_epoch(), Ok(5678));
+    }
+
+    #[test]
+    fn submit_transaction() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/transaction/submit",
+            200,
+            json!({ "duplicate": false }),
+        );
+        let notarized = notarized();
+
+        let response = sut.submit_transaction(notarized.clone()).unwrap();
+        assert!(!response.duplicate);
+        assert_eq!(
+            transport.request_bodies(),
+            vec![json!({
+                "notarized_transaction_hex": notarized.compiled.to_hex()
+            })]
+        );
+    }
+
+    #[test]
+    fn transaction_status() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/transaction/status",
+            200,
+            json!({
+                "ledger_state": ledger_state("mainnet"),
+                "status": "CommittedFailure",
+                "intent_status": "CommittedFailure",
+                "error_message": "Out of XRD"
+            }),
+        );
+        let intent_hash = notarized().intent_hash;
+        let response = sut.transaction_status(intent_hash.clone()).unwrap();
+        assert_eq!(response.status, TransactionStatus::CommittedFailure);
+        assert_eq!(response.error_message, Some("Out of XRD".to_owned()));
+        assert_eq!(
+            transport.request_bodies(),
+            vec![json!({ "intent_hash": intent_hash.bech32_encoded_tx_id })]
+        );
+    }
+
+    #[test]
+    fn error_response() {
+        let (sut, transport) = sut();
+        transport.respond(
+            "/transaction/construction",
+            400,
+            json!({ "message": "Bad request", "code": 400 }),
+        );
+        assert_eq!(
+            sut.current_epoch(),
+            Err(CommonError::GatewayRequestFailed {
+                status_code: 400,
+                message: "Bad request".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_json_response() {
+        let (sut, transport) = sut();
+        let intent_hash = notarized().intent_hash;
+        transport.respond("/transaction/status", 200, json!({}));
+        assert!(matches!(
+            sut.transaction_status(intent_hash),
+            Err(CommonError::FailedToDeserializeJSONToValue { .. })
+        ));
+    }
+
+    #[test]
+    fn transaction_on_other_network() {
+        let transport = FakeHttpTransport::new();
+        let sut = GatewayClient::new(transport.clone(), Gateway::stokenet());
+        assert_eq!(
+            sut.transaction_status(notarized().intent_hash),
+            Err(CommonError::GatewayNetworkMismatch {
+                expected: "stokenet".to_owned(),
+                found: "mainnet".to_owned()
+            })
+        );
+        assert!(transport.requests.read().unwrap().is_empty());
+    }
+}
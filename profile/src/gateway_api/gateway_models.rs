@@ -0,0 +1,178 @@
+use crate::prelude::*;
+
+/// The state of the ledger a Gateway response was computed at.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct LedgerState {
+    /// The logical name of the network, e.g. `"mainnet"`.
+    pub network: String,
+    pub state_version: u64,
+    pub proposer_round_timestamp: String,
+    pub epoch: u64,
+    pub round: u64,
+}
+
+/// The response of the `/status/gateway-status` endpoint.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct GatewayStatus {
+    pub ledger_state: LedgerState,
+}
+
+/// The amount of a fungible resource held by an entity.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct FungibleResourceBalance {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+/// The number of non fungibles of a resource held by an entity.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct NonFungibleResourceBalance {
+    pub resource_address: ResourceAddress,
+    pub amount: u64,
+}
+
+/// The details of an account, with the first page of its
+/// balances, see `GatewayClient::fungible_balances` and
+/// `GatewayClient::non_fungible_balances` for all of them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct EntityDetails {
+    pub address: AccountAddress,
+    pub fungible_resources: Vec<FungibleResourceBalance>,
+    pub non_fungible_resources: Vec<NonFungibleResourceBalance>,
+}
+
+/// The response of the `/transaction/submit` endpoint.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct TransactionSubmitResponse {
+    /// If the transaction had already been submitted before.
+    pub duplicate: bool,
+}
+
+/// The status of a transaction as known by the Gateway.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Enum,
+)]
+pub enum TransactionStatus {
+    Unknown,
+    Pending,
+    CommittedSuccess,
+    CommittedFailure,
+    Rejected,
+}
+
+/// The response of the `/transaction/status` endpoint.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct TransactionStatusResponse {
+    pub status: TransactionStatus,
+    #[serde(default)]
+    pub error_message: Option<String>,
+}
+
+//========
+// Gateway JSON models not exposed to Wallet Clients
+//========
+
+/// A page of items, `next_cursor` is set if there are more. Pages returned
+/// by the page endpoints have the `ledger_state` they were computed at,
+/// pages embedded in other responses do not.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct GatewayPage<T> {
+    #[serde(default)]
+    pub(crate) ledger_state: Option<LedgerState>,
+    pub(crate) items: Vec<T>,
+    #[serde(default)]
+    pub(crate) next_cursor: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct LedgerStateResponse {
+    pub(crate) ledger_state: LedgerState,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct EntityDetailsRequest {
+    pub(crate) addresses: Vec<AccountAddress>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct EntityDetailsResponse {
+    pub(crate) ledger_state: LedgerState,
+    pub(crate) items: Vec<EntityDetailsResponseItem>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct EntityDetailsResponseItem {
+    pub(crate) address: AccountAddress,
+    #[serde(default)]
+    pub(crate) fungible_resources: Option<GatewayPage<FungibleResourceBalance>>,
+    #[serde(default)]
+    pub(crate) non_fungible_resources:
+        Option<GatewayPage<NonFungibleResourceBalance>>,
+}
+
+impl From<EntityDetailsResponseItem> for EntityDetails {
+    fn from(value: EntityDetailsResponseItem) -> Self {
+        Self {
+            address: value.address,
+            fungible_resources: value
+                .fungible_resources
+                .map(|p| p.items)
+                .unwrap_or_default(),
+            non_fungible_resources: value
+                .non_fungible_resources
+                .map(|p| p.items)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct EntityPageRequest {
+    pub(crate) address: AccountAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) at_ledger_state: Option<LedgerStateSelector>,
+}
+
+/// Selects the state of the ledger a request is computed at.
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct LedgerStateSelector {
+    pub(crate) state_version: u64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct TransactionSubmitRequest {
+    pub(crate) notarized_transaction_hex: String,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct TransactionStatusRequest {
+    pub(crate) intent_hash: String,
+}
+
+/// The body of an error response.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct GatewayErrorResponse {
+    pub(crate) message: String,
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+
+/// The HTTP method of a [`HttpRequest`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum HttpMethod {
+    Get,
+    Post,
+}
+
+/// A HTTP request to be executed by the host's [`HttpTransport`].
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct HttpRequest {
+    pub url: Url,
+    pub method: HttpMethod,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+/// The response of a [`HttpRequest`], for any status code.
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct HttpResponse {
+    pub status_code: u16,
+    pub body: Vec<u8>,
+}
+
+/// The HTTP layer of an implementing Wallet Client, used by `GatewayClient`
+/// to talk to a Gateway, so that the kit does not need an HTTP stack of its
+/// own.
+///
+/// Should only return `Err` if the request could not be executed at all, a
+/// response with any status code should be returned as `Ok`.
+#[uniffi::export]
+pub trait HttpTransport: Send + Sync + std::fmt::Debug {
+    fn execute_request(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
@@ -0,0 +1,13 @@
+mod gateway_client;
+mod gateway_models;
+mod http_transport;
+
+pub use gateway_client::*;
+pub use gateway_models::*;
+pub use http_transport::*;
+
+#[cfg(test)]
+mod fake_http_transport;
+
+#[cfg(test)]
+pub use fake_http_transport::*;
@@ -6,6 +6,7 @@ use crate::prelude::*;
 #[derive(
     Serialize_repr,
     Deserialize_repr,
+    JsonSchema_repr,
     FromRepr,
     Clone,
     Copy,
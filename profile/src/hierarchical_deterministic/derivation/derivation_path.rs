@@ -72,6 +72,25 @@ impl Serialize for DerivationPath {
     }
 }
 
+impl JsonSchema for DerivationPath {
+    fn schema_name() -> String {
+        "DerivationPath".to_owned()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let path = |scheme: DerivationPathScheme| {
+            object_json_schema([
+                ("scheme", const_json_schema(scheme)),
+                ("path", string_json_schema(None, None)),
+            ])
+        };
+        one_of_json_schema([
+            path(DerivationPathScheme::Cap26),
+            path(DerivationPathScheme::Bip44Olympia),
+        ])
+    }
+}
+
 impl HasPlaceholder for DerivationPath {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
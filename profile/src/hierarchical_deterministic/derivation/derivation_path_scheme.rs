@@ -7,6 +7,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     Debug,
     PartialEq,
@@ -3,6 +3,7 @@ use identified_vec::{
     IdentifiedVecOf, IsIdentifiableVecOfVia, IsIdentifiedVec,
     IsIdentifiedVecOf, ViaMarker,
 };
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{Debug, Display, Formatter},
@@ -132,6 +133,24 @@ where
     }
 }
 
+impl<Element: Identifiable + Debug + Clone> JsonSchema
+    for IdentifiedVecVia<Element>
+where
+    Element: JsonSchema,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        Vec::<Element>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        Vec::<Element>::json_schema(gen)
+    }
+}
+
 #[cfg(not(tarpaulin_include))] // Tested in binding tests (e.g. test*.swift files)
 unsafe impl<UT, T: Identifiable + Debug + Clone + Lower<UT>> Lower<UT>
     for IdentifiedVecVia<T>
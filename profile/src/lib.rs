@@ -1,22 +1,28 @@
 #![allow(unused_imports)]
 
+mod gateway_api;
 mod hierarchical_deterministic;
 mod identified_vec_via;
 mod logic;
 mod profilesnapshot_version;
+mod radix_connect;
 mod v100;
 mod wallet;
 mod wallet_kit_common;
+mod wrapped_radix_engine_toolkit;
 
 pub mod prelude {
 
+    pub use crate::gateway_api::*;
     pub use crate::hierarchical_deterministic::*;
     pub use crate::identified_vec_via::*;
     pub use crate::logic::*;
     pub use crate::profilesnapshot_version::*;
+    pub use crate::radix_connect::*;
     pub use crate::v100::*;
     pub use crate::wallet::*;
     pub use crate::wallet_kit_common::*;
+    pub use crate::wrapped_radix_engine_toolkit::*;
 
     pub(crate) use std::collections::{BTreeSet, HashMap, HashSet};
 
@@ -30,6 +36,15 @@ pub mod prelude {
     pub(crate) use iso8601_timestamp::Timestamp;
     pub(crate) use itertools::Itertools;
     pub(crate) use log::{debug, error, info, trace, warn};
+    pub(crate) use schemars::{
+        gen::SchemaGenerator,
+        schema::{
+            ArrayValidation, InstanceType, NumberValidation, ObjectValidation,
+            RootSchema, Schema, SchemaObject, SingleOrVec, StringValidation,
+            SubschemaValidation,
+        },
+        JsonSchema, JsonSchema_repr,
+    };
     pub(crate) use serde::{
         de, ser::SerializeStruct, Deserialize, Deserializer, Serialize,
         Serializer,
@@ -1,5 +1,23 @@
 #![allow(unused_imports)]
 
+mod profile_authorized_dapps;
+mod profile_change_events;
+mod profile_diff;
+mod profile_factor_source_usage;
+mod profile_index;
+mod profile_json_schema;
+mod profile_merge;
 mod profile_next_derivation;
+mod profile_persona_data;
+mod profile_validation;
 
+pub use profile_authorized_dapps::*;
+pub use profile_change_events::*;
+pub use profile_diff::*;
+pub use profile_factor_source_usage::*;
+pub use profile_index::*;
+pub use profile_json_schema::*;
+pub use profile_merge::*;
 pub use profile_next_derivation::*;
+pub use profile_persona_data::*;
+pub use profile_validation::*;
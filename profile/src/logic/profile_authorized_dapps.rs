@@ -0,0 +1,855 @@
+use crate::prelude::*;
+
+impl Profile {
+    /// Records that the user has approved a request from the dApp with
+    /// `dapp_definition_address`, logging in with the Persona with
+    /// `persona_address` and - optionally - giving it ongoing access to
+    /// `shared_accounts` and `shared_persona_data`.
+    ///
+    /// If the dApp is unknown a new [`AuthorizedDapp`] is added to the
+    /// network of the Persona, and if the Persona has not been used with the
+    /// dApp before a new [`AuthorizedPersonaSimple`] is added to it.
+    ///
+    /// `None` for `shared_accounts` keeps the previously shared accounts, and
+    /// each `None` field of `shared_persona_data` keeps the previously shared
+    /// entries of that kind, i.e. only the approved delta needs to be passed.
+    ///
+    /// Returns the updated `AuthorizedDapp`.
+    pub fn update_authorized_dapp_after_approval(
+        &mut self,
+        dapp_definition_address: &DappDefinitionAddress,
+        display_name: Option<String>,
+        persona_address: &IdentityAddress,
+        shared_accounts: Option<SharedToDappWithPersonaAccountAddresses>,
+        shared_persona_data: Option<SharedPersonaData>,
+        now: Timestamp,
+    ) -> Result<AuthorizedDapp> {
+        let network_id = persona_address.network_id;
+        if dapp_definition_address.network_id != network_id {
+            return Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: network_id,
+                found: dapp_definition_address.network_id,
+            });
+        }
+
+        let persona = self
+            .networks
+            .get_persona(persona_address)
+            .filter(|p| !p.flags.contains(&EntityFlag::DeletedByUser))
+            .ok_or(CommonError::UnknownPersona)?;
+
+        let mut network = self.networks.get(&network_id).cloned().ok_or(
+            CommonError::UnknownNetworkForID(network_id.discriminant()),
+        )?;
+
+        if let Some(shared_accounts) = shared_accounts.as_ref() {
+            Self::validate_shared_accounts(&network, shared_accounts)?;
+        }
+
+        if let Some(shared_persona_data) = shared_persona_data.as_ref() {
+            Self::validate_shared_persona_data(
+                &persona.persona_data,
+                shared_persona_data,
+            )?;
+        }
+
+        let mut authorized_dapp = network
+            .authorized_dapps
+            .get_authorized_dapp_by_address(dapp_definition_address)
+            .cloned()
+            .unwrap_or(AuthorizedDapp::new(
+                network_id,
+                dapp_definition_address.clone(),
+                display_name.clone(),
+                IdentifiedVecVia::new(),
+            ));
+
+        if display_name.is_some() {
+            authorized_dapp.display_name = display_name;
+        }
+
+        let mut authorized_persona = authorized_dapp
+            .references_to_authorized_personas
+            .get(persona_address)
+            .cloned()
+            .unwrap_or(AuthorizedPersonaSimple::new(
+                persona_address.clone(),
+                now,
+                None,
+                SharedPersonaData::default(),
+            ));
+
+        authorized_persona.last_login = now;
+        if shared_accounts.is_some() {
+            authorized_persona.shared_accounts = shared_accounts;
+        }
+        if let Some(shared_persona_data) = shared_persona_data {
+            let existing = &mut authorized_persona.shared_persona_data;
+            if shared_persona_data.name.is_some() {
+                existing.name = shared_persona_data.name;
+            }
+            if shared_persona_data.email_addresses.is_some() {
+                existing.email_addresses = shared_persona_data.email_addresses;
+            }
+            if shared_persona_data.phone_numbers.is_some() {
+                existing.phone_numbers = shared_persona_data.phone_numbers;
+            }
+            if shared_persona_data.date_of_birth.is_some() {
+                existing.date_of_birth = shared_persona_data.date_of_birth;
+            }
+            if shared_persona_data.company_name.is_some() {
+                existing.company_name = shared_persona_data.company_name;
+            }
+            if shared_persona_data.postal_addresses.is_some() {
+                existing.postal_addresses =
+                    shared_persona_data.postal_addresses;
+            }
+            if shared_persona_data.urls.is_some() {
+                existing.urls = shared_persona_data.urls;
+            }
+        }
+
+        let references = &mut authorized_dapp.references_to_authorized_personas;
+        if !references
+            .update_with(persona_address, |p| *p = authorized_persona.clone())
+        {
+            references.append(authorized_persona);
+        }
+
+        if !network
+            .authorized_dapps
+            .update_with(dapp_definition_address, |d| {
+                *d = authorized_dapp.clone()
+            })
+        {
+            network.authorized_dapps.append(authorized_dapp.clone());
+        }
+
+        self.networks
+            .update_with(&network_id, |n| *n = network.clone());
+
+        Ok(authorized_dapp)
+    }
+
+    /// Forgets the dApp with `dapp_definition_address`, i.e. removes it and
+    /// all Personas, accounts and PersonaData shared with it.
+    pub fn forget_authorized_dapp(
+        &mut self,
+        dapp_definition_address: &DappDefinitionAddress,
+    ) -> Result<()> {
+        let mut network =
+            self.network_of_authorized_dapp(dapp_definition_address)?;
+        _ = network
+            .authorized_dapps
+            .remove_by_id(dapp_definition_address);
+        self.networks
+            .update_with(&network.id, |n| *n = network.clone());
+        Ok(())
+    }
+
+    /// Removes the Persona with `persona_address` from the dApp with
+    /// `dapp_definition_address`, also removing the accounts and PersonaData
+    /// shared using it. If it was the last Persona used with the dApp, the
+    /// dApp is forgotten and `None` is returned, else the updated dApp.
+    pub fn deauthorize_persona_from_authorized_dapp(
+        &mut self,
+        dapp_definition_address: &DappDefinitionAddress,
+        persona_address: &IdentityAddress,
+    ) -> Result<Option<AuthorizedDapp>> {
+        let mut network =
+            self.network_of_authorized_dapp(dapp_definition_address)?;
+        let mut authorized_dapp = network
+            .authorized_dapps
+            .get_authorized_dapp_by_address(dapp_definition_address)
+            .cloned()
+            .ok_or(CommonError::UnknownAuthorizedDapp(
+                dapp_definition_address.clone(),
+            ))?;
+
+        if authorized_dapp
+            .references_to_authorized_personas
+            .remove_by_id(persona_address)
+            .is_none()
+        {
+            return Err(CommonError::PersonaNotAuthorizedByDapp(
+                persona_address.clone(),
+            ));
+        }
+
+        let updated =
+            if authorized_dapp.references_to_authorized_personas.is_empty() {
+                _ = network
+                    .authorized_dapps
+                    .remove_by_id(dapp_definition_address);
+                None
+            } else {
+                network
+                    .authorized_dapps
+                    .update_with(dapp_definition_address, |d| {
+                        *d = authorized_dapp.clone()
+                    });
+                Some(authorized_dapp)
+            };
+
+        self.networks
+            .update_with(&network.id, |n| *n = network.clone());
+        Ok(updated)
+    }
+
+    /// Replaces the accounts shared with the dApp with
+    /// `dapp_definition_address` using the Persona with `persona_address`,
+    /// `None` stops sharing any accounts. Returns the updated dApp.
+    pub fn update_shared_accounts_of_authorized_dapp(
+        &mut self,
+        dapp_definition_address: &DappDefinitionAddress,
+        persona_address: &IdentityAddress,
+        shared_accounts: Option<SharedToDappWithPersonaAccountAddresses>,
+    ) -> Result<AuthorizedDapp> {
+        let mut network =
+            self.network_of_authorized_dapp(dapp_definition_address)?;
+        if let Some(shared_accounts) = shared_accounts.as_ref() {
+            Self::validate_shared_accounts(&network, shared_accounts)?;
+        }
+
+        let mut authorized_dapp = network
+            .authorized_dapps
+            .get_authorized_dapp_by_address(dapp_definition_address)
+            .cloned()
+            .ok_or(CommonError::UnknownAuthorizedDapp(
+                dapp_definition_address.clone(),
+            ))?;
+
+        if !authorized_dapp
+            .references_to_authorized_personas
+            .update_with(persona_address, |p| {
+                p.shared_accounts = shared_accounts.clone()
+            })
+        {
+            return Err(CommonError::PersonaNotAuthorizedByDapp(
+                persona_address.clone(),
+            ));
+        }
+
+        network
+            .authorized_dapps
+            .update_with(dapp_definition_address, |d| {
+                *d = authorized_dapp.clone()
+            });
+        self.networks
+            .update_with(&network.id, |n| *n = network.clone());
+        Ok(authorized_dapp)
+    }
+
+    /// A copy of the network the dApp with `dapp_definition_address` is on,
+    /// if the user has interacted with it, else an error is returned.
+    fn network_of_authorized_dapp(
+        &self,
+        dapp_definition_address: &DappDefinitionAddress,
+    ) -> Result<ProfileNetwork> {
+        self.networks
+            .get(&dapp_definition_address.network_id)
+            .filter(|n| n.authorized_dapps.contains_id(dapp_definition_address))
+            .cloned()
+            .ok_or(CommonError::UnknownAuthorizedDapp(
+                dapp_definition_address.clone(),
+            ))
+    }
+
+    /// Checks that `shared_accounts` are accounts on `network`, i.e. the
+    /// network of the dApp, which are not hidden by the user, and that they
+    /// fulfill the request they were shared for.
+    fn validate_shared_accounts(
+        network: &ProfileNetwork,
+        shared_accounts: &SharedToDappWithPersonaAccountAddresses,
+    ) -> Result<()> {
+        if let Some(address) = shared_accounts
+            .ids
+            .iter()
+            .find(|a| a.network_id != network.id)
+        {
+            return Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: network.id,
+                found: address.network_id,
+            });
+        }
+        if shared_accounts.ids.iter().any(|a| {
+            network
+                .accounts
+                .get_account_by_address(a)
+                .map(|a| a.flags.contains(&EntityFlag::DeletedByUser))
+                .unwrap_or(true)
+        }) {
+            return Err(CommonError::UnknownAccount);
+        }
+        if !shared_accounts
+            .request
+            .is_fulfilled_by_ids(shared_accounts.ids.len())
+        {
+            return Err(CommonError::SharedAccountsDoNotFulfillRequest {
+                requested: shared_accounts.request.clone(),
+                shared: shared_accounts.ids.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that all IDs in `shared_persona_data` refer to entries in
+    /// `persona_data`, and that the shared collections fulfill the request
+    /// they were shared for.
+    fn validate_shared_persona_data(
+        persona_data: &PersonaData,
+        shared_persona_data: &SharedPersonaData,
+    ) -> Result<()> {
+        let unknown_single =
+            |shared: &Option<PersonaDataEntryID>,
+             existing: Option<&PersonaDataEntryID>| {
+                shared.as_ref().filter(|id| Some(*id) != existing).cloned()
+            };
+        let unknown_in_collection =
+            |shared: &Option<
+                SharedToDappWithPersonaIDsOfPersonaDataEntries,
+            >,
+             existing: Vec<PersonaDataEntryID>| {
+                shared
+                    .iter()
+                    .flat_map(|s| s.ids.iter())
+                    .find(|id| !existing.contains(id))
+                    .cloned()
+            };
+
+        let unknown = unknown_single(
+            &shared_persona_data.name,
+            persona_data.name.as_ref().map(|n| &n.id),
+        )
+        .or_else(|| {
+            unknown_in_collection(
+                &shared_persona_data.email_addresses,
+                persona_data.email_addresses.ids(),
+            )
+        })
+        .or_else(|| {
+            unknown_in_collection(
+                &shared_persona_data.phone_numbers,
+                persona_data.phone_numbers.ids(),
+            )
+        })
+        .or_else(|| {
+            unknown_single(
+                &shared_persona_data.date_of_birth,
+                persona_data.date_of_birth.as_ref().map(|d| &d.id),
+            )
+        })
+        .or_else(|| {
+            unknown_single(
+                &shared_persona_data.company_name,
+                persona_data.company_name.as_ref().map(|c| &c.id),
+            )
+        })
+        .or_else(|| {
+            unknown_in_collection(
+                &shared_persona_data.postal_addresses,
+                persona_data.postal_addresses.ids(),
+            )
+        })
+        .or_else(|| {
+            unknown_in_collection(
+                &shared_persona_data.urls,
+                persona_data.urls.ids(),
+            )
+        });
+
+        if let Some(id) = unknown {
+            return Err(CommonError::UnknownPersonaDataEntry(id));
+        }
+
+        if let Some(unfulfilled) = [
+            &shared_persona_data.email_addresses,
+            &shared_persona_data.phone_numbers,
+            &shared_persona_data.postal_addresses,
+            &shared_persona_data.urls,
+        ]
+        .into_iter()
+        .flatten()
+        .find(|s| !s.request.is_fulfilled_by_ids(s.ids.len()))
+        {
+            return Err(CommonError::SharedPersonaDataDoesNotFulfillRequest {
+                requested: unfulfilled.request.clone(),
+                shared: unfulfilled.ids.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn shared_persona_data_of_leia() -> SharedPersonaData {
+        let persona_data =
+            Persona::placeholder_stokenet_leia_skywalker().persona_data;
+        SharedPersonaData::new(
+            persona_data.name.unwrap().id,
+            None,
+            SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                RequestedQuantity::exactly(1),
+                IdentifiedVecVia::from_iter(persona_data.phone_numbers.ids()),
+            ),
+        )
+    }
+
+    #[test]
+    fn new_dapp_is_added() {
+        let mut sut = Profile::placeholder();
+        let dapp_definition_address = AccountAddress::placeholder_stokenet();
+        let now = Timestamp::parse("2024-02-29T12:00:00Z").unwrap();
+        let shared_accounts = SharedToDappWithPersonaAccountAddresses::new(
+            RequestedQuantity::exactly(1),
+            IdentifiedVecVia::from_iter([
+                AccountAddress::placeholder_stokenet(),
+            ]),
+        );
+        let dapp = sut
+            .update_authorized_dapp_after_approval(
+                &dapp_definition_address,
+                Some("New".to_owned()),
+                &IdentityAddress::placeholder_stokenet(),
+                Some(shared_accounts.clone()),
+                Some(shared_persona_data_of_leia()),
+                now,
+            )
+            .unwrap();
+        assert_eq!(dapp.display_name, Some("New".to_owned()));
+        assert_eq!(
+            dapp.references_to_authorized_personas.items(),
+            [AuthorizedPersonaSimple::new(
+                IdentityAddress::placeholder_stokenet(),
+                now,
+                shared_accounts,
+                shared_persona_data_of_leia(),
+            )]
+        );
+        assert_eq!(
+            sut.networks
+                .get(&NetworkID::Stokenet)
+                .unwrap()
+                .authorized_dapps
+                .get_authorized_dapp_by_address(&dapp_definition_address),
+            Some(&dapp)
+        );
+    }
+
+    #[test]
+    fn existing_persona_is_updated_with_delta() {
+        let mut sut = Profile::placeholder();
+        let devconsole = AuthorizedDapp::placeholder_stokenet_devconsole();
+        let persona_address = IdentityAddress::placeholder_stokenet();
+        let now = Timestamp::parse("2024-02-29T12:00:00Z").unwrap();
+        let dapp = sut
+            .update_authorized_dapp_after_approval(
+                &devconsole.dapp_definition_address,
+                None,
+                &persona_address,
+                None,
+                Some(SharedPersonaData::new(
+                    None,
+                    None,
+                    shared_persona_data_of_leia().phone_numbers,
+                )),
+                now,
+            )
+            .unwrap();
+        assert_eq!(dapp.display_name, devconsole.display_name);
+        assert_eq!(
+            dapp.references_to_authorized_personas.len(),
+            devconsole.references_to_authorized_personas.len()
+        );
+        let before = devconsole
+            .references_to_authorized_personas
+            .get(&persona_address)
+            .unwrap()
+            .clone();
+        let after = dapp
+            .references_to_authorized_personas
+            .get(&persona_address)
+            .unwrap()
+            .clone();
+        assert_eq!(after.last_login, now);
+        assert_eq!(after.shared_accounts, before.shared_accounts);
+        assert_eq!(
+            after.shared_persona_data.name,
+            before.shared_persona_data.name
+        );
+        assert_eq!(
+            after.shared_persona_data.email_addresses,
+            before.shared_persona_data.email_addresses
+        );
+        assert_eq!(
+            after.shared_persona_data.phone_numbers,
+            shared_persona_data_of_leia().phone_numbers
+        );
+    }
+
+    #[test]
+    fn unknown_persona() {
+        let mut sut = Profile::placeholder();
+        sut.networks.update_with(&NetworkID::Stokenet, |n| {
+            n.personas = Personas::new();
+        });
+        assert_eq!(
+            sut.update_authorized_dapp_after_approval(
+                &AccountAddress::placeholder_stokenet(),
+                None,
+                &IdentityAddress::placeholder_stokenet(),
+                None,
+                None,
+                now(),
+            ),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn network_mismatch() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            sut.update_authorized_dapp_after_approval(
+                &AccountAddress::placeholder_mainnet(),
+                None,
+                &IdentityAddress::placeholder_stokenet(),
+                None,
+                None,
+                now(),
+            ),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_persona_data_entry() {
+        let mut sut = Profile::placeholder();
+        let unknown = PersonaDataEntryID::placeholder_four();
+        assert_eq!(
+            sut.update_authorized_dapp_after_approval(
+                &AccountAddress::placeholder_stokenet(),
+                None,
+                &IdentityAddress::placeholder_stokenet(),
+                None,
+                Some(SharedPersonaData::new(unknown.clone(), None, None)),
+                now(),
+            ),
+            Err(CommonError::UnknownPersonaDataEntry(unknown))
+        );
+    }
+
+    #[test]
+    fn shared_persona_data_not_fulfilling_request_fails() {
+        let mut sut = Profile::placeholder();
+        let phone_numbers = shared_persona_data_of_leia().phone_numbers;
+        assert_eq!(
+            sut.update_authorized_dapp_after_approval(
+                &AccountAddress::placeholder_stokenet(),
+                None,
+                &IdentityAddress::placeholder_stokenet(),
+                None,
+                Some(SharedPersonaData::new(
+                    None,
+                    None,
+                    SharedToDappWithPersonaIDsOfPersonaDataEntries {
+                        request: RequestedQuantity::exactly(2),
+                        ids: phone_numbers.unwrap().ids,
+                    },
+                )),
+                now(),
+            ),
+            Err(CommonError::SharedPersonaDataDoesNotFulfillRequest {
+                requested: RequestedQuantity::exactly(2),
+                shared: 1
+            })
+        );
+    }
+
+    fn approve_sharing_accounts(
+        sut: &mut Profile,
+        shared_accounts: SharedToDappWithPersonaAccountAddresses,
+    ) -> Result<AuthorizedDapp> {
+        sut.update_authorized_dapp_after_approval(
+            &AccountAddress::placeholder_stokenet(),
+            None,
+            &IdentityAddress::placeholder_stokenet(),
+            Some(shared_accounts),
+            None,
+            now(),
+        )
+    }
+
+    #[test]
+    fn approval_sharing_hidden_account_fails() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            approve_sharing_accounts(
+                &mut sut,
+                SharedToDappWithPersonaAccountAddresses::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([
+                        Account::placeholder_stokenet_diana().address,
+                    ]),
+                ),
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn approval_sharing_account_on_other_network_fails() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            approve_sharing_accounts(
+                &mut sut,
+                SharedToDappWithPersonaAccountAddresses::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([
+                        AccountAddress::placeholder_mainnet(),
+                    ]),
+                ),
+            ),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn approval_sharing_accounts_not_fulfilling_request_fails() {
+        let mut sut = Profile::placeholder();
+        assert_eq!(
+            approve_sharing_accounts(
+                &mut sut,
+                SharedToDappWithPersonaAccountAddresses {
+                    request: RequestedQuantity::exactly(2),
+                    ids: IdentifiedVecVia::from_iter([
+                        Account::placeholder_stokenet_carol().address,
+                    ]),
+                },
+            ),
+            Err(CommonError::SharedAccountsDoNotFulfillRequest {
+                requested: RequestedQuantity::exactly(2),
+                shared: 1
+            })
+        );
+    }
+
+    #[test]
+    fn approval_with_hidden_persona_fails() {
+        let mut sut = Profile::placeholder();
+        sut.networks.update_with(&NetworkID::Stokenet, |n| {
+            n.personas.update_with(
+                &IdentityAddress::placeholder_stokenet(),
+                |p| {
+                    p.flags.insert_flag(EntityFlag::DeletedByUser);
+                },
+            );
+        });
+        assert_eq!(
+            sut.update_authorized_dapp_after_approval(
+                &AccountAddress::placeholder_stokenet(),
+                None,
+                &IdentityAddress::placeholder_stokenet(),
+                None,
+                None,
+                now(),
+            ),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn forget_authorized_dapp() {
+        let mut sut = Profile::placeholder();
+        let sandbox = AuthorizedDapp::placeholder_stokenet_sandbox();
+        sut.forget_authorized_dapp(&sandbox.dapp_definition_address)
+            .unwrap();
+        assert_eq!(
+            sut.networks
+                .get(&NetworkID::Stokenet)
+                .unwrap()
+                .authorized_dapps
+                .items(),
+            [AuthorizedDapp::placeholder_stokenet_devconsole()]
+        );
+        assert_eq!(
+            sut.forget_authorized_dapp(&sandbox.dapp_definition_address),
+            Err(CommonError::UnknownAuthorizedDapp(
+                sandbox.dapp_definition_address
+            ))
+        );
+    }
+
+    #[test]
+    fn deauthorize_persona_from_authorized_dapp() {
+        let mut sut = Profile::placeholder();
+        let devconsole = AuthorizedDapp::placeholder_stokenet_devconsole();
+        let leia = IdentityAddress::placeholder_stokenet();
+        let hermione = IdentityAddress::placeholder_stokenet_other();
+
+        let updated = sut
+            .deauthorize_persona_from_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &leia,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated.references_to_authorized_personas.ids(),
+            [hermione.clone()]
+        );
+        assert_eq!(
+            sut.deauthorize_persona_from_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &leia,
+            ),
+            Err(CommonError::PersonaNotAuthorizedByDapp(leia))
+        );
+
+        // Last Persona, dApp is forgotten
+        assert_eq!(
+            sut.deauthorize_persona_from_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &hermione,
+            ),
+            Ok(None)
+        );
+        assert!(!sut
+            .networks
+            .get(&NetworkID::Stokenet)
+            .unwrap()
+            .authorized_dapps
+            .contains_id(&devconsole.dapp_definition_address));
+    }
+
+    #[test]
+    fn update_shared_accounts_of_authorized_dapp() {
+        let mut sut = Profile::placeholder();
+        let devconsole = AuthorizedDapp::placeholder_stokenet_devconsole();
+        let leia = IdentityAddress::placeholder_stokenet();
+        let shared_accounts = SharedToDappWithPersonaAccountAddresses::new(
+            RequestedQuantity::exactly(1),
+            IdentifiedVecVia::from_iter([Account::placeholder_stokenet_carol(
+            )
+            .address]),
+        );
+        let updated = sut
+            .update_shared_accounts_of_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &leia,
+                Some(shared_accounts.clone()),
+            )
+            .unwrap();
+        assert_eq!(
+            updated
+                .references_to_authorized_personas
+                .get(&leia)
+                .unwrap()
+                .shared_accounts,
+            Some(shared_accounts)
+        );
+
+        let cleared = sut
+            .update_shared_accounts_of_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &leia,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            cleared
+                .references_to_authorized_personas
+                .get(&leia)
+                .unwrap()
+                .shared_accounts,
+            None
+        );
+    }
+
+    #[test]
+    fn update_shared_accounts_with_hidden_account_fails() {
+        let mut sut = Profile::placeholder();
+        let devconsole = AuthorizedDapp::placeholder_stokenet_devconsole();
+        assert_eq!(
+            sut.update_shared_accounts_of_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &IdentityAddress::placeholder_stokenet(),
+                Some(SharedToDappWithPersonaAccountAddresses::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([
+                        Account::placeholder_stokenet_diana().address,
+                    ]),
+                )),
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn update_shared_accounts_of_persona_not_authorized() {
+        let mut sut = Profile::placeholder();
+        let sandbox = AuthorizedDapp::placeholder_stokenet_sandbox();
+        let leia = IdentityAddress::placeholder_stokenet();
+        assert_eq!(
+            sut.update_shared_accounts_of_authorized_dapp(
+                &sandbox.dapp_definition_address,
+                &leia,
+                None,
+            ),
+            Err(CommonError::PersonaNotAuthorizedByDapp(leia))
+        );
+    }
+
+    #[test]
+    fn update_shared_accounts_not_fulfilling_request_fails() {
+        let mut sut = Profile::placeholder();
+        let devconsole = AuthorizedDapp::placeholder_stokenet_devconsole();
+        assert_eq!(
+            sut.update_shared_accounts_of_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &IdentityAddress::placeholder_stokenet(),
+                Some(SharedToDappWithPersonaAccountAddresses {
+                    request: RequestedQuantity::at_least(2),
+                    ids: IdentifiedVecVia::from_iter([
+                        Account::placeholder_stokenet_carol().address,
+                    ]),
+                }),
+            ),
+            Err(CommonError::SharedAccountsDoNotFulfillRequest {
+                requested: RequestedQuantity::at_least(2),
+                shared: 1
+            })
+        );
+    }
+
+    #[test]
+    fn update_shared_accounts_on_other_network_fails() {
+        let mut sut = Profile::placeholder();
+        let devconsole = AuthorizedDapp::placeholder_stokenet_devconsole();
+        assert_eq!(
+            sut.update_shared_accounts_of_authorized_dapp(
+                &devconsole.dapp_definition_address,
+                &IdentityAddress::placeholder_stokenet(),
+                Some(SharedToDappWithPersonaAccountAddresses::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([
+                        AccountAddress::placeholder_mainnet(),
+                    ]),
+                )),
+            ),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+}
@@ -0,0 +1,455 @@
+use crate::prelude::*;
+
+/// A typed change of a Profile, with the identifiers of the affected items,
+/// see [`Profile::change_events`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileChangeEvent {
+    AccountAdded {
+        address: AccountAddress,
+    },
+    AccountRenamed {
+        address: AccountAddress,
+        display_name: DisplayName,
+    },
+    /// Any change of the account other than its display name, e.g. it
+    /// was hidden or its third party deposit settings were changed.
+    AccountUpdated {
+        address: AccountAddress,
+    },
+    AccountRemoved {
+        address: AccountAddress,
+    },
+
+    PersonaAdded {
+        address: IdentityAddress,
+    },
+    PersonaRenamed {
+        address: IdentityAddress,
+        display_name: DisplayName,
+    },
+    /// Any change of the persona other than its display name, e.g. its
+    /// persona data was changed.
+    PersonaUpdated {
+        address: IdentityAddress,
+    },
+    PersonaRemoved {
+        address: IdentityAddress,
+    },
+
+    DappAuthorized {
+        dapp_definition_address: DappDefinitionAddress,
+    },
+    DappAuthorizationUpdated {
+        dapp_definition_address: DappDefinitionAddress,
+    },
+    DappForgotten {
+        dapp_definition_address: DappDefinitionAddress,
+    },
+
+    FactorSourceAdded {
+        id: FactorSourceID,
+    },
+    FactorSourceUpdated {
+        id: FactorSourceID,
+    },
+    FactorSourceRemoved {
+        id: FactorSourceID,
+    },
+
+    GatewaySwitched {
+        from: Url,
+        to: Url,
+    },
+    /// The `id` is the hash of the connection password of the link, which
+    /// itself is never part of an event.
+    P2PLinkAdded {
+        id: Hex32Bytes,
+        display_name: String,
+    },
+    P2PLinkRemoved {
+        id: Hex32Bytes,
+        display_name: String,
+    },
+    /// Any change of the app preferences not covered by another event, e.g.
+    /// the display or security settings or the saved gateways.
+    AppPreferencesUpdated,
+}
+
+/// How an item changed between two versions of a collection.
+enum ItemChange<'a, T> {
+    Added(&'a T),
+    Modified { from: &'a T, to: &'a T },
+    Removed(&'a T),
+}
+
+fn item_changes<'a, E>(
+    from: &'a IdentifiedVecVia<E>,
+    to: &'a IdentifiedVecVia<E>,
+) -> Vec<ItemChange<'a, E>>
+where
+    E: Identifiable + Debug + Clone + PartialEq,
+{
+    union_of_ids(from, to)
+        .into_iter()
+        .filter_map(|id| match (from.get(&id), to.get(&id)) {
+            (None, Some(t)) => Some(ItemChange::Added(t)),
+            (Some(f), None) => Some(ItemChange::Removed(f)),
+            (Some(f), Some(t)) if f != t => {
+                Some(ItemChange::Modified { from: f, to: t })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+impl ProfileChangeEvent {
+    fn of_accounts(from: &Accounts, to: &Accounts) -> Vec<Self> {
+        item_changes(from, to)
+            .into_iter()
+            .flat_map(|change| match change {
+                ItemChange::Added(a) => vec![Self::AccountAdded {
+                    address: a.address.clone(),
+                }],
+                ItemChange::Removed(a) => vec![Self::AccountRemoved {
+                    address: a.address.clone(),
+                }],
+                ItemChange::Modified { from, to } => {
+                    let mut events = Vec::new();
+                    if from.display_name != to.display_name {
+                        events.push(Self::AccountRenamed {
+                            address: to.address.clone(),
+                            display_name: to.display_name.clone(),
+                        });
+                    }
+                    let mut renamed = from.clone();
+                    renamed.display_name = to.display_name.clone();
+                    if &renamed != to {
+                        events.push(Self::AccountUpdated {
+                            address: to.address.clone(),
+                        });
+                    }
+                    events
+                }
+            })
+            .collect()
+    }
+
+    fn of_personas(from: &Personas, to: &Personas) -> Vec<Self> {
+        item_changes(from, to)
+            .into_iter()
+            .flat_map(|change| match change {
+                ItemChange::Added(p) => vec![Self::PersonaAdded {
+                    address: p.address.clone(),
+                }],
+                ItemChange::Removed(p) => vec![Self::PersonaRemoved {
+                    address: p.address.clone(),
+                }],
+                ItemChange::Modified { from, to } => {
+                    let mut events = Vec::new();
+                    if from.display_name != to.display_name {
+                        events.push(Self::PersonaRenamed {
+                            address: to.address.clone(),
+                            display_name: to.display_name.clone(),
+                        });
+                    }
+                    let mut renamed = from.clone();
+                    renamed.display_name = to.display_name.clone();
+                    if &renamed != to {
+                        events.push(Self::PersonaUpdated {
+                            address: to.address.clone(),
+                        });
+                    }
+                    events
+                }
+            })
+            .collect()
+    }
+
+    fn of_authorized_dapps(
+        from: &AuthorizedDapps,
+        to: &AuthorizedDapps,
+    ) -> Vec<Self> {
+        item_changes(from, to)
+            .into_iter()
+            .map(|change| match change {
+                ItemChange::Added(d) => Self::DappAuthorized {
+                    dapp_definition_address: d.dapp_definition_address.clone(),
+                },
+                ItemChange::Modified { to, .. } => {
+                    Self::DappAuthorizationUpdated {
+                        dapp_definition_address: to
+                            .dapp_definition_address
+                            .clone(),
+                    }
+                }
+                ItemChange::Removed(d) => Self::DappForgotten {
+                    dapp_definition_address: d.dapp_definition_address.clone(),
+                },
+            })
+            .collect()
+    }
+
+    fn of_networks(from: &ProfileNetworks, to: &ProfileNetworks) -> Vec<Self> {
+        let empty = |id| {
+            ProfileNetwork::new(
+                id,
+                Accounts::new(),
+                Personas::new(),
+                AuthorizedDapps::new(),
+            )
+        };
+        union_of_ids(from, to)
+            .into_iter()
+            .flat_map(|id| {
+                let f = from.get(&id).cloned().unwrap_or_else(|| empty(id));
+                let t = to.get(&id).cloned().unwrap_or_else(|| empty(id));
+                Self::of_accounts(&f.accounts, &t.accounts)
+                    .into_iter()
+                    .chain(Self::of_personas(&f.personas, &t.personas))
+                    .chain(Self::of_authorized_dapps(
+                        &f.authorized_dapps,
+                        &t.authorized_dapps,
+                    ))
+                    .collect_vec()
+            })
+            .collect()
+    }
+
+    fn of_factor_sources(
+        from: &FactorSources,
+        to: &FactorSources,
+    ) -> Vec<Self> {
+        item_changes(from, to)
+            .into_iter()
+            .map(|change| match change {
+                ItemChange::Added(f) => Self::FactorSourceAdded { id: f.id() },
+                ItemChange::Modified { to, .. } => {
+                    Self::FactorSourceUpdated { id: to.id() }
+                }
+                ItemChange::Removed(f) => {
+                    Self::FactorSourceRemoved { id: f.id() }
+                }
+            })
+            .collect()
+    }
+
+    fn of_app_preferences(
+        from: &AppPreferences,
+        to: &AppPreferences,
+    ) -> Vec<Self> {
+        let mut events = Vec::new();
+        if from.gateways.current != to.gateways.current {
+            events.push(Self::GatewaySwitched {
+                from: from.gateways.current.url.clone(),
+                to: to.gateways.current.url.clone(),
+            });
+        }
+        events.extend(
+            item_changes(&from.p2p_links, &to.p2p_links)
+                .into_iter()
+                .map(|change| match change {
+                    ItemChange::Added(l)
+                    | ItemChange::Modified { to: l, .. } => {
+                        Self::P2PLinkAdded {
+                            id: l.id().into(),
+                            display_name: l.display_name.clone(),
+                        }
+                    }
+                    ItemChange::Removed(l) => Self::P2PLinkRemoved {
+                        id: l.id().into(),
+                        display_name: l.display_name.clone(),
+                    },
+                }),
+        );
+
+        // Switching gateway moves gateways between `current` and `other`,
+        // which is covered by `GatewaySwitched`.
+        let all_gateways = |p: &AppPreferences| {
+            p.gateways
+                .all()
+                .into_iter()
+                .sorted_by_key(|g| g.id().to_string())
+                .collect_vec()
+        };
+        if from.display != to.display
+            || from.security != to.security
+            || from.transaction != to.transaction
+            || all_gateways(from) != all_gateways(to)
+        {
+            events.push(Self::AppPreferencesUpdated);
+        }
+        events
+    }
+}
+
+impl Profile {
+    /// The typed changes made going from this Profile to `other`, in the
+    /// order: entities and dApps per network, factor sources and then app
+    /// preferences. The header is not compared.
+    pub fn change_events(&self, other: &Self) -> Vec<ProfileChangeEvent> {
+        ProfileChangeEvent::of_networks(&self.networks, &other.networks)
+            .into_iter()
+            .chain(ProfileChangeEvent::of_factor_sources(
+                &self.factor_sources,
+                &other.factor_sources,
+            ))
+            .chain(ProfileChangeEvent::of_app_preferences(
+                &self.app_preferences,
+                &other.app_preferences,
+            ))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn profile() -> Profile {
+        let mut profile = Profile::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            "Test",
+        );
+        profile.networks = ProfileNetworks::with_network(ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::from_iter([Account::placeholder_mainnet_alice()]),
+            Personas::from_iter([Persona::placeholder_mainnet_satoshi()]),
+            AuthorizedDapps::default(),
+        ));
+        profile
+    }
+
+    #[test]
+    fn no_events_when_unchanged() {
+        let sut = profile();
+        assert_eq!(sut.change_events(&sut.clone()), Vec::new());
+    }
+
+    #[test]
+    fn account_added_and_renamed() {
+        let from = profile();
+        let mut to = from.clone();
+        let alice = Account::placeholder_mainnet_alice();
+        let bob = Account::placeholder_mainnet_bob();
+        let name = DisplayName::new("Renamed").unwrap();
+        to.update_account(&alice.address, |a| a.display_name = name.clone());
+        to.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.append(bob.clone());
+        });
+
+        assert_eq!(
+            from.change_events(&to),
+            vec![
+                ProfileChangeEvent::AccountRenamed {
+                    address: alice.address.clone(),
+                    display_name: name
+                },
+                ProfileChangeEvent::AccountAdded {
+                    address: bob.address
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn account_hidden_is_updated() {
+        let from = profile();
+        let mut to = from.clone();
+        let alice = Account::placeholder_mainnet_alice();
+        to.update_account(&alice.address, |a| {
+            a.flags.insert_flag(EntityFlag::DeletedByUser);
+        });
+        assert_eq!(
+            from.change_events(&to),
+            vec![ProfileChangeEvent::AccountUpdated {
+                address: alice.address
+            }]
+        );
+    }
+
+    #[test]
+    fn accounts_on_new_network_are_added() {
+        let from = profile();
+        let mut to = from.clone();
+        let carol = Account::placeholder_stokenet_carol();
+        to.networks.append(ProfileNetwork::new(
+            NetworkID::Stokenet,
+            Accounts::from_iter([carol.clone()]),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        ));
+        assert_eq!(
+            from.change_events(&to),
+            vec![ProfileChangeEvent::AccountAdded {
+                address: carol.address
+            }]
+        );
+    }
+
+    #[test]
+    fn factor_source_added() {
+        let from = profile();
+        let mut to = from.clone();
+        let ledger = FactorSource::placeholder_ledger();
+        to.factor_sources.append(ledger.clone());
+        assert_eq!(
+            from.change_events(&to),
+            vec![ProfileChangeEvent::FactorSourceAdded { id: ledger.id() }]
+        );
+    }
+
+    #[test]
+    fn gateway_switched() {
+        let from = profile();
+        let mut to = from.clone();
+        to.app_preferences
+            .gateways
+            .change_current(Gateway::stokenet())
+            .unwrap();
+        assert_eq!(
+            from.change_events(&to),
+            vec![ProfileChangeEvent::GatewaySwitched {
+                from: Gateway::mainnet().url,
+                to: Gateway::stokenet().url,
+            }]
+        );
+    }
+
+    #[test]
+    fn p2p_link_added_and_display_updated() {
+        let from = profile();
+        let mut to = from.clone();
+        let link = P2PLink::placeholder_brave();
+        to.app_preferences.p2p_links.append(link.clone());
+        to.app_preferences.display = AppDisplay::placeholder_other();
+        assert_eq!(
+            from.change_events(&to),
+            vec![
+                ProfileChangeEvent::P2PLinkAdded {
+                    id: link.connection_password.hash().into(),
+                    display_name: link.display_name.clone(),
+                },
+                ProfileChangeEvent::AppPreferencesUpdated
+            ]
+        );
+    }
+
+    #[test]
+    fn p2p_link_removed_does_not_contain_password() {
+        let link = P2PLink::placeholder_brave();
+        let mut from = profile();
+        from.app_preferences.p2p_links.append(link.clone());
+        let mut to = from.clone();
+        _ = to.app_preferences.p2p_links.remove_by_id(&link.id());
+        let events = from.change_events(&to);
+        assert_eq!(
+            events,
+            vec![ProfileChangeEvent::P2PLinkRemoved {
+                id: link.connection_password.hash().into(),
+                display_name: link.display_name.clone(),
+            }]
+        );
+        assert!(!format!("{:?}", events)
+            .contains(&link.connection_password.value.to_hex()));
+    }
+}
@@ -0,0 +1,367 @@
+use crate::prelude::*;
+
+/// A collection of identifiable items in a Profile, the preferences which
+/// are not collections are grouped under `AppPreferences`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileCollection {
+    /// The networks of the Profile, identified by `NetworkID`.
+    Networks,
+
+    /// The accounts on the network `network_id`, identified by address.
+    Accounts { network_id: NetworkID },
+
+    /// The personas on the network `network_id`, identified by address.
+    Personas { network_id: NetworkID },
+
+    /// The authorized dApps on the network `network_id`, identified by
+    /// dApp definition address.
+    AuthorizedDapps { network_id: NetworkID },
+
+    /// The factor sources, identified by `FactorSourceID`.
+    FactorSources,
+
+    /// The saved gateways which are not the current one, identified by URL.
+    Gateways,
+
+    /// The P2P links, identified by the hash of the connection password.
+    P2PLinks,
+
+    /// The app preferences which are not collections, identified by
+    /// `"display"`, `"security"`, `"transaction"` and `"currentGateway"`.
+    AppPreferences,
+}
+
+/// How an item changed between two Profiles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// A change of the item with `id` in `collection`, see [`Profile::diff`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ProfileChange {
+    pub collection: ProfileCollection,
+    pub id: String,
+    pub kind: ProfileChangeKind,
+}
+
+impl ProfileChange {
+    pub fn new(
+        collection: ProfileCollection,
+        id: impl Into<String>,
+        kind: ProfileChangeKind,
+    ) -> Self {
+        Self {
+            collection,
+            id: id.into(),
+            kind,
+        }
+    }
+}
+
+/// The structural difference between two Profiles, see [`Profile::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ProfileDiff {
+    pub changes: Vec<ProfileChange>,
+}
+
+impl ProfileDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The changes made to `collection`.
+    pub fn changes_in(
+        &self,
+        collection: &ProfileCollection,
+    ) -> Vec<ProfileChange> {
+        self.changes
+            .iter()
+            .filter(|c| &c.collection == collection)
+            .cloned()
+            .collect()
+    }
+}
+
+#[uniffi::export]
+pub fn profile_diff(from: &Profile, to: &Profile) -> ProfileDiff {
+    from.diff(to)
+}
+
+/// The ids of the items in `from` and `to`, in the order of `from` followed
+/// by the items only in `to` in their order.
+pub(crate) fn union_of_ids<E>(
+    from: &IdentifiedVecVia<E>,
+    to: &IdentifiedVecVia<E>,
+) -> Vec<E::ID>
+where
+    E: Identifiable + Debug + Clone,
+{
+    from.iter()
+        .map(|e| e.id())
+        .chain(to.iter().map(|e| e.id()).filter(|id| !from.contains_id(id)))
+        .collect()
+}
+
+/// The change of a single item with `id`, if any.
+fn change_of<T: PartialEq>(
+    collection: &ProfileCollection,
+    id: impl Into<String>,
+    from: Option<&T>,
+    to: Option<&T>,
+) -> Option<ProfileChange> {
+    let kind = match (from, to) {
+        (None, Some(_)) => ProfileChangeKind::Added,
+        (Some(_), None) => ProfileChangeKind::Removed,
+        (Some(f), Some(t)) if f != t => ProfileChangeKind::Modified,
+        _ => return None,
+    };
+    Some(ProfileChange::new(collection.clone(), id, kind))
+}
+
+fn diff_identified<E>(
+    collection: ProfileCollection,
+    from: &IdentifiedVecVia<E>,
+    to: &IdentifiedVecVia<E>,
+) -> Vec<ProfileChange>
+where
+    E: Identifiable + Debug + Clone + PartialEq,
+    E::ID: std::fmt::Display,
+{
+    union_of_ids(from, to)
+        .into_iter()
+        .filter_map(|id| {
+            change_of(&collection, id.to_string(), from.get(&id), to.get(&id))
+        })
+        .collect()
+}
+
+impl Profile {
+    /// The changes needed to go from this Profile to `other`, keyed by the
+    /// identifiers of the changed items. The header is not compared.
+    ///
+    /// Networks present in both Profiles are not reported as modified,
+    /// instead the changes of their accounts, personas and authorized dApps
+    /// are reported.
+    pub fn diff(&self, other: &Self) -> ProfileDiff {
+        let mut changes = Vec::<ProfileChange>::new();
+
+        for network_id in union_of_ids(&self.networks, &other.networks) {
+            match (
+                self.networks.get(&network_id),
+                other.networks.get(&network_id),
+            ) {
+                (Some(from), Some(to)) => {
+                    changes.extend(diff_identified(
+                        ProfileCollection::Accounts { network_id },
+                        &from.accounts,
+                        &to.accounts,
+                    ));
+                    changes.extend(diff_identified(
+                        ProfileCollection::Personas { network_id },
+                        &from.personas,
+                        &to.personas,
+                    ));
+                    changes.extend(diff_identified(
+                        ProfileCollection::AuthorizedDapps { network_id },
+                        &from.authorized_dapps,
+                        &to.authorized_dapps,
+                    ));
+                }
+                (from, to) => changes.extend(change_of(
+                    &ProfileCollection::Networks,
+                    network_id.to_string(),
+                    from,
+                    to,
+                )),
+            }
+        }
+
+        changes.extend(diff_identified(
+            ProfileCollection::FactorSources,
+            &self.factor_sources,
+            &other.factor_sources,
+        ));
+
+        let from = &self.app_preferences;
+        let to = &other.app_preferences;
+        changes.extend(diff_identified(
+            ProfileCollection::Gateways,
+            &from.gateways.other,
+            &to.gateways.other,
+        ));
+        changes.extend(diff_identified(
+            ProfileCollection::P2PLinks,
+            &from.p2p_links,
+            &to.p2p_links,
+        ));
+
+        let preferences = ProfileCollection::AppPreferences;
+        changes.extend(change_of(
+            &preferences,
+            "display",
+            Some(&from.display),
+            Some(&to.display),
+        ));
+        changes.extend(change_of(
+            &preferences,
+            "security",
+            Some(&from.security),
+            Some(&to.security),
+        ));
+        changes.extend(change_of(
+            &preferences,
+            "transaction",
+            Some(&from.transaction),
+            Some(&to.transaction),
+        ));
+        changes.extend(change_of(
+            &preferences,
+            "currentGateway",
+            Some(&from.gateways.current),
+            Some(&to.gateways.current),
+        ));
+
+        ProfileDiff { changes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn profile() -> Profile {
+        let mut profile = Profile::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            "Test",
+        );
+        profile.networks = ProfileNetworks::with_network(ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::from_iter([
+                Account::placeholder_mainnet_alice(),
+                Account::placeholder_mainnet_bob(),
+            ]),
+            Personas::from_iter([Persona::placeholder_mainnet_satoshi()]),
+            AuthorizedDapps::default(),
+        ));
+        profile
+    }
+
+    #[test]
+    fn diff_with_self_is_empty() {
+        let sut = profile();
+        assert!(sut.diff(&sut.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_accounts() {
+        let from = profile();
+        let mut to = from.clone();
+        let alice = Account::placeholder_mainnet_alice();
+        let bob = Account::placeholder_mainnet_bob();
+        let carol = Account::placeholder_mainnet_carol();
+        to.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.remove_by_id(&bob.address);
+            n.accounts.append(carol.clone());
+        });
+        to.update_account(&alice.address, |a| {
+            a.display_name = DisplayName::new("Renamed").unwrap()
+        });
+
+        let collection = ProfileCollection::Accounts {
+            network_id: NetworkID::Mainnet,
+        };
+        assert_eq!(
+            from.diff(&to).changes,
+            vec![
+                ProfileChange::new(
+                    collection.clone(),
+                    alice.address.to_string(),
+                    ProfileChangeKind::Modified
+                ),
+                ProfileChange::new(
+                    collection.clone(),
+                    bob.address.to_string(),
+                    ProfileChangeKind::Removed
+                ),
+                ProfileChange::new(
+                    collection,
+                    carol.address.to_string(),
+                    ProfileChangeKind::Added
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_added_network_is_reported_as_network() {
+        let from = profile();
+        let mut to = from.clone();
+        to.networks.append(ProfileNetwork::new(
+            NetworkID::Stokenet,
+            Accounts::from_iter([Account::placeholder_stokenet_carol()]),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        ));
+        assert_eq!(
+            from.diff(&to).changes,
+            vec![ProfileChange::new(
+                ProfileCollection::Networks,
+                NetworkID::Stokenet.to_string(),
+                ProfileChangeKind::Added
+            )]
+        );
+    }
+
+    #[test]
+    fn diff_preferences() {
+        let from = profile();
+        let mut to = from.clone();
+        to.app_preferences
+            .gateways
+            .change_current(Gateway::stokenet())
+            .unwrap();
+        to.app_preferences
+            .p2p_links
+            .append(P2PLink::placeholder_brave());
+
+        let diff = from.diff(&to);
+        assert_eq!(
+            diff.changes_in(&ProfileCollection::AppPreferences),
+            vec![ProfileChange::new(
+                ProfileCollection::AppPreferences,
+                "currentGateway",
+                ProfileChangeKind::Modified
+            )]
+        );
+        assert_eq!(
+            diff.changes_in(&ProfileCollection::Gateways)
+                .into_iter()
+                .map(|c| c.kind)
+                .collect_vec(),
+            vec![ProfileChangeKind::Removed, ProfileChangeKind::Added]
+        );
+        assert_eq!(
+            diff.changes_in(&ProfileCollection::P2PLinks)
+                .into_iter()
+                .map(|c| c.kind)
+                .collect_vec(),
+            vec![ProfileChangeKind::Added]
+        );
+    }
+
+    #[test]
+    fn diff_factor_sources() {
+        let from = profile();
+        let mut to = from.clone();
+        to.factor_sources.append(FactorSource::placeholder_ledger());
+        assert_eq!(
+            from.diff(&to)
+                .changes_in(&ProfileCollection::FactorSources)
+                .len(),
+            1
+        );
+    }
+}
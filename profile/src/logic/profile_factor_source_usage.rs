@@ -0,0 +1,380 @@
+use crate::prelude::*;
+
+/// Which parts of a Profile depend on a factor source, built with
+/// [`Profile::factor_source_usage`]. A factor source which is in use, see
+/// [`FactorSourceUsage::is_in_use`], cannot be removed.
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct FactorSourceUsage {
+    /// The ID of the factor source this is the usage of.
+    pub factor_source_id: FactorSourceID,
+
+    /// The accounts having a factor instance of the factor source, on any
+    /// network, including hidden ones.
+    pub accounts: Vec<AccountAddress>,
+
+    /// The personas having a factor instance of the factor source, on any
+    /// network, including hidden ones.
+    pub personas: Vec<IdentityAddress>,
+
+    /// The networks with accounts or personas using the factor source.
+    pub networks: Vec<NetworkID>,
+
+    /// The security structures of securified accounts or personas having a
+    /// factor instance of the factor source in any of their roles, without
+    /// duplicates.
+    pub security_structures: Vec<SecurityStructureOfFactorInstances>,
+}
+
+impl FactorSourceUsage {
+    fn new(factor_source_id: FactorSourceID) -> Self {
+        Self {
+            factor_source_id,
+            accounts: Vec::new(),
+            personas: Vec::new(),
+            networks: Vec::new(),
+            security_structures: Vec::new(),
+        }
+    }
+
+    /// `true` if any account or persona uses the factor source.
+    pub fn is_in_use(&self) -> bool {
+        !self.accounts.is_empty() || !self.personas.is_empty()
+    }
+
+    fn references(
+        &self,
+        instance: &HierarchicalDeterministicFactorInstance,
+    ) -> bool {
+        FactorSourceID::from(instance.factor_source_id.clone())
+            == self.factor_source_id
+    }
+
+    /// Records the network and security structure of an entity on
+    /// `network_id` with `security_state`, returns `false` without recording
+    /// anything if the entity does not use the factor source.
+    fn record(
+        &mut self,
+        network_id: NetworkID,
+        security_state: &EntitySecurityState,
+    ) -> bool {
+        if !security_state
+            .factor_instances()
+            .iter()
+            .any(|f| self.references(f))
+        {
+            return false;
+        }
+        if !self.networks.contains(&network_id) {
+            self.networks.push(network_id);
+        }
+        if let Ok(secured) = security_state.secured_entity_control() {
+            let structure = &secured.security_structure;
+            let is_referenced = structure
+                .primary_role
+                .all_factors()
+                .chain(structure.recovery_role.all_factors())
+                .chain(structure.confirmation_role.all_factors())
+                .any(|f| self.references(f));
+            if is_referenced && !self.security_structures.contains(structure) {
+                self.security_structures.push(structure.clone());
+            }
+        }
+        true
+    }
+}
+
+impl Profile {
+    /// The accounts and personas having a factor instance of the factor
+    /// source with `factor_source_id`, in the order of the Profile.
+    pub fn entities_controlled_by(
+        &self,
+        factor_source_id: &FactorSourceID,
+    ) -> Vec<AccountOrPersona> {
+        self.index().entities_controlled_by(factor_source_id)
+    }
+
+    /// The usage of the factor source with `factor_source_id`, which need
+    /// not be in this Profile.
+    pub fn factor_source_usage(
+        &self,
+        factor_source_id: &FactorSourceID,
+    ) -> FactorSourceUsage {
+        let mut usage = FactorSourceUsage::new(factor_source_id.clone());
+        for network in self.networks.iter() {
+            for account in network.accounts.iter() {
+                if usage.record(network.id, &account.security_state) {
+                    usage.accounts.push(account.address.clone());
+                }
+            }
+            for persona in network.personas.iter() {
+                if usage.record(network.id, &persona.security_state) {
+                    usage.personas.push(persona.address.clone());
+                }
+            }
+        }
+        usage
+    }
+
+    /// The usage of each factor source of this Profile, in the order of
+    /// `factor_sources`.
+    pub fn factor_source_usages(&self) -> Vec<FactorSourceUsage> {
+        self.factor_sources
+            .iter()
+            .map(|f| self.factor_source_usage(&f.factor_source_id()))
+            .collect()
+    }
+
+    /// `true` if the factor source with `factor_source_id` is the one new
+    /// accounts and personas are created with, see [`Profile::bdfs`], or is
+    /// the only factor source of this Profile.
+    fn is_main_factor_source(&self, factor_source_id: &FactorSourceID) -> bool {
+        let has_babylon_device = self.factor_sources.iter().any(|f| {
+            f.as_device()
+                .map(|d| d.common.supports_babylon())
+                .unwrap_or(false)
+        });
+        (has_babylon_device
+            && &self.bdfs().factor_source_id() == factor_source_id)
+            || self.factor_sources.len() == 1
+    }
+
+    /// Removes the factor source with `factor_source_id` and returns it,
+    /// throws an error if it is unknown, if it is the main one or if any
+    /// account or persona uses it.
+    pub fn remove_factor_source(
+        &mut self,
+        factor_source_id: &FactorSourceID,
+    ) -> Result<FactorSource> {
+        if self.factor_sources.get(factor_source_id).is_none() {
+            return Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                factor_source_id.clone(),
+            ));
+        }
+        if self.is_main_factor_source(factor_source_id) {
+            return Err(CommonError::CannotRemoveMainFactorSource(
+                factor_source_id.clone(),
+            ));
+        }
+        if self.factor_source_usage(factor_source_id).is_in_use() {
+            return Err(CommonError::FactorSourceInUse(
+                factor_source_id.clone(),
+            ));
+        }
+        Ok(self
+            .factor_sources
+            .remove_by_id(factor_source_id)
+            .expect("Factor source was just found"))
+    }
+
+    /// Flags the factor source with `factor_source_id` as deleted by the
+    /// user, hiding it without removing it, and returns it. Throws an error
+    /// if it is unknown or if it is the main one.
+    pub fn mark_factor_source_deleted_by_user(
+        &mut self,
+        factor_source_id: &FactorSourceID,
+    ) -> Result<FactorSource> {
+        if self.factor_sources.get(factor_source_id).is_none() {
+            return Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                factor_source_id.clone(),
+            ));
+        }
+        if self.is_main_factor_source(factor_source_id) {
+            return Err(CommonError::CannotRemoveMainFactorSource(
+                factor_source_id.clone(),
+            ));
+        }
+        self.factor_sources.update_with(factor_source_id, |f| {
+            let common = match f {
+                FactorSource::Device { value } => &mut value.common,
+                FactorSource::Ledger { value } => &mut value.common,
+            };
+            _ = common.flags.append(FactorSourceFlag::DeletedByUser);
+        });
+        Ok(self
+            .factor_sources
+            .get(factor_source_id)
+            .cloned()
+            .expect("Factor source was just updated"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// A Profile with a securified account using the placeholder ledger in
+    /// the recovery role of its security structure.
+    fn profile_with_ledger_in_security_structure(
+    ) -> (Profile, SecurityStructureOfFactorInstances) {
+        let mut profile = Profile::placeholder();
+        let ledger = LedgerHardwareWalletFactorSource::placeholder();
+        let device = HierarchicalDeterministicFactorInstance::placeholder();
+        let mut structure = SecurityStructureOfFactorInstances::placeholder();
+        structure.recovery_role = RoleOfFactorInstances::single_override(
+            HierarchicalDeterministicFactorInstance::new(
+                ledger.id.clone(),
+                device.public_key.clone(),
+            ),
+        );
+        let address = profile.networks[0].accounts[0].address.clone();
+        profile.update_account(&address, |a| {
+            a.security_state = SecuredEntityControl::new(
+                a.security_state.entity_creating_factor_instance(),
+                AccessControllerAddress::placeholder(),
+                structure.clone(),
+            )
+            .into()
+        });
+        (profile, structure)
+    }
+
+    #[test]
+    fn entities_controlled_by() {
+        let profile = Profile::placeholder();
+        let id = profile.bdfs().factor_source_id();
+        let entities = profile.entities_controlled_by(&id);
+        assert_eq!(entities, profile.index().entities_controlled_by(&id));
+        assert_eq!(
+            entities.len(),
+            profile
+                .networks
+                .iter()
+                .map(|n| n.accounts.len() + n.personas.len())
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn usage_of_bdfs() {
+        let profile = Profile::placeholder();
+        let usage =
+            profile.factor_source_usage(&profile.bdfs().factor_source_id());
+        assert!(usage.is_in_use());
+        assert_eq!(
+            usage.accounts,
+            profile.networks[0]
+                .accounts
+                .iter()
+                .chain(profile.networks[1].accounts.iter())
+                .map(|a| a.address.clone())
+                .collect_vec()
+        );
+        assert_eq!(
+            usage.networks,
+            profile.networks.iter().map(|n| n.id).collect_vec()
+        );
+        assert!(usage.security_structures.is_empty());
+    }
+
+    #[test]
+    fn usage_of_unused_ledger() {
+        let profile = Profile::placeholder();
+        let id =
+            LedgerHardwareWalletFactorSource::placeholder().factor_source_id();
+        let usage = profile.factor_source_usage(&id);
+        assert!(!usage.is_in_use());
+        assert!(usage.networks.is_empty());
+    }
+
+    #[test]
+    fn usage_of_ledger_in_security_structure() {
+        let (profile, structure) = profile_with_ledger_in_security_structure();
+        let usage = profile.factor_source_usage(
+            &LedgerHardwareWalletFactorSource::placeholder().factor_source_id(),
+        );
+        assert_eq!(
+            usage.accounts,
+            vec![profile.networks[0].accounts[0].address.clone()]
+        );
+        assert_eq!(usage.networks, vec![NetworkID::Mainnet]);
+        assert_eq!(usage.security_structures, vec![structure]);
+    }
+
+    #[test]
+    fn usages_of_all_factor_sources() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile
+                .factor_source_usages()
+                .into_iter()
+                .map(|u| (u.factor_source_id.clone(), u.is_in_use()))
+                .collect_vec(),
+            vec![
+                (profile.bdfs().factor_source_id(), true),
+                (
+                    LedgerHardwareWalletFactorSource::placeholder()
+                        .factor_source_id(),
+                    false
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_unused_factor_source() {
+        let mut profile = Profile::placeholder();
+        let ledger = FactorSource::placeholder_ledger();
+        assert_eq!(
+            profile.remove_factor_source(&ledger.factor_source_id()),
+            Ok(ledger)
+        );
+        assert_eq!(profile.factor_sources.len(), 1);
+    }
+
+    #[test]
+    fn remove_factor_source_in_use_fails() {
+        let (mut profile, _) = profile_with_ledger_in_security_structure();
+        let id =
+            LedgerHardwareWalletFactorSource::placeholder().factor_source_id();
+        assert_eq!(
+            profile.remove_factor_source(&id),
+            Err(CommonError::FactorSourceInUse(id))
+        );
+    }
+
+    #[test]
+    fn remove_main_factor_source_fails() {
+        let mut profile = Profile::placeholder();
+        let id = profile.bdfs().factor_source_id();
+        assert_eq!(
+            profile.remove_factor_source(&id),
+            Err(CommonError::CannotRemoveMainFactorSource(id))
+        );
+    }
+
+    #[test]
+    fn remove_unknown_factor_source_fails() {
+        let mut profile = Profile::placeholder();
+        let id = LedgerHardwareWalletFactorSource::placeholder_other()
+            .factor_source_id();
+        assert_eq!(
+            profile.remove_factor_source(&id),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(id))
+        );
+    }
+
+    #[test]
+    fn mark_factor_source_deleted_by_user() {
+        let mut profile = Profile::placeholder();
+        let id =
+            LedgerHardwareWalletFactorSource::placeholder().factor_source_id();
+        let marked = profile.mark_factor_source_deleted_by_user(&id).unwrap();
+        assert!(marked
+            .as_ledger()
+            .unwrap()
+            .common
+            .flags
+            .contains(&FactorSourceFlag::DeletedByUser));
+        assert_eq!(profile.factor_sources.get(&id), Some(&marked));
+    }
+
+    #[test]
+    fn mark_main_factor_source_deleted_by_user_fails() {
+        let mut profile = Profile::placeholder();
+        let id = profile.bdfs().factor_source_id();
+        assert_eq!(
+            profile.mark_factor_source_deleted_by_user(&id),
+            Err(CommonError::CannotRemoveMainFactorSource(id))
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use crate::prelude::*;
+
+/// Either an account or a persona.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum AccountOrPersona {
+    Account { value: Account },
+    Persona { value: Persona },
+}
+
+impl From<Account> for AccountOrPersona {
+    fn from(value: Account) -> Self {
+        Self::Account { value }
+    }
+}
+
+impl From<Persona> for AccountOrPersona {
+    fn from(value: Persona) -> Self {
+        Self::Persona { value }
+    }
+}
+
+/// The address of an indexed entity, resolved using the entity maps of
+/// [`ProfileIndex`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum IndexedEntity {
+    Account(AccountAddress),
+    Persona(IdentityAddress),
+}
+
+/// Lookup tables over the entities of a Profile, built with
+/// [`Profile::index`], answering all queries in O(1).
+///
+/// The index is a snapshot, it MUST be rebuilt when the Profile changes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileIndex {
+    accounts: HashMap<AccountAddress, Account>,
+    personas: HashMap<IdentityAddress, Persona>,
+    entity_by_public_key: HashMap<PublicKey, IndexedEntity>,
+    entities_by_factor_source: HashMap<FactorSourceID, Vec<IndexedEntity>>,
+    personas_by_dapp: HashMap<DappDefinitionAddress, Vec<IdentityAddress>>,
+}
+
+impl ProfileIndex {
+    pub fn new(profile: &Profile) -> Self {
+        let mut index = Self::default();
+        for network in profile.networks.iter() {
+            for account in network.accounts.iter() {
+                index.insert_security_state(
+                    IndexedEntity::Account(account.address.clone()),
+                    &account.security_state,
+                );
+                index
+                    .accounts
+                    .insert(account.address.clone(), account.clone());
+            }
+            for persona in network.personas.iter() {
+                index.insert_security_state(
+                    IndexedEntity::Persona(persona.address.clone()),
+                    &persona.security_state,
+                );
+                index
+                    .personas
+                    .insert(persona.address.clone(), persona.clone());
+            }
+            for dapp in network.authorized_dapps.iter() {
+                index.personas_by_dapp.insert(
+                    dapp.dapp_definition_address.clone(),
+                    dapp.references_to_authorized_personas
+                        .iter()
+                        .map(|r| r.identity_address.clone())
+                        .collect(),
+                );
+            }
+        }
+        index
+    }
+
+    fn insert_security_state(
+        &mut self,
+        entity: IndexedEntity,
+        security_state: &EntitySecurityState,
+    ) {
+        for factor_instance in security_state.factor_instances() {
+            self.entity_by_public_key
+                .entry(factor_instance.public_key.public_key)
+                .or_insert_with(|| entity.clone());
+
+            let entities = self
+                .entities_by_factor_source
+                .entry(factor_instance.factor_source_id.into())
+                .or_default();
+            if entities.last() != Some(&entity) {
+                entities.push(entity.clone());
+            }
+        }
+    }
+
+    fn resolve(&self, entity: &IndexedEntity) -> Option<AccountOrPersona> {
+        match entity {
+            IndexedEntity::Account(address) => {
+                self.account(address).cloned().map(AccountOrPersona::from)
+            }
+            IndexedEntity::Persona(address) => {
+                self.persona(address).cloned().map(AccountOrPersona::from)
+            }
+        }
+    }
+
+    /// The account with `address` on any network.
+    pub fn account(&self, address: &AccountAddress) -> Option<&Account> {
+        self.accounts.get(address)
+    }
+
+    /// The persona with `address` on any network.
+    pub fn persona(&self, address: &IdentityAddress) -> Option<&Persona> {
+        self.personas.get(address)
+    }
+
+    /// The entity having a factor instance with `public_key`.
+    pub fn entity_by_public_key(
+        &self,
+        public_key: &PublicKey,
+    ) -> Option<AccountOrPersona> {
+        self.entity_by_public_key
+            .get(public_key)
+            .and_then(|e| self.resolve(e))
+    }
+
+    /// The entities having a factor instance of the factor source with
+    /// `factor_source_id`, in the order of the Profile.
+    pub fn entities_controlled_by(
+        &self,
+        factor_source_id: &FactorSourceID,
+    ) -> Vec<AccountOrPersona> {
+        self.entities_by_factor_source
+            .get(factor_source_id)
+            .map(|entities| {
+                entities.iter().filter_map(|e| self.resolve(e)).collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The personas used to log in to the authorized dApp with
+    /// `dapp_definition_address`.
+    pub fn personas_of_authorized_dapp(
+        &self,
+        dapp_definition_address: &DappDefinitionAddress,
+    ) -> Vec<Persona> {
+        self.personas_by_dapp
+            .get(dapp_definition_address)
+            .map(|addresses| {
+                addresses
+                    .iter()
+                    .filter_map(|a| self.persona(a))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Profile {
+    /// Builds a [`ProfileIndex`] over this Profile.
+    pub fn index(&self) -> ProfileIndex {
+        ProfileIndex::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    const ACCOUNT_COUNT: u32 = 20;
+    const PERSONA_COUNT: u32 = 5;
+
+    /// A mainnet Profile with many entities all created with the placeholder
+    /// device factor source, see `benches/profile_index.rs` for a large one.
+    fn profile_with_many_entities() -> Profile {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let accounts = private
+            .derive_entity_creation_factor_instances(
+                NetworkID::Mainnet,
+                0..ACCOUNT_COUNT,
+            )
+            .into_iter()
+            .enumerate()
+            .map(|(i, instance)| {
+                Account::new(
+                    instance,
+                    DisplayName::new(&format!("Account {}", i)).unwrap(),
+                    AppearanceID::from_number_of_accounts_on_network(i),
+                )
+            });
+        let personas = private
+            .derive_entity_creation_factor_instances(
+                NetworkID::Mainnet,
+                0..PERSONA_COUNT,
+            )
+            .into_iter()
+            .enumerate()
+            .map(|(i, instance)| {
+                Persona::new(
+                    instance,
+                    DisplayName::new(&format!("Persona {}", i)).unwrap(),
+                    PersonaData::default(),
+                )
+            });
+
+        let mut profile = Profile::new(private, "Test");
+        profile.networks = ProfileNetworks::with_network(ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::from_iter(accounts),
+            Personas::from_iter(personas),
+            AuthorizedDapps::default(),
+        ));
+        profile
+    }
+
+    #[test]
+    fn lookups_in_placeholder() {
+        let profile = Profile::placeholder();
+        let sut = profile.index();
+
+        for network in profile.networks.iter() {
+            for account in network.accounts.iter() {
+                assert_eq!(sut.account(&account.address), Some(account));
+                assert_eq!(
+                    sut.entity_by_public_key(
+                        &account
+                            .security_state
+                            .entity_creating_factor_instance()
+                            .public_key
+                            .public_key
+                    ),
+                    Some(account.clone().into())
+                );
+            }
+            for persona in network.personas.iter() {
+                assert_eq!(sut.persona(&persona.address), Some(persona));
+            }
+            for dapp in network.authorized_dapps.iter() {
+                assert_eq!(
+                    sut.personas_of_authorized_dapp(
+                        &dapp.dapp_definition_address
+                    )
+                    .into_iter()
+                    .map(|p| p.address)
+                    .collect_vec(),
+                    dapp.references_to_authorized_personas
+                        .iter()
+                        .map(|r| r.identity_address.clone())
+                        .collect_vec()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_lookups() {
+        let sut = Profile::placeholder().index();
+        assert_eq!(sut.account(&Account::placeholder_nebunet().address), None);
+        assert_eq!(
+            sut.entities_controlled_by(
+                &FactorSource::placeholder_device_olympia().id()
+            ),
+            Vec::new()
+        );
+        assert_eq!(
+            sut.personas_of_authorized_dapp(
+                &AuthorizedDapp::placeholder_stokenet_devconsole()
+                    .dapp_definition_address
+            ),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn many_entities_lookups() {
+        let profile = profile_with_many_entities();
+        let sut = profile.index();
+
+        let network = &profile.networks[0];
+        for account in network.accounts.iter() {
+            assert_eq!(sut.account(&account.address), Some(account));
+        }
+        for persona in network.personas.iter() {
+            assert_eq!(
+                sut.entity_by_public_key(
+                    &persona
+                        .security_state
+                        .entity_creating_factor_instance()
+                        .public_key
+                        .public_key
+                ),
+                Some(persona.clone().into())
+            );
+        }
+
+        let controlled =
+            sut.entities_controlled_by(&profile.bdfs().factor_source_id());
+        assert_eq!(controlled.len(), (ACCOUNT_COUNT + PERSONA_COUNT) as usize);
+        assert_eq!(controlled[0], network.accounts[0].clone().into());
+        assert_eq!(
+            controlled[ACCOUNT_COUNT as usize],
+            network.personas[0].clone().into()
+        );
+    }
+}
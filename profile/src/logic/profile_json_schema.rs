@@ -0,0 +1,143 @@
+use crate::prelude::*;
+use std::sync::OnceLock;
+
+/// The JSON Schema of the Profile snapshot format, generated from the
+/// Profile model once and then shared.
+pub fn profile_json_schema() -> &'static RootSchema {
+    static SCHEMA: OnceLock<RootSchema> = OnceLock::new();
+    SCHEMA.get_or_init(|| schemars::schema_for!(Profile))
+}
+
+/// The JSON Schema of the Profile snapshot format as a JSON string.
+#[uniffi::export]
+pub fn profile_snapshot_json_schema() -> String {
+    serde_json::to_string_pretty(profile_json_schema())
+        .expect("JSON Schema should always be serializable")
+}
+
+/// All places where the Profile snapshot `json` does not conform to the
+/// schema of Profile, empty if it conforms. Throws if `json` is not JSON.
+#[uniffi::export]
+pub fn profile_snapshot_schema_violations(
+    json: String,
+) -> Result<Vec<JsonSchemaViolation>> {
+    serde_json::from_str::<serde_json::Value>(&json)
+        .map_err(|_| CommonError::FailedToDeserializeJSONToValue {
+            json_byte_count: json.len(),
+            type_name: "serde_json::Value".to_owned(),
+        })
+        .map(|value| {
+            JsonSchemaValidator::new(profile_json_schema()).violations(&value)
+        })
+}
+
+impl Profile {
+    /// Validates the Profile snapshot `json` against [`profile_json_schema`],
+    /// throws the first violation found, if any.
+    ///
+    /// Unlike deserializing `json`, which fails with the first error serde
+    /// runs into, this reports the path of the offending value.
+    pub fn validate_snapshot_json(json: &str) -> Result<()> {
+        match profile_snapshot_schema_violations(json.to_owned())?
+            .into_iter()
+            .next()
+        {
+            Some(violation) => {
+                Err(CommonError::ProfileSnapshotSchemaViolation {
+                    path: violation.path,
+                    reason: violation.reason,
+                })
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn placeholder_json() -> serde_json::Value {
+        serde_json::to_value(Profile::placeholder()).unwrap()
+    }
+
+    #[test]
+    fn placeholders_conform() {
+        for profile in [Profile::placeholder(), Profile::placeholder_other()] {
+            let json = serde_json::to_string(&profile).unwrap();
+            assert_eq!(
+                profile_snapshot_schema_violations(json),
+                Ok(Vec::new())
+            );
+        }
+    }
+
+    #[test]
+    fn schema_has_definitions_of_custom_serde() {
+        let definitions = &profile_json_schema().definitions;
+        for name in ["EntitySecurityState", "FactorSource", "PublicKey"] {
+            assert!(definitions.contains_key(name), "{} missing", name);
+        }
+    }
+
+    #[test]
+    fn schema_as_string_is_json() {
+        let schema: serde_json::Value =
+            serde_json::from_str(&profile_snapshot_json_schema()).unwrap();
+        assert_eq!(schema["title"], json!("Profile"));
+    }
+
+    #[test]
+    fn wrong_type_is_reported_with_path() {
+        let mut json = placeholder_json();
+        json["networks"][0]["accounts"][1]["displayName"] = json!(42);
+        assert_eq!(
+            Profile::validate_snapshot_json(&json.to_string()),
+            Err(CommonError::ProfileSnapshotSchemaViolation {
+                path: "/networks/0/accounts/1/displayName".to_owned(),
+                reason: "expected string, found integer".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn missing_discriminator_is_reported_with_path() {
+        let mut json = placeholder_json();
+        json["factorSources"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("discriminator");
+        assert_eq!(
+            profile_snapshot_schema_violations(json.to_string()),
+            Ok(vec![JsonSchemaViolation {
+                path: "/factorSources/0/discriminator".to_owned(),
+                reason: "missing required property".to_owned()
+            }])
+        );
+    }
+
+    #[test]
+    fn invalid_public_key_is_reported_with_path() {
+        let mut json = placeholder_json();
+        let path = "/networks/0/personas/0/securityState/unsecuredEntityControl/transactionSigning/badge/virtualSource/hierarchicalDeterministicPublicKey/publicKey/compressedData";
+        *json.pointer_mut(path).unwrap() = json!("deadbeef");
+        assert_eq!(
+            profile_snapshot_schema_violations(json.to_string()),
+            Ok(vec![JsonSchemaViolation {
+                path: path.to_owned(),
+                reason: "expected at least 64 characters, found 8".to_owned()
+            }])
+        );
+    }
+
+    #[test]
+    fn not_json_is_an_error() {
+        assert_eq!(
+            Profile::validate_snapshot_json("{"),
+            Err(CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: 1,
+                type_name: "serde_json::Value".to_owned()
+            })
+        );
+    }
+}
@@ -0,0 +1,618 @@
+use crate::prelude::*;
+
+/// How an item was changed on both sides of a merge, see [`Profile::merge`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileMergeConflictKind {
+    /// The item was modified differently on both sides.
+    BothModified,
+
+    /// The item was modified on one side and removed on the other.
+    ModifiedAndRemoved,
+
+    /// An item with the same id but different content was added on both
+    /// sides.
+    BothAdded,
+}
+
+/// Which side of a merge a conflict was resolved to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileMergeResolution {
+    KeptOurs,
+    KeptTheirs,
+}
+
+/// A conflict of the item with `id` in `collection` which was resolved
+/// automatically with `resolution`, but which the user might want to review.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ProfileMergeConflict {
+    pub collection: ProfileCollection,
+    pub id: String,
+    pub kind: ProfileMergeConflictKind,
+    pub resolution: ProfileMergeResolution,
+}
+
+/// The result of a three-way merge of Profiles, see [`Profile::merge`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ProfileMergeResult {
+    pub merged: Profile,
+    pub conflicts: Vec<ProfileMergeConflict>,
+
+    /// The issues of `merged` which could not be repaired, e.g. an account
+    /// added on one side using a factor source removed on the other side,
+    /// which the user needs to resolve, see [`Profile::validate`].
+    pub unresolved_issues: Vec<ProfileValidationIssue>,
+}
+
+#[uniffi::export]
+pub fn profile_merge(
+    base: &Profile,
+    ours: &Profile,
+    theirs: &Profile,
+) -> Result<ProfileMergeResult> {
+    Profile::merge(base, ours, theirs)
+}
+
+/// Three-way merges single items, collecting the conflicts.
+#[derive(Default)]
+struct ProfileMerger {
+    conflicts: Vec<ProfileMergeConflict>,
+}
+
+impl ProfileMerger {
+    /// Merges a single item which is `None` if it does not exist on that
+    /// side. Conflicts are resolved to the modified side if the other side
+    /// removed the item, else to ours.
+    fn merge_item<T: PartialEq + Clone>(
+        &mut self,
+        collection: &ProfileCollection,
+        id: impl Into<String>,
+        base: Option<&T>,
+        ours: Option<&T>,
+        theirs: Option<&T>,
+    ) -> Option<T> {
+        if ours == theirs || theirs == base {
+            return ours.cloned();
+        }
+        if ours == base {
+            return theirs.cloned();
+        }
+        let (kind, resolution) = match (base, ours, theirs) {
+            (None, _, _) => (
+                ProfileMergeConflictKind::BothAdded,
+                ProfileMergeResolution::KeptOurs,
+            ),
+            (Some(_), Some(_), Some(_)) => (
+                ProfileMergeConflictKind::BothModified,
+                ProfileMergeResolution::KeptOurs,
+            ),
+            (Some(_), Some(_), None) => (
+                ProfileMergeConflictKind::ModifiedAndRemoved,
+                ProfileMergeResolution::KeptOurs,
+            ),
+            (Some(_), None, _) => (
+                ProfileMergeConflictKind::ModifiedAndRemoved,
+                ProfileMergeResolution::KeptTheirs,
+            ),
+        };
+        self.conflicts.push(ProfileMergeConflict {
+            collection: collection.clone(),
+            id: id.into(),
+            kind,
+            resolution,
+        });
+        match resolution {
+            ProfileMergeResolution::KeptOurs => ours.cloned(),
+            ProfileMergeResolution::KeptTheirs => theirs.cloned(),
+        }
+    }
+
+    /// Merges the items of three collections, in the order of `ours`
+    /// followed by the items added in `theirs` in their order.
+    fn merge_identified<E>(
+        &mut self,
+        collection: ProfileCollection,
+        base: &IdentifiedVecVia<E>,
+        ours: &IdentifiedVecVia<E>,
+        theirs: &IdentifiedVecVia<E>,
+    ) -> IdentifiedVecVia<E>
+    where
+        E: Identifiable + Debug + Clone + PartialEq,
+        E::ID: std::fmt::Display,
+    {
+        union_of_ids(ours, theirs)
+            .into_iter()
+            .filter_map(|id| {
+                self.merge_item(
+                    &collection,
+                    id.to_string(),
+                    base.get(&id),
+                    ours.get(&id),
+                    theirs.get(&id),
+                )
+            })
+            .collect()
+    }
+
+    /// Networks existing on both sides are merged per collection, else
+    /// the network is merged as a single item.
+    fn merge_networks(
+        &mut self,
+        base: &ProfileNetworks,
+        ours: &ProfileNetworks,
+        theirs: &ProfileNetworks,
+    ) -> ProfileNetworks {
+        union_of_ids(ours, theirs)
+            .into_iter()
+            .filter_map(|network_id| {
+                match (ours.get(&network_id), theirs.get(&network_id)) {
+                    (Some(o), Some(t)) => {
+                        let empty = ProfileNetwork::new(
+                            network_id,
+                            Accounts::new(),
+                            Personas::new(),
+                            AuthorizedDapps::new(),
+                        );
+                        let b = base.get(&network_id).unwrap_or(&empty);
+                        Some(ProfileNetwork::new(
+                            network_id,
+                            self.merge_identified(
+                                ProfileCollection::Accounts { network_id },
+                                &b.accounts,
+                                &o.accounts,
+                                &t.accounts,
+                            ),
+                            self.merge_identified(
+                                ProfileCollection::Personas { network_id },
+                                &b.personas,
+                                &o.personas,
+                                &t.personas,
+                            ),
+                            self.merge_identified(
+                                ProfileCollection::AuthorizedDapps {
+                                    network_id,
+                                },
+                                &b.authorized_dapps,
+                                &o.authorized_dapps,
+                                &t.authorized_dapps,
+                            ),
+                        ))
+                    }
+                    (o, t) => self.merge_item(
+                        &ProfileCollection::Networks,
+                        network_id.to_string(),
+                        base.get(&network_id),
+                        o,
+                        t,
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn merge_app_preferences(
+        &mut self,
+        base: &AppPreferences,
+        ours: &AppPreferences,
+        theirs: &AppPreferences,
+    ) -> AppPreferences {
+        let preferences = ProfileCollection::AppPreferences;
+        let display = self
+            .merge_item(
+                &preferences,
+                "display",
+                Some(&base.display),
+                Some(&ours.display),
+                Some(&theirs.display),
+            )
+            .expect("Present on all sides");
+        let security = self
+            .merge_item(
+                &preferences,
+                "security",
+                Some(&base.security),
+                Some(&ours.security),
+                Some(&theirs.security),
+            )
+            .expect("Present on all sides");
+        let transaction = self
+            .merge_item(
+                &preferences,
+                "transaction",
+                Some(&base.transaction),
+                Some(&ours.transaction),
+                Some(&theirs.transaction),
+            )
+            .expect("Present on all sides");
+        let current = self
+            .merge_item(
+                &preferences,
+                "currentGateway",
+                Some(&base.gateways.current),
+                Some(&ours.gateways.current),
+                Some(&theirs.gateways.current),
+            )
+            .expect("Present on all sides");
+        let mut other = self.merge_identified(
+            ProfileCollection::Gateways,
+            &base.gateways.other,
+            &ours.gateways.other,
+            &theirs.gateways.other,
+        );
+        // If both sides switched to different gateways, the side whose
+        // gateway was not kept as current keeps it as another gateway.
+        for switched_to in [&ours.gateways.current, &theirs.gateways.current] {
+            if switched_to != &base.gateways.current
+                && switched_to.id() != current.id()
+                && !other.contains_id(&switched_to.id())
+            {
+                other.append(switched_to.clone());
+            }
+        }
+        other.remove_by_id(&current.id());
+        let p2p_links = self.merge_identified(
+            ProfileCollection::P2PLinks,
+            &base.p2p_links,
+            &ours.p2p_links,
+            &theirs.p2p_links,
+        );
+
+        AppPreferences {
+            display,
+            gateways: Gateways { current, other },
+            p2p_links,
+            security,
+            transaction,
+        }
+    }
+}
+
+impl Profile {
+    /// Three-way merges `ours` and `theirs`, two versions of the same
+    /// Profile edited on different devices, with `base`, their common
+    /// ancestor.
+    ///
+    /// Items are matched by their identifiers. An item changed on only one
+    /// side is taken from that side. An item changed on both sides is a
+    /// conflict which is resolved deterministically, by keeping the
+    /// modified item if the other side removed it, and else by keeping
+    /// ours. Resolved conflicts are returned so that they can be reviewed
+    /// by the user.
+    ///
+    /// The header is taken from `ours`, with the latest `last_modified` of
+    /// both sides and an updated content hint. Should the merge remove all
+    /// factor sources, the factor sources of `ours` are kept.
+    ///
+    /// Since items are merged independently, the merged Profile might
+    /// reference items removed on one side, e.g. a dApp authorized on one
+    /// side sharing an account removed on the other side. The merged
+    /// Profile is repaired, see [`Profile::repair`], and the issues which
+    /// cannot be repaired are returned as `unresolved_issues`.
+    pub fn merge(
+        base: &Self,
+        ours: &Self,
+        theirs: &Self,
+    ) -> Result<ProfileMergeResult> {
+        for other in [base, theirs] {
+            if other.id() != ours.id() {
+                return Err(CommonError::ProfileMergeMismatchingIDs {
+                    ours: ours.id().to_string(),
+                    theirs: other.id().to_string(),
+                });
+            }
+        }
+
+        let mut merger = ProfileMerger::default();
+        let networks = merger.merge_networks(
+            &base.networks,
+            &ours.networks,
+            &theirs.networks,
+        );
+        let mut factor_sources = merger.merge_identified(
+            ProfileCollection::FactorSources,
+            &base.factor_sources,
+            &ours.factor_sources,
+            &theirs.factor_sources,
+        );
+        if factor_sources.is_empty() {
+            factor_sources = ours.factor_sources.clone();
+        }
+        let app_preferences = merger.merge_app_preferences(
+            &base.app_preferences,
+            &ours.app_preferences,
+            &theirs.app_preferences,
+        );
+
+        let mut header = ours.header.clone();
+        header.last_modified =
+            ours.header.last_modified.max(theirs.header.last_modified);
+        header.content_hint = networks.content_hint();
+
+        let mut merged =
+            Profile::with(header, factor_sources, app_preferences, networks);
+        let unresolved_issues = merged.repair().issues;
+
+        Ok(ProfileMergeResult {
+            merged,
+            conflicts: merger.conflicts,
+            unresolved_issues,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn base() -> Profile {
+        let mut profile = Profile::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            "Test",
+        );
+        profile.networks = ProfileNetworks::with_network(ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::from_iter([
+                Account::placeholder_mainnet_alice(),
+                Account::placeholder_mainnet_bob(),
+            ]),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        ));
+        profile.header.content_hint = profile.networks.content_hint();
+        profile
+    }
+
+    fn rename(profile: &mut Profile, account: &Account, name: &str) {
+        profile.update_account(&account.address, |a| {
+            a.display_name = DisplayName::new(name).unwrap()
+        });
+    }
+
+    fn accounts(profile: &Profile) -> Vec<Account> {
+        profile
+            .networks
+            .get(&NetworkID::Mainnet)
+            .unwrap()
+            .accounts
+            .items()
+    }
+
+    #[test]
+    fn merge_unchanged_is_base() {
+        let base = base();
+        let result = Profile::merge(&base, &base, &base).unwrap();
+        assert_eq!(result.merged, base);
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[test]
+    fn merge_non_conflicting_changes() {
+        let base = base();
+        let alice = Account::placeholder_mainnet_alice();
+        let bob = Account::placeholder_mainnet_bob();
+        let carol = Account::placeholder_mainnet_carol();
+
+        let mut ours = base.clone();
+        rename(&mut ours, &alice, "Ours");
+        let mut theirs = base.clone();
+        theirs.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.remove_by_id(&bob.address);
+            n.accounts.append(carol.clone());
+        });
+        theirs
+            .app_preferences
+            .p2p_links
+            .append(P2PLink::placeholder_brave());
+
+        let result = Profile::merge(&base, &ours, &theirs).unwrap();
+        assert!(result.conflicts.is_empty());
+        assert_eq!(
+            accounts(&result.merged)
+                .into_iter()
+                .map(|a| (a.address, a.display_name.value))
+                .collect_vec(),
+            vec![
+                (alice.address, "Ours".to_string()),
+                (carol.address, carol.display_name.value),
+            ]
+        );
+        assert_eq!(result.merged.app_preferences.p2p_links.len(), 1);
+        assert_eq!(
+            result
+                .merged
+                .header
+                .content_hint
+                .number_of_accounts_on_all_networks_in_total,
+            2
+        );
+    }
+
+    #[test]
+    fn merge_both_modified_keeps_ours() {
+        let base = base();
+        let alice = Account::placeholder_mainnet_alice();
+        let mut ours = base.clone();
+        rename(&mut ours, &alice, "Ours");
+        let mut theirs = base.clone();
+        rename(&mut theirs, &alice, "Theirs");
+
+        let result = Profile::merge(&base, &ours, &theirs).unwrap();
+        assert_eq!(accounts(&result.merged)[0].display_name.value, "Ours");
+        assert_eq!(
+            result.conflicts,
+            vec![ProfileMergeConflict {
+                collection: ProfileCollection::Accounts {
+                    network_id: NetworkID::Mainnet
+                },
+                id: alice.address.to_string(),
+                kind: ProfileMergeConflictKind::BothModified,
+                resolution: ProfileMergeResolution::KeptOurs,
+            }]
+        );
+    }
+
+    #[test]
+    fn merge_modified_and_removed_keeps_modified() {
+        let base = base();
+        let bob = Account::placeholder_mainnet_bob();
+        let mut ours = base.clone();
+        ours.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.remove_by_id(&bob.address);
+        });
+        let mut theirs = base.clone();
+        rename(&mut theirs, &bob, "Theirs");
+
+        let result = Profile::merge(&base, &ours, &theirs).unwrap();
+        assert_eq!(
+            accounts(&result.merged)
+                .into_iter()
+                .map(|a| a.display_name.value)
+                .collect_vec(),
+            vec!["Alice".to_string(), "Theirs".to_string()]
+        );
+        assert_eq!(
+            result
+                .conflicts
+                .into_iter()
+                .map(|c| (c.kind, c.resolution))
+                .collect_vec(),
+            vec![(
+                ProfileMergeConflictKind::ModifiedAndRemoved,
+                ProfileMergeResolution::KeptTheirs
+            )]
+        );
+    }
+
+    #[test]
+    fn merge_current_gateway() {
+        let base = base();
+        let mut ours = base.clone();
+        ours.app_preferences
+            .gateways
+            .change_current(Gateway::stokenet())
+            .unwrap();
+
+        let result = Profile::merge(&base, &ours, &base).unwrap();
+        assert_eq!(
+            result.merged.app_preferences.gateways,
+            ours.app_preferences.gateways
+        );
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[test]
+    fn merge_both_switched_gateway_keeps_theirs_in_other() {
+        let base = base();
+        let mut ours = base.clone();
+        ours.app_preferences
+            .gateways
+            .change_current(Gateway::stokenet())
+            .unwrap();
+        let mut theirs = base.clone();
+        theirs
+            .app_preferences
+            .gateways
+            .change_current(Gateway::nebunet())
+            .unwrap();
+
+        let result = Profile::merge(&base, &ours, &theirs).unwrap();
+        let gateways = result.merged.app_preferences.gateways;
+        assert_eq!(gateways.current, Gateway::stokenet());
+        assert!(gateways.other.contains_id(&Gateway::nebunet().id()));
+        assert!(gateways.other.contains_id(&Gateway::mainnet().id()));
+        assert!(!gateways.other.contains_id(&Gateway::stokenet().id()));
+        assert_eq!(
+            result
+                .conflicts
+                .into_iter()
+                .map(|c| (c.collection, c.id))
+                .collect_vec(),
+            vec![(
+                ProfileCollection::AppPreferences,
+                "currentGateway".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn merge_repairs_references_to_account_removed_on_other_side() {
+        let base = base();
+        let bob = Account::placeholder_mainnet_bob();
+        let mut ours = base.clone();
+        ours.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts.remove_by_id(&bob.address);
+        });
+        let mut theirs = base.clone();
+        let persona = Persona::placeholder_mainnet();
+        let dapp = AuthorizedDapp::new(
+            NetworkID::Mainnet,
+            AuthorizedDapp::placeholder_mainnet_dashboard()
+                .dapp_definition_address,
+            "Dashboard".to_owned(),
+            IdentifiedVecVia::from_iter([AuthorizedPersonaSimple::new(
+                persona.address.clone(),
+                now(),
+                Some(SharedToDappWithPersonaAccountAddresses::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([bob.address.clone()]),
+                )),
+                SharedPersonaData::default(),
+            )]),
+        );
+        theirs.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.personas.append(persona.clone());
+            n.authorized_dapps.append(dapp.clone());
+        });
+
+        let result = Profile::merge(&base, &ours, &theirs).unwrap();
+        let merged_dapp = result
+            .merged
+            .networks
+            .get(&NetworkID::Mainnet)
+            .unwrap()
+            .authorized_dapps
+            .get(&dapp.dapp_definition_address)
+            .cloned()
+            .unwrap();
+        assert!(!accounts(&result.merged).contains(&bob));
+        assert_eq!(
+            merged_dapp.references_to_authorized_personas[0].shared_accounts,
+            None
+        );
+        assert!(!result.unresolved_issues.iter().any(|i| matches!(
+            i.kind,
+            ProfileValidationIssueKind::AuthorizedDappReferencesUnknownAccount { .. }
+        )));
+        assert_eq!(result.unresolved_issues, result.merged.validate().issues);
+    }
+
+    #[test]
+    fn merge_keeps_latest_last_modified() {
+        let base = base();
+        let ours = base.clone();
+        let mut theirs = base.clone();
+        theirs.header.last_modified =
+            Timestamp::parse("2099-01-01T00:00:00Z").unwrap();
+
+        let result = Profile::merge(&base, &ours, &theirs).unwrap();
+        assert_eq!(
+            result.merged.header.last_modified,
+            theirs.header.last_modified
+        );
+    }
+
+    #[test]
+    fn merge_different_profiles_fails() {
+        let base = base();
+        let other = Profile::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            "Other",
+        );
+        assert_eq!(
+            Profile::merge(&base, &base, &other),
+            Err(CommonError::ProfileMergeMismatchingIDs {
+                ours: base.id().to_string(),
+                theirs: other.id().to_string(),
+            })
+        );
+    }
+}
@@ -74,11 +74,11 @@ impl Profile {
                 n.accounts
                     .items()
                     .into_iter()
-                    .filter(|a| match &a.security_state {
-                        EntitySecurityState::Unsecured { value } => {
-                            value.transaction_signing.factor_source_id
-                                == factor_source_id
-                        }
+                    .filter(|a| {
+                        a.security_state
+                            .entity_creating_factor_instance()
+                            .factor_source_id
+                            == factor_source_id
                     })
                     .collect_vec()
                     .len()
@@ -0,0 +1,160 @@
+use crate::prelude::*;
+
+impl Profile {
+    /// Mutates the PersonaData of the Persona with `persona_address`, the
+    /// Persona is only updated if `mutate` succeeds.
+    pub fn update_persona_data<F, R>(
+        &mut self,
+        persona_address: &IdentityAddress,
+        mutate: F,
+    ) -> Result<R>
+    where
+        F: FnOnce(&mut PersonaData) -> Result<R>,
+    {
+        let mut persona = self
+            .networks
+            .get_persona(persona_address)
+            .ok_or(CommonError::UnknownPersona)?;
+        let result = mutate(&mut persona.persona_data)?;
+        self.update_persona(persona_address, |p| *p = persona.clone());
+        Ok(result)
+    }
+
+    /// Removes the PersonaData entry of `kind` with `id` from the Persona
+    /// with `persona_address`, and from the [`SharedPersonaData`] of every
+    /// [`AuthorizedDapp`] it has been shared with.
+    pub fn remove_persona_data_entry(
+        &mut self,
+        persona_address: &IdentityAddress,
+        kind: PersonaDataEntryKind,
+        id: &PersonaDataEntryID,
+    ) -> Result<()> {
+        self.update_persona_data(persona_address, |d| {
+            d.remove_entry(kind, id)
+        })?;
+        self.networks.update_with(&persona_address.network_id, |n| {
+            n.remove_references_to_persona_data_entry(persona_address, kind, id)
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn update_persona_data() {
+        let mut sut = Profile::placeholder();
+        let address = IdentityAddress::placeholder_stokenet();
+        let entry = sut
+            .update_persona_data(&address, |d| {
+                d.add_email_address(PersonaDataEntryEmailAddress::placeholder())
+            })
+            .unwrap();
+        assert!(sut
+            .networks
+            .get_persona(&address)
+            .unwrap()
+            .persona_data
+            .email_addresses
+            .contains_id(&entry.id));
+    }
+
+    #[test]
+    fn update_persona_data_failure_leaves_persona_unchanged() {
+        let mut sut = Profile::placeholder();
+        let address = IdentityAddress::placeholder_stokenet();
+        assert_eq!(
+            sut.update_persona_data(&address, |d| {
+                d.name = None;
+                d.add_phone_number(PersonaDataEntryPhoneNumber {
+                    number: "".to_owned(),
+                })
+            }),
+            Err(CommonError::PersonaDataInvalidPhoneNumberEmpty)
+        );
+        assert_eq!(
+            sut.networks.get_persona(&address),
+            Some(Persona::placeholder_stokenet_leia_skywalker())
+        );
+    }
+
+    #[test]
+    fn update_persona_data_unknown_persona() {
+        let mut sut = Profile::placeholder();
+        sut.networks.update_with(&NetworkID::Stokenet, |n| {
+            n.personas = Personas::new();
+        });
+        assert_eq!(
+            sut.update_persona_data(
+                &IdentityAddress::placeholder_stokenet(),
+                |_| Ok(())
+            ),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn remove_persona_data_entry_removes_shared_references() {
+        let mut sut = Profile::placeholder();
+        let leia = Persona::placeholder_stokenet_leia_skywalker();
+        let devconsole = AuthorizedDapp::placeholder_stokenet_devconsole()
+            .dapp_definition_address;
+        let phone_id = leia.persona_data.phone_numbers[0].id.clone();
+        sut.update_authorized_dapp_after_approval(
+            &devconsole,
+            None,
+            &leia.address,
+            None,
+            Some(SharedPersonaData::new(
+                None,
+                None,
+                SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([phone_id.clone()]),
+                ),
+            )),
+            now(),
+        )
+        .unwrap();
+
+        sut.remove_persona_data_entry(
+            &leia.address,
+            PersonaDataEntryKind::PhoneNumber,
+            &phone_id,
+        )
+        .unwrap();
+
+        let persona = sut.networks.get_persona(&leia.address).unwrap();
+        assert!(persona.persona_data.phone_numbers.is_empty());
+        let shared = sut
+            .networks
+            .get(&NetworkID::Stokenet)
+            .unwrap()
+            .authorized_dapps
+            .get_authorized_dapp_by_address(&devconsole)
+            .unwrap()
+            .references_to_authorized_personas
+            .get(&leia.address)
+            .unwrap()
+            .shared_persona_data
+            .clone();
+        // No longer fulfills `exactly(1)`, so no phone number is shared.
+        assert_eq!(shared.phone_numbers, None);
+    }
+
+    #[test]
+    fn remove_unknown_persona_data_entry() {
+        let mut sut = Profile::placeholder();
+        let id = PersonaDataEntryID::placeholder_four();
+        assert_eq!(
+            sut.remove_persona_data_entry(
+                &IdentityAddress::placeholder_stokenet(),
+                PersonaDataEntryKind::PhoneNumber,
+                &id
+            ),
+            Err(CommonError::UnknownPersonaDataEntry(id))
+        );
+    }
+}
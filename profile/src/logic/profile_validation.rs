@@ -0,0 +1,652 @@
+use crate::prelude::*;
+
+/// How severe a [`ProfileValidationIssue`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileValidationSeverity {
+    /// The Profile is inconsistent in a way that can cause loss of access to
+    /// entities or wrong data being shared with dApps.
+    Error,
+
+    /// The Profile is inconsistent in a way of no real consequence.
+    Warning,
+}
+
+/// An inconsistency found when validating a Profile, see
+/// [`Profile::validate`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileValidationIssueKind {
+    /// A factor instance of the entity at `entity_address` references a
+    /// factor source which is not in the Profile.
+    UnknownFactorSource {
+        entity_address: String,
+        factor_source_id: FactorSourceIDFromHash,
+    },
+
+    /// An authorized dApp references a persona which is not in the Profile.
+    AuthorizedDappReferencesUnknownPersona {
+        dapp_definition_address: DappDefinitionAddress,
+        identity_address: IdentityAddress,
+    },
+
+    /// An authorized dApp has been shared an account which is not in the
+    /// Profile.
+    AuthorizedDappReferencesUnknownAccount {
+        dapp_definition_address: DappDefinitionAddress,
+        account_address: AccountAddress,
+    },
+
+    /// The entity or authorized dApp with `address` is on `found` but is
+    /// stored in the network `expected`.
+    WrongNetwork {
+        address: String,
+        expected: NetworkID,
+        found: NetworkID,
+    },
+
+    /// The entities with `entity_addresses` were created with the same
+    /// public key.
+    DuplicatePublicKey {
+        public_key: PublicKey,
+        entity_addresses: Vec<String>,
+    },
+
+    /// The content hint of the header does not match the content.
+    ContentHintOutOfSync {
+        found: ContentHint,
+        expected: ContentHint,
+    },
+}
+
+impl ProfileValidationIssueKind {
+    /// The stable code of this kind of issue, errors are in the 1000 range
+    /// and warnings in the 2000 range. Codes MUST NOT be changed or reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::UnknownFactorSource { .. } => 1001,
+            Self::AuthorizedDappReferencesUnknownPersona { .. } => 1002,
+            Self::AuthorizedDappReferencesUnknownAccount { .. } => 1003,
+            Self::WrongNetwork { .. } => 1004,
+            Self::DuplicatePublicKey { .. } => 1005,
+            Self::ContentHintOutOfSync { .. } => 2001,
+        }
+    }
+
+    pub fn severity(&self) -> ProfileValidationSeverity {
+        match self {
+            Self::ContentHintOutOfSync { .. } => {
+                ProfileValidationSeverity::Warning
+            }
+            _ => ProfileValidationSeverity::Error,
+        }
+    }
+}
+
+/// An inconsistency found when validating a Profile, with its stable `code`
+/// and `severity`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ProfileValidationIssue {
+    pub code: u32,
+    pub severity: ProfileValidationSeverity,
+    pub kind: ProfileValidationIssueKind,
+}
+
+impl From<ProfileValidationIssueKind> for ProfileValidationIssue {
+    fn from(kind: ProfileValidationIssueKind) -> Self {
+        Self {
+            code: kind.code(),
+            severity: kind.severity(),
+            kind,
+        }
+    }
+}
+
+/// All issues found when validating a Profile, see [`Profile::validate`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ProfileValidationReport {
+    pub issues: Vec<ProfileValidationIssue>,
+}
+
+impl ProfileValidationReport {
+    pub fn errors(&self) -> Vec<ProfileValidationIssue> {
+        self.with_severity(ProfileValidationSeverity::Error)
+    }
+
+    pub fn warnings(&self) -> Vec<ProfileValidationIssue> {
+        self.with_severity(ProfileValidationSeverity::Warning)
+    }
+
+    /// If the Profile has no errors, it might still have warnings.
+    pub fn is_valid(&self) -> bool {
+        self.errors().is_empty()
+    }
+
+    fn with_severity(
+        &self,
+        severity: ProfileValidationSeverity,
+    ) -> Vec<ProfileValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == severity)
+            .cloned()
+            .collect()
+    }
+
+    /// The codes of the errors, e.g. `"1001, 1004"`.
+    fn error_codes_string(&self) -> String {
+        self.errors().iter().map(|i| i.code).unique().join(", ")
+    }
+}
+
+/// What to do with a Profile which fails validation when it is imported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfileImportPolicy {
+    /// Fail the import if the Profile has any errors.
+    Reject,
+
+    /// Repair what can safely be repaired, see [`Profile::repair`], then
+    /// fail the import if the Profile still has any errors.
+    Repair,
+
+    /// Import the Profile as is, logging any issues.
+    Accept,
+}
+
+#[uniffi::export]
+pub fn profile_validate(profile: &Profile) -> ProfileValidationReport {
+    profile.validate()
+}
+
+impl Profile {
+    /// Checks that this Profile is internally consistent, returning all
+    /// errors and warnings found.
+    pub fn validate(&self) -> ProfileValidationReport {
+        let mut issues = Vec::<ProfileValidationIssueKind>::new();
+        let mut entities_by_public_key =
+            HashMap::<PublicKey, Vec<String>>::new();
+
+        for network in self.networks.iter() {
+            let entities = network
+                .accounts
+                .iter()
+                .map(|a| {
+                    (
+                        a.address.address.clone(),
+                        vec![a.network_id, a.address.network_id],
+                        a.security_state.clone(),
+                    )
+                })
+                .chain(network.personas.iter().map(|p| {
+                    (
+                        p.address.address.clone(),
+                        vec![p.network_id, p.address.network_id],
+                        p.security_state.clone(),
+                    )
+                }))
+                .collect_vec();
+
+            for (address, network_ids, security_state) in entities {
+                if let Some(found) =
+                    network_ids.into_iter().find(|id| *id != network.id)
+                {
+                    issues.push(ProfileValidationIssueKind::WrongNetwork {
+                        address: address.clone(),
+                        expected: network.id,
+                        found,
+                    });
+                }
+
+                for factor_instance in security_state.factor_instances() {
+                    let factor_source_id = factor_instance.factor_source_id;
+                    if !self
+                        .factor_sources
+                        .contains_id(&factor_source_id.clone().into())
+                    {
+                        issues.push(
+                            ProfileValidationIssueKind::UnknownFactorSource {
+                                entity_address: address.clone(),
+                                factor_source_id,
+                            },
+                        );
+                    }
+                }
+
+                entities_by_public_key
+                    .entry(
+                        security_state
+                            .entity_creating_factor_instance()
+                            .public_key
+                            .public_key
+                            .clone(),
+                    )
+                    .or_default()
+                    .push(address);
+            }
+
+            for dapp in network.authorized_dapps.iter() {
+                let dapp_address = &dapp.dapp_definition_address;
+                if let Some(found) = [dapp.network_id, dapp_address.network_id]
+                    .into_iter()
+                    .find(|id| *id != network.id)
+                {
+                    issues.push(ProfileValidationIssueKind::WrongNetwork {
+                        address: dapp_address.address.clone(),
+                        expected: network.id,
+                        found,
+                    });
+                }
+
+                for reference in dapp.references_to_authorized_personas.iter() {
+                    if !network
+                        .personas
+                        .contains_id(&reference.identity_address)
+                    {
+                        issues.push(ProfileValidationIssueKind::AuthorizedDappReferencesUnknownPersona {
+                            dapp_definition_address: dapp_address.clone(),
+                            identity_address: reference.identity_address.clone(),
+                        });
+                    }
+                    for account_address in reference
+                        .shared_accounts
+                        .iter()
+                        .flat_map(|s| s.ids.iter())
+                        .filter(|a| !network.accounts.contains_id(a))
+                        .cloned()
+                    {
+                        issues.push(ProfileValidationIssueKind::AuthorizedDappReferencesUnknownAccount {
+                            dapp_definition_address: dapp_address.clone(),
+                            account_address,
+                        });
+                    }
+                }
+            }
+        }
+
+        issues.extend(
+            entities_by_public_key
+                .into_iter()
+                .filter(|(_, addresses)| addresses.len() > 1)
+                .sorted_by(|l, r| l.1.cmp(&r.1))
+                .map(|(public_key, entity_addresses)| {
+                    ProfileValidationIssueKind::DuplicatePublicKey {
+                        public_key,
+                        entity_addresses,
+                    }
+                }),
+        );
+
+        let expected = self.networks.content_hint();
+        if self.header.content_hint != expected {
+            issues.push(ProfileValidationIssueKind::ContentHintOutOfSync {
+                found: self.header.content_hint.clone(),
+                expected,
+            });
+        }
+
+        ProfileValidationReport {
+            issues: issues
+                .into_iter()
+                .map(ProfileValidationIssue::from)
+                .collect(),
+        }
+    }
+
+    /// Repairs the issues which can be repaired without losing access to
+    /// any entity:
+    /// * references to unknown personas are removed from authorized dApps,
+    /// and dApps left without any persona are forgotten.
+    /// * unknown accounts are removed from the accounts shared with dApps,
+    /// if the remaining accounts no longer fulfill the request of the dApp
+    /// no accounts are shared.
+    /// * the content hint is updated.
+    ///
+    /// Returns the report of the repaired Profile.
+    pub fn repair(&mut self) -> ProfileValidationReport {
+        let network_ids = self.networks.iter().map(|n| n.id).collect_vec();
+        for network_id in network_ids {
+            self.networks.update_with(&network_id, |network| {
+                let personas = network.personas.clone();
+                let accounts = network.accounts.clone();
+                let dapps = network
+                    .authorized_dapps
+                    .iter()
+                    .cloned()
+                    .filter_map(|mut dapp| {
+                        let references =
+                            dapp.references_to_authorized_personas
+                                .iter()
+                                .filter(|r| {
+                                    personas.contains_id(&r.identity_address)
+                                })
+                                .cloned()
+                                .map(|mut reference| {
+                                    if let Some(shared) =
+                                        reference.shared_accounts.as_mut()
+                                    {
+                                        shared.ids = shared
+                                            .ids
+                                            .iter()
+                                            .filter(|a| accounts.contains_id(a))
+                                            .cloned()
+                                            .collect();
+                                        if !shared.request.is_fulfilled_by_ids(
+                                            shared.ids.len(),
+                                        ) {
+                                            reference.shared_accounts = None;
+                                        }
+                                    }
+                                    reference
+                                })
+                                .collect::<IdentifiedVecVia<_>>();
+                        if references.is_empty() {
+                            return None;
+                        }
+                        dapp.references_to_authorized_personas = references;
+                        Some(dapp)
+                    })
+                    .collect();
+                network.authorized_dapps = dapps;
+            });
+        }
+        self.header.content_hint = self.networks.content_hint();
+        self.validate()
+    }
+
+    /// Validates this Profile when it is being imported, according to
+    /// `policy`, returning the Profile to import.
+    pub(crate) fn validated_for_import(
+        mut self,
+        policy: ProfileImportPolicy,
+    ) -> Result<Self> {
+        let report = match policy {
+            ProfileImportPolicy::Repair => self.repair(),
+            ProfileImportPolicy::Reject | ProfileImportPolicy::Accept => {
+                self.validate()
+            }
+        };
+        for issue in report.issues.iter() {
+            warn!("Imported Profile has issue: {:?}", issue);
+        }
+        if policy != ProfileImportPolicy::Accept && !report.is_valid() {
+            return Err(CommonError::ProfileFailedValidation(
+                report.error_codes_string(),
+            ));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn codes(report: &ProfileValidationReport) -> Vec<u32> {
+        report.issues.iter().map(|i| i.code).collect()
+    }
+
+    /// A new Profile with the accounts Alice and Bob and the persona Satoshi
+    /// on mainnet, all created with the main device factor source.
+    fn profile() -> Profile {
+        let mut profile = Profile::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder(),
+            "Test",
+        );
+        let network = ProfileNetwork::new(
+            NetworkID::Mainnet,
+            Accounts::from_iter([
+                Account::placeholder_mainnet_alice(),
+                Account::placeholder_mainnet_bob(),
+            ]),
+            Personas::from_iter([Persona::placeholder_mainnet_satoshi()]),
+            AuthorizedDapps::default(),
+        );
+        profile.networks = ProfileNetworks::with_network(network);
+        profile.header.content_hint = profile.networks.content_hint();
+        profile
+    }
+
+    fn with_dapp(
+        mut profile: Profile,
+        reference: AuthorizedPersonaSimple,
+    ) -> Profile {
+        let dapp = AuthorizedDapp::new(
+            NetworkID::Mainnet,
+            AuthorizedDapp::placeholder_mainnet().dapp_definition_address,
+            "Dashboard".to_owned(),
+            IdentifiedVecVia::from_iter([reference]),
+        );
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.authorized_dapps = AuthorizedDapps::from_iter([dapp.clone()]);
+        });
+        profile
+    }
+
+    fn reference(
+        persona: &Persona,
+        accounts: impl IntoIterator<Item = AccountAddress>,
+    ) -> AuthorizedPersonaSimple {
+        let accounts = accounts.into_iter().collect_vec();
+        let shared_accounts = (!accounts.is_empty()).then(|| {
+            SharedToDappWithPersonaAccountAddresses::new(
+                RequestedQuantity::at_least(1),
+                IdentifiedVecVia::from_iter(accounts),
+            )
+        });
+        AuthorizedPersonaSimple::new(
+            persona.address.clone(),
+            Timestamp::parse("2024-01-31T14:23:45Z").unwrap(),
+            shared_accounts,
+            SharedPersonaData::default(),
+        )
+    }
+
+    #[test]
+    fn consistent_profile_is_valid() {
+        let sut = with_dapp(
+            profile(),
+            reference(
+                &Persona::placeholder_mainnet_satoshi(),
+                [Account::placeholder_mainnet_alice().address],
+            ),
+        )
+        .validate();
+        assert_eq!(sut, ProfileValidationReport::default());
+        assert!(sut.is_valid());
+    }
+
+    #[test]
+    fn unknown_factor_source() {
+        let mut profile = profile();
+        profile.factor_sources =
+            FactorSources::from_iter([FactorSource::placeholder_ledger()]);
+        let sut = profile.validate();
+        assert!(!sut.is_valid());
+        assert!(sut.issues.contains(
+            &ProfileValidationIssueKind::UnknownFactorSource {
+                entity_address: Account::placeholder_mainnet_alice()
+                    .address
+                    .address,
+                factor_source_id: FactorSourceIDFromHash::placeholder_device(),
+            }
+            .into()
+        ));
+        assert_eq!(codes(&sut), vec![1001, 1001, 1001]);
+    }
+
+    #[test]
+    fn dapp_referencing_unknown_persona_and_account() {
+        let unknown_persona = Persona::placeholder_mainnet_batman();
+        let unknown_account = Account::placeholder_mainnet_carol().address;
+        let sut = with_dapp(
+            profile(),
+            reference(&unknown_persona, [unknown_account.clone()]),
+        )
+        .validate();
+        assert_eq!(codes(&sut), vec![1002, 1003]);
+        assert_eq!(
+            sut.issues[1].kind,
+            ProfileValidationIssueKind::AuthorizedDappReferencesUnknownAccount {
+                dapp_definition_address: AuthorizedDapp::placeholder_mainnet()
+                    .dapp_definition_address,
+                account_address: unknown_account,
+            }
+        );
+    }
+
+    #[test]
+    fn entity_on_wrong_network() {
+        let mut profile = profile();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts = Accounts::from_iter([Account::placeholder_stokenet()]);
+        });
+        let sut = profile.validate();
+        assert!(sut.errors().contains(
+            &ProfileValidationIssueKind::WrongNetwork {
+                address: Account::placeholder_stokenet().address.address,
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet,
+            }
+            .into()
+        ));
+    }
+
+    #[test]
+    fn persona_with_wrong_stored_network_id() {
+        let mut profile = profile();
+        let mut satoshi = Persona::placeholder_mainnet_satoshi();
+        satoshi.network_id = NetworkID::Stokenet;
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.personas = Personas::from_iter([satoshi.clone()]);
+        });
+        let sut = profile.validate();
+        assert_eq!(
+            sut.errors(),
+            vec![ProfileValidationIssueKind::WrongNetwork {
+                address: satoshi.address.address,
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet,
+            }
+            .into()]
+        );
+    }
+
+    #[test]
+    fn duplicate_public_key() {
+        let mut profile = profile();
+        let alice = Account::placeholder_mainnet_alice();
+        let mut bob = Account::placeholder_mainnet_bob();
+        bob.security_state = alice.security_state.clone();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.accounts = Accounts::from_iter([alice.clone(), bob.clone()]);
+        });
+        let sut = profile.validate();
+        assert_eq!(codes(&sut), vec![1005]);
+        assert_eq!(
+            sut.issues[0].kind,
+            ProfileValidationIssueKind::DuplicatePublicKey {
+                public_key: alice
+                    .security_state
+                    .entity_creating_factor_instance()
+                    .public_key
+                    .public_key
+                    .clone(),
+                entity_addresses: vec![
+                    alice.address.address.clone(),
+                    bob.address.address.clone()
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn content_hint_out_of_sync_is_warning() {
+        let mut profile = profile();
+        profile.header.content_hint = ContentHint::with_counters(0, 0, 0);
+        let sut = profile.validate();
+        assert!(sut.is_valid());
+        assert_eq!(sut.warnings().len(), 1);
+        assert_eq!(codes(&sut), vec![2001]);
+    }
+
+    #[test]
+    fn repair() {
+        let mut profile = with_dapp(
+            profile(),
+            reference(
+                &Persona::placeholder_mainnet_satoshi(),
+                [
+                    Account::placeholder_mainnet_alice().address,
+                    Account::placeholder_mainnet_carol().address,
+                ],
+            ),
+        );
+        profile.header.content_hint = ContentHint::with_counters(0, 0, 0);
+        assert_eq!(codes(&profile.validate()), vec![1003, 2001]);
+
+        let sut = profile.repair();
+        assert_eq!(sut, ProfileValidationReport::default());
+        let dapp = profile
+            .networks
+            .get(&NetworkID::Mainnet)
+            .unwrap()
+            .authorized_dapps
+            .first()
+            .unwrap();
+        let shared = dapp
+            .references_to_authorized_personas
+            .first()
+            .unwrap()
+            .shared_accounts
+            .unwrap();
+        assert_eq!(
+            shared.ids.items(),
+            vec![Account::placeholder_mainnet_alice().address]
+        );
+    }
+
+    #[test]
+    fn repair_forgets_dapp_without_known_persona() {
+        let mut profile = with_dapp(
+            profile(),
+            reference(&Persona::placeholder_mainnet_batman(), []),
+        );
+        assert!(profile.repair().is_valid());
+        assert!(profile
+            .networks
+            .get(&NetworkID::Mainnet)
+            .unwrap()
+            .authorized_dapps
+            .is_empty());
+    }
+
+    #[test]
+    fn import_policy() {
+        let invalid = with_dapp(
+            profile(),
+            reference(&Persona::placeholder_mainnet_batman(), []),
+        );
+        assert_eq!(
+            invalid
+                .clone()
+                .validated_for_import(ProfileImportPolicy::Reject),
+            Err(CommonError::ProfileFailedValidation("1002".to_owned()))
+        );
+        assert_eq!(
+            invalid
+                .clone()
+                .validated_for_import(ProfileImportPolicy::Accept),
+            Ok(invalid.clone())
+        );
+        let repaired = invalid
+            .validated_for_import(ProfileImportPolicy::Repair)
+            .unwrap();
+        assert!(repaired.validate().is_valid());
+
+        let mut unrepairable = profile();
+        unrepairable.factor_sources =
+            FactorSources::from_iter([FactorSource::placeholder_ledger()]);
+        assert_eq!(
+            unrepairable.validated_for_import(ProfileImportPolicy::Repair),
+            Err(CommonError::ProfileFailedValidation("1001".to_owned()))
+        );
+    }
+}
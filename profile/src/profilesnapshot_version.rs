@@ -7,6 +7,7 @@ use strum::FromRepr;
 #[derive(
     Serialize_repr,
     Deserialize_repr,
+    JsonSchema_repr,
     FromRepr,
     Clone,
     Copy,
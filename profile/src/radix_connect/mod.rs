@@ -0,0 +1,3 @@
+mod wallet_interaction;
+
+pub use wallet_interaction::*;
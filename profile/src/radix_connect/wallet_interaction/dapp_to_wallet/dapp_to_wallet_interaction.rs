@@ -0,0 +1,239 @@
+use crate::prelude::*;
+
+// Generate the FfiConverter needed by UniFFI for newtype `WalletInteractionId`.
+uniffi::custom_newtype!(WalletInteractionId, String);
+
+/// The ID of an interaction between a dApp and the wallet, chosen by
+/// the dApp (typically a UUID), the wallet MUST use the same ID in
+/// its response.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    derive_more::Debug,
+)]
+#[serde(transparent)]
+pub struct WalletInteractionId(pub String);
+
+impl HasPlaceholder for WalletInteractionId {
+    fn placeholder() -> Self {
+        Self("6ff8c1e6-1a7b-4f4c-8f2d-4a9d1e3c2b10".to_owned())
+    }
+
+    fn placeholder_other() -> Self {
+        Self("0c1a2b3c-4d5e-4f60-8a7b-9c8d7e6f5a40".to_owned())
+    }
+}
+
+/// A request sent by a dApp to the wallet (CAP21) over RadixConnect, e.g.
+/// "Login with a Persona and give me ongoing access to 2 accounts".
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteraction {
+    /// The ID of this interaction, used in the response.
+    pub interaction_id: WalletInteractionId,
+
+    /// The requested items, e.g. authorized request or transaction.
+    pub items: DappToWalletInteractionItems,
+
+    /// Information about the dApp and the network it is on.
+    pub metadata: DappToWalletInteractionMetadata,
+}
+
+/// Information about the dApp sending a [`DappToWalletInteraction`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteractionMetadata {
+    /// The version of the interaction format the dApp uses.
+    pub version: u64,
+
+    /// The network the dApp wants to interact with.
+    pub network_id: NetworkID,
+
+    /// The origin (website) from which the request was sent.
+    pub origin: Url,
+
+    /// The address of the account defining the dApp.
+    #[serde(rename = "dAppDefinitionAddress")]
+    pub dapp_definition_address: DappDefinitionAddress,
+}
+
+impl DappToWalletInteraction {
+    pub fn new(
+        interaction_id: WalletInteractionId,
+        items: DappToWalletInteractionItems,
+        metadata: DappToWalletInteractionMetadata,
+    ) -> Self {
+        Self {
+            interaction_id,
+            items,
+            metadata,
+        }
+    }
+
+    /// Tries to deserialize a JSON string, as sent by a dApp, into a
+    /// `DappToWalletInteraction`.
+    pub fn from_json_str(json: impl AsRef<str>) -> Result<Self> {
+        let json = json.as_ref();
+        serde_json::from_str(json).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: std::any::type_name::<Self>().to_string(),
+            }
+        })
+    }
+}
+
+impl DappToWalletInteractionMetadata {
+    pub fn new(
+        version: u64,
+        network_id: NetworkID,
+        origin: Url,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Self {
+        Self {
+            version,
+            network_id,
+            origin,
+            dapp_definition_address,
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn new_dapp_to_wallet_interaction_from_json_string(
+    json_string: String,
+) -> Result<DappToWalletInteraction> {
+    DappToWalletInteraction::from_json_str(json_string)
+}
+
+impl HasPlaceholder for DappToWalletInteractionMetadata {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        let dapp = AuthorizedDapp::placeholder_mainnet_dashboard();
+        Self::new(
+            2,
+            dapp.network_id,
+            Url::parse("https://dashboard.radixdlt.com").unwrap(),
+            dapp.dapp_definition_address,
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        let dapp = AuthorizedDapp::placeholder_stokenet_devconsole();
+        Self::new(
+            2,
+            dapp.network_id,
+            Url::parse("https://stokenet-console.radixdlt.com").unwrap(),
+            dapp.dapp_definition_address,
+        )
+    }
+}
+
+impl HasPlaceholder for DappToWalletInteraction {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            WalletInteractionId::placeholder(),
+            DappToWalletInteractionItems::placeholder(),
+            DappToWalletInteractionMetadata::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            WalletInteractionId::placeholder_other(),
+            DappToWalletInteractionItems::placeholder_other(),
+            DappToWalletInteractionMetadata::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DappToWalletInteraction;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            {
+                "interactionId": "6ff8c1e6-1a7b-4f4c-8f2d-4a9d1e3c2b10",
+                "items": {
+                    "discriminator": "authorizedRequest",
+                    "auth": {
+                        "discriminator": "loginWithoutChallenge"
+                    },
+                    "ongoingAccounts": {
+                        "numberOfAccounts": {
+                            "quantifier": "atLeast",
+                            "quantity": 1
+                        }
+                    },
+                    "ongoingPersonaData": {
+                        "isRequestingName": true,
+                        "numberOfRequestedEmailAddresses": {
+                            "quantifier": "atLeast",
+                            "quantity": 1
+                        }
+                    }
+                },
+                "metadata": {
+                    "version": 2,
+                    "networkId": 1,
+                    "origin": "https://dashboard.radixdlt.com/",
+                    "dAppDefinitionAddress": "account_rdx12x0xfz2yumu2qsh6yt0v8xjfc7et04vpsz775kc3yd3xvle4w5d5k5"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn from_json_str() {
+        let json = serde_json::to_string(&SUT::placeholder_other()).unwrap();
+        assert_eq!(SUT::from_json_str(json), Ok(SUT::placeholder_other()));
+    }
+
+    #[test]
+    fn from_json_str_invalid() {
+        assert!(matches!(
+            SUT::from_json_str("{}"),
+            Err(CommonError::FailedToDeserializeJSONToValue { .. })
+        ));
+    }
+
+    #[test]
+    fn from_json_string_via_uniffi() {
+        let json = serde_json::to_string(&SUT::placeholder()).unwrap();
+        assert_eq!(
+            new_dapp_to_wallet_interaction_from_json_string(json),
+            Ok(SUT::placeholder())
+        );
+    }
+}
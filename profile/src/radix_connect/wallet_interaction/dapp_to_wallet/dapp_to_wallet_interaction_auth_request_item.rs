@@ -0,0 +1,109 @@
+use crate::prelude::*;
+
+/// How a dApp wants the user to authenticate in an authorized request,
+/// either by logging in - with or without a challenge to sign - or by
+/// using a Persona which has previously been used to login to the dApp.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum DappToWalletInteractionAuthRequestItem {
+    /// Login with any Persona the user chooses, no proof of ownership
+    /// is requested.
+    LoginWithoutChallenge,
+
+    /// Login with any Persona the user chooses, and prove ownership
+    /// of it by signing `challenge`.
+    LoginWithChallenge { challenge: Hex32Bytes },
+
+    /// Use the Persona identified by `identity_address`, which MUST have
+    /// previously been used to login to the dApp.
+    UsePersona {
+        #[serde(rename = "identityAddress")]
+        identity_address: IdentityAddress,
+    },
+}
+
+impl DappToWalletInteractionAuthRequestItem {
+    /// Returns the address of the Persona to use if this is a
+    /// `UsePersona` request, else `None`.
+    pub fn persona_to_use(&self) -> Option<IdentityAddress> {
+        match self {
+            Self::UsePersona { identity_address } => {
+                Some(identity_address.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl HasPlaceholder for DappToWalletInteractionAuthRequestItem {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::LoginWithoutChallenge
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::UsePersona {
+            identity_address: IdentityAddress::placeholder_stokenet(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DappToWalletInteractionAuthRequestItem;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn persona_to_use() {
+        assert_eq!(SUT::placeholder().persona_to_use(), None);
+        assert_eq!(
+            SUT::placeholder_other().persona_to_use(),
+            Some(IdentityAddress::placeholder_stokenet())
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_login_with_challenge() {
+        let model = SUT::LoginWithChallenge {
+            challenge: Hex32Bytes::placeholder_dead(),
+        };
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "discriminator": "loginWithChallenge",
+                "challenge": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_use_persona() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder_other(),
+            r#"
+            {
+                "discriminator": "usePersona",
+                "identityAddress": "identity_tdx_2_12fk6qyu2860xyx2jk7j6ex464ccrnxrve4kpaa8qyxx99y5627ahhc"
+            }
+            "#,
+        );
+    }
+}
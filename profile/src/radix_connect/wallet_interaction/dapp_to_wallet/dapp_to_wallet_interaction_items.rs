@@ -0,0 +1,275 @@
+use crate::prelude::*;
+
+/// The kind of request a dApp sends to the wallet, an authorized request
+/// (login), an unauthorized request (one-time data) or a transaction.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum DappToWalletInteractionItems {
+    AuthorizedRequest {
+        #[serde(flatten)]
+        value: DappToWalletInteractionAuthorizedRequestItems,
+    },
+
+    UnauthorizedRequest {
+        #[serde(flatten)]
+        value: DappToWalletInteractionUnauthorizedRequestItems,
+    },
+
+    Transaction {
+        #[serde(flatten)]
+        value: DappToWalletInteractionTransactionItems,
+    },
+}
+
+/// The items of a request requiring the user to login with a Persona.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteractionAuthorizedRequestItems {
+    pub auth: DappToWalletInteractionAuthRequestItem,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset: Option<DappToWalletInteractionResetRequestItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ongoing_accounts: Option<DappToWalletInteractionAccountsRequestItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ongoing_persona_data:
+        Option<DappToWalletInteractionPersonaDataRequestItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_accounts: Option<DappToWalletInteractionAccountsRequestItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_persona_data:
+        Option<DappToWalletInteractionPersonaDataRequestItem>,
+}
+
+/// The items of a request NOT requiring the user to login, only one-time
+/// data can be requested.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteractionUnauthorizedRequestItems {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_accounts: Option<DappToWalletInteractionAccountsRequestItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_persona_data:
+        Option<DappToWalletInteractionPersonaDataRequestItem>,
+}
+
+/// The items of a transaction request.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct DappToWalletInteractionTransactionItems {
+    pub send: DappToWalletInteractionSendTransactionItem,
+}
+
+impl DappToWalletInteractionItems {
+    /// All requests for accounts, both ongoing and one-time.
+    pub fn accounts_requests(
+        &self,
+    ) -> Vec<DappToWalletInteractionAccountsRequestItem> {
+        match self {
+            Self::AuthorizedRequest { value } => [
+                value.ongoing_accounts.clone(),
+                value.one_time_accounts.clone(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect_vec(),
+            Self::UnauthorizedRequest { value } => {
+                value.one_time_accounts.clone().into_iter().collect_vec()
+            }
+            Self::Transaction { value: _ } => Vec::new(),
+        }
+    }
+
+    /// All requests for PersonaData, both ongoing and one-time.
+    pub fn persona_data_requests(
+        &self,
+    ) -> Vec<DappToWalletInteractionPersonaDataRequestItem> {
+        match self {
+            Self::AuthorizedRequest { value } => [
+                value.ongoing_persona_data.clone(),
+                value.one_time_persona_data.clone(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect_vec(),
+            Self::UnauthorizedRequest { value } => value
+                .one_time_persona_data
+                .clone()
+                .into_iter()
+                .collect_vec(),
+            Self::Transaction { value: _ } => Vec::new(),
+        }
+    }
+}
+
+impl From<DappToWalletInteractionAuthorizedRequestItems>
+    for DappToWalletInteractionItems
+{
+    fn from(value: DappToWalletInteractionAuthorizedRequestItems) -> Self {
+        Self::AuthorizedRequest { value }
+    }
+}
+
+impl From<DappToWalletInteractionUnauthorizedRequestItems>
+    for DappToWalletInteractionItems
+{
+    fn from(value: DappToWalletInteractionUnauthorizedRequestItems) -> Self {
+        Self::UnauthorizedRequest { value }
+    }
+}
+
+impl From<DappToWalletInteractionTransactionItems>
+    for DappToWalletInteractionItems
+{
+    fn from(value: DappToWalletInteractionTransactionItems) -> Self {
+        Self::Transaction { value }
+    }
+}
+
+impl HasPlaceholder for DappToWalletInteractionAuthorizedRequestItems {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self {
+            auth: DappToWalletInteractionAuthRequestItem::placeholder(),
+            reset: None,
+            ongoing_accounts: Some(
+                DappToWalletInteractionAccountsRequestItem::placeholder(),
+            ),
+            ongoing_persona_data: Some(
+                DappToWalletInteractionPersonaDataRequestItem::placeholder(),
+            ),
+            one_time_accounts: None,
+            one_time_persona_data: None,
+        }
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self {
+            auth: DappToWalletInteractionAuthRequestItem::placeholder_other(),
+            reset: Some(DappToWalletInteractionResetRequestItem {
+                accounts: false,
+                persona_data: true,
+            }),
+            ongoing_accounts: None,
+            ongoing_persona_data: Some(
+                DappToWalletInteractionPersonaDataRequestItem::placeholder_other(
+                ),
+            ),
+            one_time_accounts: Some(
+                DappToWalletInteractionAccountsRequestItem::placeholder_other(),
+            ),
+            one_time_persona_data: None,
+        }
+    }
+}
+
+impl HasPlaceholder for DappToWalletInteractionItems {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        DappToWalletInteractionAuthorizedRequestItems::placeholder().into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        DappToWalletInteractionAuthorizedRequestItems::placeholder_other()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DappToWalletInteractionItems;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn accounts_requests() {
+        assert_eq!(
+            SUT::placeholder().accounts_requests(),
+            vec![DappToWalletInteractionAccountsRequestItem::placeholder()]
+        );
+        let transaction: SUT = DappToWalletInteractionTransactionItems {
+            send: DappToWalletInteractionSendTransactionItem::placeholder(),
+        }
+        .into();
+        assert!(transaction.accounts_requests().is_empty());
+    }
+
+    #[test]
+    fn persona_data_requests() {
+        assert_eq!(
+            SUT::placeholder_other().persona_data_requests(),
+            vec![DappToWalletInteractionPersonaDataRequestItem::placeholder_other()]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_unauthorized() {
+        let model: SUT = DappToWalletInteractionUnauthorizedRequestItems {
+            one_time_accounts: Some(
+                DappToWalletInteractionAccountsRequestItem::placeholder(),
+            ),
+            one_time_persona_data: None,
+        }
+        .into();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "discriminator": "unauthorizedRequest",
+                "oneTimeAccounts": {
+                    "numberOfAccounts": {
+                        "quantifier": "atLeast",
+                        "quantity": 1
+                    }
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_transaction() {
+        let model: SUT = DappToWalletInteractionTransactionItems {
+            send: DappToWalletInteractionSendTransactionItem::placeholder(),
+        }
+        .into();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "discriminator": "transaction",
+                "send": {
+                    "transactionManifest": "CALL_METHOD Address(\"component_tdx_2_1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxyulkzl\") \"free\";",
+                    "version": 1
+                }
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::prelude::*;
+
+/// A request for access to accounts, e.g. "I want AT LEAST 1 account",
+/// optionally with a `challenge` the user should sign with each account
+/// to prove ownership.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteractionAccountsRequestItem {
+    pub number_of_accounts: RequestedQuantity,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<Hex32Bytes>,
+}
+
+impl DappToWalletInteractionAccountsRequestItem {
+    pub fn new(
+        number_of_accounts: RequestedQuantity,
+        challenge: impl Into<Option<Hex32Bytes>>,
+    ) -> Self {
+        Self {
+            number_of_accounts,
+            challenge: challenge.into(),
+        }
+    }
+}
+
+/// A request for PersonaData, e.g. "I want the name and EXACTLY 1 email
+/// address".
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteractionPersonaDataRequestItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_requesting_name: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_requested_email_addresses: Option<RequestedQuantity>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_requested_phone_numbers: Option<RequestedQuantity>,
+}
+
+impl DappToWalletInteractionPersonaDataRequestItem {
+    pub fn new(
+        is_requesting_name: impl Into<Option<bool>>,
+        number_of_requested_email_addresses: impl Into<Option<RequestedQuantity>>,
+        number_of_requested_phone_numbers: impl Into<Option<RequestedQuantity>>,
+    ) -> Self {
+        Self {
+            is_requesting_name: is_requesting_name.into(),
+            number_of_requested_email_addresses:
+                number_of_requested_email_addresses.into(),
+            number_of_requested_phone_numbers:
+                number_of_requested_phone_numbers.into(),
+        }
+    }
+
+    /// If the name is requested, `is_requesting_name` being `None` is
+    /// treated as NOT requesting the name.
+    pub fn requests_name(&self) -> bool {
+        self.is_requesting_name.unwrap_or(false)
+    }
+
+    /// All quantities in this request, used for validation.
+    pub(crate) fn requested_quantities(&self) -> Vec<RequestedQuantity> {
+        [
+            self.number_of_requested_email_addresses.clone(),
+            self.number_of_requested_phone_numbers.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect_vec()
+    }
+}
+
+/// A request from a dApp to reset previously shared ongoing data.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteractionResetRequestItem {
+    pub accounts: bool,
+    pub persona_data: bool,
+}
+
+/// A request from a dApp to sign and submit a transaction.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappToWalletInteractionSendTransactionItem {
+    /// The transaction manifest, in Radix Transaction Manifest (RTM) format.
+    pub transaction_manifest: String,
+
+    /// The version of the transaction manifest format.
+    pub version: u64,
+
+    /// Hex encoded blobs referenced by the manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blobs: Option<Vec<String>>,
+
+    /// An optional message to attach to the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl HasPlaceholder for DappToWalletInteractionAccountsRequestItem {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(RequestedQuantity::at_least(1), None)
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(RequestedQuantity::exactly(1), Hex32Bytes::placeholder())
+    }
+}
+
+impl HasPlaceholder for DappToWalletInteractionPersonaDataRequestItem {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(true, RequestedQuantity::at_least(1), None)
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(None, None, RequestedQuantity::at_least(1))
+    }
+}
+
+impl HasPlaceholder for DappToWalletInteractionSendTransactionItem {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self {
+            transaction_manifest: "CALL_METHOD Address(\"component_tdx_2_1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxyulkzl\") \"free\";".to_owned(),
+            version: 1,
+            blobs: None,
+            message: None,
+        }
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self {
+            message: Some("Thanks for the XRD!".to_owned()),
+            ..Self::placeholder()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn accounts_request_json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &DappToWalletInteractionAccountsRequestItem::placeholder_other(),
+            r#"
+            {
+                "numberOfAccounts": {
+                    "quantifier": "exactly",
+                    "quantity": 1
+                },
+                "challenge": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn persona_data_request_json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &DappToWalletInteractionPersonaDataRequestItem::placeholder_other(),
+            r#"
+            {
+                "numberOfRequestedPhoneNumbers": {
+                    "quantifier": "atLeast",
+                    "quantity": 1
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn persona_data_request_requests_name() {
+        assert!(DappToWalletInteractionPersonaDataRequestItem::placeholder()
+            .requests_name());
+        assert!(
+            !DappToWalletInteractionPersonaDataRequestItem::placeholder_other()
+                .requests_name()
+        );
+    }
+
+    #[test]
+    fn reset_request_json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &DappToWalletInteractionResetRequestItem {
+                accounts: true,
+                persona_data: false,
+            },
+            r#"
+            {
+                "accounts": true,
+                "personaData": false
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn send_transaction_json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &DappToWalletInteractionSendTransactionItem::placeholder_other(),
+            r#"
+            {
+                "transactionManifest": "CALL_METHOD Address(\"component_tdx_2_1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxyulkzl\") \"free\";",
+                "version": 1,
+                "message": "Thanks for the XRD!"
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod dapp_to_wallet_interaction;
+mod dapp_to_wallet_interaction_auth_request_item;
+mod dapp_to_wallet_interaction_items;
+mod dapp_to_wallet_interaction_request_items;
+
+pub use dapp_to_wallet_interaction::*;
+pub use dapp_to_wallet_interaction_auth_request_item::*;
+pub use dapp_to_wallet_interaction_items::*;
+pub use dapp_to_wallet_interaction_request_items::*;
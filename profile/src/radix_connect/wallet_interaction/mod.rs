@@ -0,0 +1,9 @@
+mod dapp_to_wallet;
+mod ongoing_requests_fulfillment;
+mod wallet_interaction_validation;
+mod wallet_to_dapp;
+
+pub use dapp_to_wallet::*;
+pub use ongoing_requests_fulfillment::*;
+pub use wallet_interaction_validation::*;
+pub use wallet_to_dapp::*;
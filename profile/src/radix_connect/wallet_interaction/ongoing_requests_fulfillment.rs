@@ -0,0 +1,561 @@
+use crate::prelude::*;
+
+/// The outcome of evaluating a request for *ongoing* accounts against the
+/// accounts the user has previously shared with the dApp.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum OngoingAccountsFulfillment {
+    /// The previously shared accounts fulfill the request, they can be sent
+    /// to the dApp without asking the user.
+    Fulfilled { accounts: Vec<Account> },
+
+    /// The user must select accounts fulfilling `request`, e.g. since it is
+    /// the first time the dApp asks for accounts, the dApp asks for another
+    /// quantity than before, some previously shared account has been hidden,
+    /// or the dApp wants proof of ownership (challenge).
+    RequiresApproval { request: RequestedQuantity },
+}
+
+/// The outcome of evaluating a request for *ongoing* PersonaData against the
+/// PersonaData entries the user has previously shared with the dApp.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct OngoingPersonaDataFulfillment {
+    /// Entries previously shared which still fulfill (parts of) the request,
+    /// these can be sent to the dApp without asking the user.
+    pub fulfilled: PersonaData,
+
+    /// The part of the request not fulfilled by previously shared entries,
+    /// which the user must approve, `None` if nothing remains.
+    pub requires_approval:
+        Option<DappToWalletInteractionPersonaDataRequestItem>,
+}
+
+/// The outcome of evaluating the *ongoing* requests of an authorized request
+/// from a dApp against what the user has previously shared with it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct OngoingRequestsFulfillment {
+    /// `None` if the dApp did not request ongoing accounts.
+    pub accounts: Option<OngoingAccountsFulfillment>,
+
+    /// `None` if the dApp did not request ongoing PersonaData.
+    pub persona_data: Option<OngoingPersonaDataFulfillment>,
+}
+
+impl OngoingRequestsFulfillment {
+    /// `true` if all ongoing requests can be responded to without asking the
+    /// user for approval.
+    pub fn is_fully_fulfilled(&self) -> bool {
+        let accounts_fulfilled = !matches!(
+            self.accounts,
+            Some(OngoingAccountsFulfillment::RequiresApproval { .. })
+        );
+        let persona_data_fulfilled = self
+            .persona_data
+            .as_ref()
+            .map(|p| p.requires_approval.is_none())
+            .unwrap_or(true);
+        accounts_fulfilled && persona_data_fulfilled
+    }
+}
+
+impl Profile {
+    /// Evaluates the ongoing requests in `items`, sent by the dApp with
+    /// `dapp_definition_address` to which the user logs in with the Persona
+    /// with `persona_address`, against the accounts and PersonaData the user
+    /// has previously shared with the dApp using that Persona.
+    ///
+    /// Previously shared entries which have since been removed, or accounts
+    /// which have been hidden, are not considered to fulfill any request. If
+    /// the dApp asks to `reset` accounts or PersonaData, previously shared
+    /// entries of that kind are ignored.
+    pub fn fulfill_ongoing_requests(
+        &self,
+        dapp_definition_address: &DappDefinitionAddress,
+        persona_address: &IdentityAddress,
+        items: &DappToWalletInteractionAuthorizedRequestItems,
+    ) -> Result<OngoingRequestsFulfillment> {
+        let persona = self.networks.get_persona(persona_address).ok_or(
+            CommonError::WalletInteractionUnknownPersona(
+                persona_address.clone(),
+            ),
+        )?;
+
+        let authorized_persona = self
+            .networks
+            .get(&persona_address.network_id)
+            .and_then(|n| {
+                n.authorized_dapps
+                    .get_authorized_dapp_by_address(dapp_definition_address)
+            })
+            .and_then(|d| {
+                d.references_to_authorized_personas.get(persona_address)
+            })
+            .cloned();
+
+        let reset = items.reset.clone();
+        let reset_accounts =
+            reset.as_ref().map(|r| r.accounts).unwrap_or(false);
+        let reset_persona_data =
+            reset.as_ref().map(|r| r.persona_data).unwrap_or(false);
+
+        let accounts = items.ongoing_accounts.as_ref().map(|request| {
+            let shared = authorized_persona
+                .as_ref()
+                .filter(|_| !reset_accounts)
+                .and_then(|p| p.shared_accounts.clone());
+            self.fulfill_ongoing_accounts(request, shared)
+        });
+
+        let persona_data = items.ongoing_persona_data.as_ref().map(|request| {
+            let shared = authorized_persona
+                .as_ref()
+                .filter(|_| !reset_persona_data)
+                .map(|p| p.shared_persona_data.clone())
+                .unwrap_or_default();
+            Self::fulfill_ongoing_persona_data(
+                &persona.persona_data,
+                request,
+                &shared,
+            )
+        });
+
+        Ok(OngoingRequestsFulfillment {
+            accounts,
+            persona_data,
+        })
+    }
+
+    /// The previously `shared` accounts fulfill `request` only if they were
+    /// shared for the very same requested quantity, e.g. accounts shared for
+    /// `exactly(1)` do not fulfill a request for `atLeast(1)`, even though
+    /// their number would, since the user chose them for another request.
+    fn fulfill_ongoing_accounts(
+        &self,
+        request: &DappToWalletInteractionAccountsRequestItem,
+        shared: Option<SharedToDappWithPersonaAccountAddresses>,
+    ) -> OngoingAccountsFulfillment {
+        let requires_approval = OngoingAccountsFulfillment::RequiresApproval {
+            request: request.number_of_accounts.clone(),
+        };
+        if request.challenge.is_some() {
+            return requires_approval;
+        }
+        let Some(shared) = shared else {
+            return requires_approval;
+        };
+        let accounts = shared
+            .ids
+            .iter()
+            .filter_map(|a| self.networks.get_account(a))
+            .filter(|a| !a.flags.contains(&EntityFlag::DeletedByUser))
+            .collect_vec();
+
+        if shared.request == request.number_of_accounts
+            && accounts.len() == shared.ids.len()
+            && request
+                .number_of_accounts
+                .is_fulfilled_by_ids(accounts.len())
+        {
+            OngoingAccountsFulfillment::Fulfilled { accounts }
+        } else {
+            requires_approval
+        }
+    }
+
+    fn fulfill_ongoing_persona_data(
+        persona_data: &PersonaData,
+        request: &DappToWalletInteractionPersonaDataRequestItem,
+        shared: &SharedPersonaData,
+    ) -> OngoingPersonaDataFulfillment {
+        let mut fulfilled = PersonaData::default();
+        let mut remaining =
+            DappToWalletInteractionPersonaDataRequestItem::default();
+
+        if request.requests_name() {
+            match persona_data
+                .name
+                .as_ref()
+                .filter(|n| shared.name.as_ref() == Some(&n.id))
+            {
+                Some(name) => fulfilled.name = Some(name.clone()),
+                None => remaining.is_requesting_name = Some(true),
+            }
+        }
+
+        if let Some(quantity) = &request.number_of_requested_email_addresses {
+            let entries = shared
+                .email_addresses
+                .as_ref()
+                .map(|s| {
+                    s.ids
+                        .iter()
+                        .filter_map(|id| persona_data.email_addresses.get(id))
+                        .cloned()
+                        .collect_vec()
+                })
+                .unwrap_or_default();
+            if quantity.is_fulfilled_by_ids(entries.len()) {
+                fulfilled.email_addresses =
+                    CollectionOfEmailAddresses::entries(entries);
+            } else {
+                remaining.number_of_requested_email_addresses =
+                    Some(quantity.clone());
+            }
+        }
+
+        if let Some(quantity) = &request.number_of_requested_phone_numbers {
+            let entries = shared
+                .phone_numbers
+                .as_ref()
+                .map(|s| {
+                    s.ids
+                        .iter()
+                        .filter_map(|id| persona_data.phone_numbers.get(id))
+                        .cloned()
+                        .collect_vec()
+                })
+                .unwrap_or_default();
+            if quantity.is_fulfilled_by_ids(entries.len()) {
+                fulfilled.phone_numbers =
+                    CollectionOfPhoneNumbers::entries(entries);
+            } else {
+                remaining.number_of_requested_phone_numbers =
+                    Some(quantity.clone());
+            }
+        }
+
+        let requires_approval = if remaining
+            == DappToWalletInteractionPersonaDataRequestItem::default()
+        {
+            None
+        } else {
+            Some(remaining)
+        };
+
+        OngoingPersonaDataFulfillment {
+            fulfilled,
+            requires_approval,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// Leia has shared her name, phone number and Carol with the
+    /// Dev Console.
+    fn profile() -> Profile {
+        let mut profile = Profile::placeholder();
+        let persona_data =
+            Persona::placeholder_stokenet_leia_skywalker().persona_data;
+        profile
+            .update_authorized_dapp_after_approval(
+                &devconsole(),
+                None,
+                &leia(),
+                Some(SharedToDappWithPersonaAccountAddresses::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([
+                        Account::placeholder_stokenet_carol().address,
+                    ]),
+                )),
+                Some(SharedPersonaData::new(
+                    persona_data.name.unwrap().id,
+                    SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                        RequestedQuantity::at_least(0),
+                        IdentifiedVecVia::new(),
+                    ),
+                    SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                        RequestedQuantity::exactly(1),
+                        IdentifiedVecVia::from_iter(
+                            persona_data.phone_numbers.ids(),
+                        ),
+                    ),
+                )),
+                now(),
+            )
+            .unwrap();
+        profile
+    }
+
+    fn devconsole() -> DappDefinitionAddress {
+        AuthorizedDapp::placeholder_stokenet_devconsole()
+            .dapp_definition_address
+    }
+
+    fn leia() -> IdentityAddress {
+        IdentityAddress::placeholder_stokenet()
+    }
+
+    fn items(
+        ongoing_accounts: impl Into<
+            Option<DappToWalletInteractionAccountsRequestItem>,
+        >,
+        ongoing_persona_data: impl Into<
+            Option<DappToWalletInteractionPersonaDataRequestItem>,
+        >,
+    ) -> DappToWalletInteractionAuthorizedRequestItems {
+        DappToWalletInteractionAuthorizedRequestItems {
+            auth: DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge,
+            reset: None,
+            ongoing_accounts: ongoing_accounts.into(),
+            ongoing_persona_data: ongoing_persona_data.into(),
+            one_time_accounts: None,
+            one_time_persona_data: None,
+        }
+    }
+
+    #[test]
+    fn fully_fulfilled() {
+        let sut = profile();
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(
+                    DappToWalletInteractionAccountsRequestItem::new(
+                        RequestedQuantity::exactly(1),
+                        None,
+                    ),
+                    DappToWalletInteractionPersonaDataRequestItem::new(
+                        true,
+                        None,
+                        RequestedQuantity::exactly(1),
+                    ),
+                ),
+            )
+            .unwrap();
+        assert!(fulfillment.is_fully_fulfilled());
+        assert_eq!(
+            fulfillment.accounts,
+            Some(OngoingAccountsFulfillment::Fulfilled {
+                accounts: vec![Account::placeholder_stokenet_carol()]
+            })
+        );
+        let persona_data =
+            Persona::placeholder_stokenet_leia_skywalker().persona_data;
+        assert_eq!(
+            fulfillment.persona_data,
+            Some(OngoingPersonaDataFulfillment {
+                fulfilled: PersonaData::new(
+                    persona_data.name,
+                    persona_data.phone_numbers,
+                    CollectionOfEmailAddresses::default()
+                ),
+                requires_approval: None
+            })
+        );
+    }
+
+    #[test]
+    fn other_quantity_of_accounts_requires_approval() {
+        let sut = profile();
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(
+                    DappToWalletInteractionAccountsRequestItem::new(
+                        RequestedQuantity::at_least(2),
+                        None,
+                    ),
+                    None,
+                ),
+            )
+            .unwrap();
+        assert!(!fulfillment.is_fully_fulfilled());
+        assert_eq!(
+            fulfillment.accounts,
+            Some(OngoingAccountsFulfillment::RequiresApproval {
+                request: RequestedQuantity::at_least(2)
+            })
+        );
+        assert_eq!(fulfillment.persona_data, None);
+    }
+
+    #[test]
+    fn other_quantity_of_accounts_fulfilled_by_shared_requires_approval() {
+        let sut = profile();
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(
+                    DappToWalletInteractionAccountsRequestItem::new(
+                        RequestedQuantity::at_least(1),
+                        None,
+                    ),
+                    None,
+                ),
+            )
+            .unwrap();
+        assert_eq!(
+            fulfillment.accounts,
+            Some(OngoingAccountsFulfillment::RequiresApproval {
+                request: RequestedQuantity::at_least(1)
+            })
+        );
+    }
+
+    #[test]
+    fn challenge_requires_approval() {
+        let sut = profile();
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(
+                    DappToWalletInteractionAccountsRequestItem::placeholder_other(),
+                    None,
+                ),
+            )
+            .unwrap();
+        assert_eq!(
+            fulfillment.accounts,
+            Some(OngoingAccountsFulfillment::RequiresApproval {
+                request: RequestedQuantity::exactly(1)
+            })
+        );
+    }
+
+    #[test]
+    fn hidden_account_requires_approval() {
+        let mut sut = profile();
+        sut.update_account(
+            &Account::placeholder_stokenet_carol().address,
+            |a| {
+                a.flags.insert_flag(EntityFlag::DeletedByUser);
+            },
+        );
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(
+                    DappToWalletInteractionAccountsRequestItem::new(
+                        RequestedQuantity::exactly(1),
+                        None,
+                    ),
+                    None,
+                ),
+            )
+            .unwrap();
+        assert!(!fulfillment.is_fully_fulfilled());
+    }
+
+    #[test]
+    fn reset_requires_approval() {
+        let sut = profile();
+        let mut items = items(
+            DappToWalletInteractionAccountsRequestItem::new(
+                RequestedQuantity::exactly(1),
+                None,
+            ),
+            DappToWalletInteractionPersonaDataRequestItem::new(
+                true, None, None,
+            ),
+        );
+        items.reset = Some(DappToWalletInteractionResetRequestItem {
+            accounts: false,
+            persona_data: true,
+        });
+        let fulfillment = sut
+            .fulfill_ongoing_requests(&devconsole(), &leia(), &items)
+            .unwrap();
+        assert!(matches!(
+            fulfillment.accounts,
+            Some(OngoingAccountsFulfillment::Fulfilled { .. })
+        ));
+        assert_eq!(
+            fulfillment.persona_data,
+            Some(OngoingPersonaDataFulfillment {
+                fulfilled: PersonaData::default(),
+                requires_approval: Some(
+                    DappToWalletInteractionPersonaDataRequestItem::new(
+                        true, None, None
+                    )
+                )
+            })
+        );
+    }
+
+    #[test]
+    fn delta_of_persona_data_requires_approval() {
+        let sut = profile();
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(
+                    None,
+                    DappToWalletInteractionPersonaDataRequestItem::new(
+                        true,
+                        RequestedQuantity::exactly(1),
+                        RequestedQuantity::exactly(1),
+                    ),
+                ),
+            )
+            .unwrap();
+        let persona_data = fulfillment.persona_data.unwrap();
+        assert_eq!(
+            persona_data.requires_approval,
+            Some(DappToWalletInteractionPersonaDataRequestItem::new(
+                None,
+                RequestedQuantity::exactly(1),
+                None
+            ))
+        );
+        assert!(persona_data.fulfilled.name.is_some());
+        assert_eq!(persona_data.fulfilled.phone_numbers.len(), 1);
+    }
+
+    #[test]
+    fn unknown_dapp_requires_approval() {
+        let sut = profile();
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &AccountAddress::placeholder_stokenet(),
+                &leia(),
+                &items(
+                    DappToWalletInteractionAccountsRequestItem::placeholder(),
+                    DappToWalletInteractionPersonaDataRequestItem::placeholder(
+                    ),
+                ),
+            )
+            .unwrap();
+        assert!(!fulfillment.is_fully_fulfilled());
+        assert_eq!(
+            fulfillment.persona_data.unwrap().requires_approval,
+            Some(DappToWalletInteractionPersonaDataRequestItem::placeholder())
+        );
+    }
+
+    #[test]
+    fn unknown_persona() {
+        let mut sut = profile();
+        sut.networks.update_with(&NetworkID::Stokenet, |n| {
+            n.personas = Personas::new();
+        });
+        assert_eq!(
+            sut.fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(None, None),
+            ),
+            Err(CommonError::WalletInteractionUnknownPersona(leia()))
+        );
+    }
+
+    #[test]
+    fn nothing_requested_is_fully_fulfilled() {
+        let sut = profile();
+        let fulfillment = sut
+            .fulfill_ongoing_requests(
+                &devconsole(),
+                &leia(),
+                &items(None, None),
+            )
+            .unwrap();
+        assert!(fulfillment.is_fully_fulfilled());
+    }
+}
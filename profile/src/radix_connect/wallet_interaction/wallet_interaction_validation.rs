@@ -0,0 +1,453 @@
+use crate::prelude::*;
+
+/// The outcome of successfully validating a [`DappToWalletInteraction`]
+/// against a [`Profile`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ValidatedDappToWalletInteraction {
+    /// The validated interaction.
+    pub interaction: DappToWalletInteraction,
+
+    /// The dApp sending the interaction if the user has previously
+    /// interacted with it, else `None`.
+    pub authorized_dapp: Option<AuthorizedDapp>,
+
+    /// The Persona the dApp asked to use (`usePersona`), if any.
+    pub persona: Option<Persona>,
+}
+
+impl Profile {
+    /// Validates `interaction` against this Profile, checking that:
+    /// * the network of the dApp is the current network of the wallet, and
+    /// the dApp definition address is on that network
+    /// * any Persona the dApp asks to use exists on the network of the dApp,
+    /// is not hidden and has been used to login to the dApp before
+    /// * requested quantities are valid and can be fulfilled by the
+    /// non-hidden accounts on the network (and the Persona to use).
+    pub fn validate_dapp_to_wallet_interaction(
+        &self,
+        interaction: DappToWalletInteraction,
+    ) -> Result<ValidatedDappToWalletInteraction> {
+        let network_id = interaction.metadata.network_id;
+        let current_network_id =
+            self.app_preferences.gateways.current.network.id;
+        if network_id != current_network_id {
+            return Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: current_network_id,
+                found: network_id,
+            });
+        }
+
+        let dapp_definition_address =
+            &interaction.metadata.dapp_definition_address;
+        if dapp_definition_address.network_id != network_id {
+            return Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: network_id,
+                found: dapp_definition_address.network_id,
+            });
+        }
+        let network = self.networks.get(&network_id);
+
+        let authorized_dapp = network.and_then(|n| {
+            n.authorized_dapps
+                .get_authorized_dapp_by_address(dapp_definition_address)
+                .cloned()
+        });
+
+        let persona = match &interaction.items {
+            DappToWalletInteractionItems::AuthorizedRequest { value } => {
+                match value.auth.persona_to_use() {
+                    Some(identity_address) => {
+                        Some(self.validate_persona_to_use(
+                            network_id,
+                            &identity_address,
+                            dapp_definition_address,
+                            authorized_dapp.as_ref(),
+                        )?)
+                    }
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+
+        let number_of_visible_accounts = network
+            .map(|n| {
+                n.accounts
+                    .iter()
+                    .filter(|a| !a.flags.contains(&EntityFlag::DeletedByUser))
+                    .count()
+            })
+            .unwrap_or(0);
+
+        for request in interaction.items.accounts_requests() {
+            let quantity = request.number_of_accounts;
+            Self::validate_requested_quantity(&quantity)?;
+            if !Self::can_fulfill(&quantity, number_of_visible_accounts) {
+                return Err(
+                    CommonError::WalletInteractionInsufficientAccounts {
+                        requested: quantity,
+                        available: number_of_visible_accounts,
+                    },
+                );
+            }
+        }
+
+        for request in interaction.items.persona_data_requests() {
+            for quantity in request.requested_quantities() {
+                Self::validate_requested_quantity(&quantity)?;
+            }
+            if let Some(persona) = persona.as_ref() {
+                Self::validate_persona_data_can_fulfill(
+                    &persona.persona_data,
+                    &request,
+                )?;
+            }
+        }
+
+        Ok(ValidatedDappToWalletInteraction {
+            interaction,
+            authorized_dapp,
+            persona,
+        })
+    }
+
+    fn validate_persona_to_use(
+        &self,
+        network_id: NetworkID,
+        identity_address: &IdentityAddress,
+        dapp_definition_address: &DappDefinitionAddress,
+        authorized_dapp: Option<&AuthorizedDapp>,
+    ) -> Result<Persona> {
+        if identity_address.network_id != network_id {
+            return Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: network_id,
+                found: identity_address.network_id,
+            });
+        }
+
+        let persona = self
+            .networks
+            .get(&network_id)
+            .and_then(|n| n.personas.get_persona_by_address(identity_address))
+            .filter(|p| !p.flags.contains(&EntityFlag::DeletedByUser))
+            .cloned()
+            .ok_or(CommonError::WalletInteractionUnknownPersona(
+                identity_address.clone(),
+            ))?;
+
+        let authorized_dapp = authorized_dapp.ok_or(
+            CommonError::WalletInteractionUnknownDapp(
+                dapp_definition_address.clone(),
+            ),
+        )?;
+
+        if !authorized_dapp
+            .references_to_authorized_personas
+            .contains_id(identity_address)
+        {
+            return Err(
+                CommonError::WalletInteractionPersonaNotAuthorizedByDapp(
+                    identity_address.clone(),
+                ),
+            );
+        }
+
+        Ok(persona)
+    }
+
+    fn validate_requested_quantity(quantity: &RequestedQuantity) -> Result<()> {
+        if quantity.quantifier == RequestedNumberQuantifier::Exactly
+            && quantity.quantity == 0
+        {
+            return Err(
+                CommonError::WalletInteractionInvalidRequestedQuantity(
+                    quantity.clone(),
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    /// If the user has `available` many entries to choose from, can they
+    /// select a subset fulfilling `quantity`?
+    fn can_fulfill(quantity: &RequestedQuantity, available: usize) -> bool {
+        available >= quantity.quantity as usize
+    }
+
+    fn validate_persona_data_can_fulfill(
+        persona_data: &PersonaData,
+        request: &DappToWalletInteractionPersonaDataRequestItem,
+    ) -> Result<()> {
+        if request.requests_name() && persona_data.name.is_none() {
+            return Err(CommonError::WalletInteractionInsufficientPersonaData(
+                "name".to_owned(),
+            ));
+        }
+        if let Some(quantity) = &request.number_of_requested_email_addresses {
+            if !Self::can_fulfill(quantity, persona_data.email_addresses.len())
+            {
+                return Err(
+                    CommonError::WalletInteractionInsufficientPersonaData(
+                        format!("{} email addresses", quantity),
+                    ),
+                );
+            }
+        }
+        if let Some(quantity) = &request.number_of_requested_phone_numbers {
+            if !Self::can_fulfill(quantity, persona_data.phone_numbers.len()) {
+                return Err(
+                    CommonError::WalletInteractionInsufficientPersonaData(
+                        format!("{} phone numbers", quantity),
+                    ),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn profile_on(network_id: NetworkID) -> Profile {
+        let mut profile = Profile::placeholder();
+        let gateway = match network_id {
+            NetworkID::Stokenet => Gateway::stokenet(),
+            _ => Gateway::mainnet(),
+        };
+        profile
+            .app_preferences
+            .gateways
+            .change_current(gateway)
+            .unwrap();
+        profile
+    }
+
+    fn authorized_request(
+        items: DappToWalletInteractionAuthorizedRequestItems,
+    ) -> DappToWalletInteraction {
+        let mut request = DappToWalletInteraction::placeholder();
+        request.items = items.into();
+        request
+    }
+
+    #[test]
+    fn valid_login_from_known_dapp() {
+        let sut = profile_on(NetworkID::Mainnet);
+        let validated = sut
+            .validate_dapp_to_wallet_interaction(
+                DappToWalletInteraction::placeholder(),
+            )
+            .unwrap();
+        assert_eq!(
+            validated.authorized_dapp,
+            Some(AuthorizedDapp::placeholder_mainnet_dashboard())
+        );
+        assert_eq!(validated.persona, None);
+    }
+
+    #[test]
+    fn valid_login_from_unknown_dapp() {
+        let sut = profile_on(NetworkID::Mainnet);
+        let mut request = DappToWalletInteraction::placeholder();
+        request.metadata.dapp_definition_address =
+            AccountAddress::placeholder_mainnet();
+        let validated =
+            sut.validate_dapp_to_wallet_interaction(request).unwrap();
+        assert_eq!(validated.authorized_dapp, None);
+    }
+
+    #[test]
+    fn wrong_network() {
+        let sut = profile_on(NetworkID::Stokenet);
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(
+                DappToWalletInteraction::placeholder()
+            ),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn dapp_definition_address_on_other_network() {
+        let sut = profile_on(NetworkID::Mainnet);
+        let mut request = DappToWalletInteraction::placeholder();
+        request.metadata.dapp_definition_address =
+            AccountAddress::placeholder_stokenet();
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(request),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+
+    #[test]
+    fn use_persona() {
+        let sut = profile_on(NetworkID::Stokenet);
+        let validated = sut
+            .validate_dapp_to_wallet_interaction(
+                DappToWalletInteraction::placeholder_other(),
+            )
+            .unwrap();
+        assert_eq!(
+            validated.persona,
+            Some(Persona::placeholder_stokenet_leia_skywalker())
+        );
+    }
+
+    #[test]
+    fn use_persona_from_unknown_dapp() {
+        let sut = profile_on(NetworkID::Stokenet);
+        let mut request = DappToWalletInteraction::placeholder_other();
+        request.metadata.dapp_definition_address =
+            AccountAddress::placeholder_stokenet();
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(request),
+            Err(CommonError::WalletInteractionUnknownDapp(
+                AccountAddress::placeholder_stokenet()
+            ))
+        );
+    }
+
+    #[test]
+    fn use_unknown_persona() {
+        let mut sut = profile_on(NetworkID::Stokenet);
+        sut.networks.update_with(&NetworkID::Stokenet, |n| {
+            n.personas = Personas::new();
+        });
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(
+                DappToWalletInteraction::placeholder_other()
+            ),
+            Err(CommonError::WalletInteractionUnknownPersona(
+                IdentityAddress::placeholder_stokenet()
+            ))
+        );
+    }
+
+    #[test]
+    fn use_hidden_persona() {
+        let mut sut = profile_on(NetworkID::Stokenet);
+        sut.update_persona(&IdentityAddress::placeholder_stokenet(), |p| {
+            p.flags.insert_flag(EntityFlag::DeletedByUser);
+        });
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(
+                DappToWalletInteraction::placeholder_other()
+            ),
+            Err(CommonError::WalletInteractionUnknownPersona(
+                IdentityAddress::placeholder_stokenet()
+            ))
+        );
+    }
+
+    #[test]
+    fn use_persona_on_other_network() {
+        let sut = profile_on(NetworkID::Stokenet);
+        let mut request = DappToWalletInteraction::placeholder_other();
+        let DappToWalletInteractionItems::AuthorizedRequest { value: items } =
+            &mut request.items
+        else {
+            panic!("Expected authorized request")
+        };
+        items.auth = DappToWalletInteractionAuthRequestItem::UsePersona {
+            identity_address: IdentityAddress::placeholder_mainnet(),
+        };
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(request),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn use_persona_not_authorized_by_dapp() {
+        let sut = profile_on(NetworkID::Stokenet);
+        let mut request = DappToWalletInteraction::placeholder_other();
+        // Sandbox has only been used with Hermione
+        request.metadata.dapp_definition_address =
+            AuthorizedDapp::placeholder_stokenet_sandbox()
+                .dapp_definition_address;
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(request),
+            Err(CommonError::WalletInteractionPersonaNotAuthorizedByDapp(
+                IdentityAddress::placeholder_stokenet()
+            ))
+        );
+    }
+
+    #[test]
+    fn insufficient_accounts() {
+        let sut = profile_on(NetworkID::Mainnet);
+        let mut items =
+            DappToWalletInteractionAuthorizedRequestItems::placeholder();
+        // Only Alice is visible, Bob is hidden.
+        items.ongoing_accounts =
+            Some(DappToWalletInteractionAccountsRequestItem::new(
+                RequestedQuantity::exactly(2),
+                None,
+            ));
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(authorized_request(items)),
+            Err(CommonError::WalletInteractionInsufficientAccounts {
+                requested: RequestedQuantity::exactly(2),
+                available: 1
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_requested_quantity() {
+        let sut = profile_on(NetworkID::Mainnet);
+        let mut items =
+            DappToWalletInteractionAuthorizedRequestItems::placeholder();
+        let invalid = RequestedQuantity {
+            quantifier: RequestedNumberQuantifier::Exactly,
+            quantity: 0,
+        };
+        items.one_time_persona_data =
+            Some(DappToWalletInteractionPersonaDataRequestItem::new(
+                None,
+                invalid.clone(),
+                None,
+            ));
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(authorized_request(items)),
+            Err(CommonError::WalletInteractionInvalidRequestedQuantity(
+                invalid
+            ))
+        );
+    }
+
+    #[test]
+    fn insufficient_persona_data() {
+        let sut = profile_on(NetworkID::Stokenet);
+        let mut request = DappToWalletInteraction::placeholder_other();
+        let DappToWalletInteractionItems::AuthorizedRequest { value: items } =
+            &mut request.items
+        else {
+            panic!("Expected authorized request")
+        };
+        items.one_time_accounts = None;
+        items.ongoing_persona_data =
+            Some(DappToWalletInteractionPersonaDataRequestItem::new(
+                None,
+                None,
+                RequestedQuantity::at_least(10),
+            ));
+        assert_eq!(
+            sut.validate_dapp_to_wallet_interaction(request),
+            Err(CommonError::WalletInteractionInsufficientPersonaData(
+                "AtLeast: 10 phone numbers".to_owned()
+            ))
+        );
+    }
+}
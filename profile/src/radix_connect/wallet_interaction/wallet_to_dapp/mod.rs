@@ -0,0 +1,7 @@
+mod wallet_to_dapp_interaction_response;
+mod wallet_to_dapp_interaction_response_builder;
+mod wallet_to_dapp_interaction_response_items;
+
+pub use wallet_to_dapp_interaction_response::*;
+pub use wallet_to_dapp_interaction_response_builder::*;
+pub use wallet_to_dapp_interaction_response_items::*;
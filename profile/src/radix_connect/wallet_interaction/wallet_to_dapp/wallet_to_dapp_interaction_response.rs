@@ -0,0 +1,267 @@
+use crate::prelude::*;
+
+/// The response the wallet sends back to a dApp for a
+/// [`DappToWalletInteraction`], either a success or a failure.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum WalletToDappInteractionResponse {
+    Success {
+        #[serde(flatten)]
+        value: WalletToDappInteractionSuccessResponse,
+    },
+    Failure {
+        #[serde(flatten)]
+        value: WalletToDappInteractionFailureResponse,
+    },
+}
+
+/// A successful response to a [`DappToWalletInteraction`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionSuccessResponse {
+    pub interaction_id: WalletInteractionId,
+    pub items: WalletToDappInteractionResponseItems,
+}
+
+/// A failed response to a [`DappToWalletInteraction`], e.g. if the user
+/// rejected the request.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionFailureResponse {
+    pub interaction_id: WalletInteractionId,
+    pub error: DappWalletInteractionErrorType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// The kind of error a wallet reports back to a dApp.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    strum_macros::Display,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DappWalletInteractionErrorType {
+    RejectedByUser,
+    WrongNetwork,
+    FailedToPrepareTransaction,
+    FailedToCompileTransaction,
+    FailedToSignTransaction,
+    FailedToSubmitTransaction,
+    FailedToPollSubmittedTransaction,
+    FailedToFindAccountWithEnoughFundsToLockFee,
+    SubmittedTransactionWasDuplicate,
+    SubmittedTransactionHasFailedTransactionStatus,
+    SubmittedTransactionHasRejectedTransactionStatus,
+    WrongAccountType,
+    UnknownWebsite,
+    InvalidOriginURL,
+    RadixJsonNotFound,
+    RadixJsonUnknownFileFormat,
+    UnknownDappDefinitionAddress,
+    InvalidPersona,
+    InvalidRequest,
+    IncompatibleVersion,
+    FailedToSignAuthChallenge,
+}
+
+impl From<CommonError> for DappWalletInteractionErrorType {
+    /// Maps errors from validating a [`DappToWalletInteraction`] into the
+    /// error kind sent back to the dApp.
+    fn from(value: CommonError) -> Self {
+        match value {
+            CommonError::WalletInteractionNetworkMismatch { .. } => {
+                Self::WrongNetwork
+            }
+            CommonError::WalletInteractionUnknownDapp(_) => {
+                Self::UnknownDappDefinitionAddress
+            }
+            CommonError::WalletInteractionUnknownPersona(_)
+            | CommonError::WalletInteractionPersonaNotAuthorizedByDapp(_) => {
+                Self::InvalidPersona
+            }
+            _ => Self::InvalidRequest,
+        }
+    }
+}
+
+impl WalletToDappInteractionResponse {
+    /// A successful response to the interaction with id `interaction_id`.
+    pub fn success(
+        interaction_id: WalletInteractionId,
+        items: WalletToDappInteractionResponseItems,
+    ) -> Self {
+        Self::Success {
+            value: WalletToDappInteractionSuccessResponse {
+                interaction_id,
+                items,
+            },
+        }
+    }
+
+    /// A failed response to the interaction with id `interaction_id`.
+    pub fn failure(
+        interaction_id: WalletInteractionId,
+        error: DappWalletInteractionErrorType,
+        message: impl Into<Option<String>>,
+    ) -> Self {
+        Self::Failure {
+            value: WalletToDappInteractionFailureResponse {
+                interaction_id,
+                error,
+                message: message.into(),
+            },
+        }
+    }
+
+    /// The ID of the interaction this is a response to.
+    pub fn interaction_id(&self) -> WalletInteractionId {
+        match self {
+            Self::Success { value } => value.interaction_id.clone(),
+            Self::Failure { value } => value.interaction_id.clone(),
+        }
+    }
+
+    /// JSON serializes this response, to be sent to the dApp.
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(self).expect(
+            "Should always be able to JSON serialize a WalletToDappInteractionResponse.",
+        )
+    }
+}
+
+#[uniffi::export]
+pub fn new_wallet_to_dapp_interaction_response_failure(
+    interaction_id: WalletInteractionId,
+    error: DappWalletInteractionErrorType,
+    message: Option<String>,
+) -> WalletToDappInteractionResponse {
+    WalletToDappInteractionResponse::failure(interaction_id, error, message)
+}
+
+#[uniffi::export]
+pub fn wallet_to_dapp_interaction_response_to_json_string(
+    response: &WalletToDappInteractionResponse,
+) -> String {
+    response.to_json_string()
+}
+
+impl HasPlaceholder for WalletToDappInteractionResponse {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::success(
+            WalletInteractionId::placeholder(),
+            WalletToDappInteractionResponseItems::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::failure(
+            WalletInteractionId::placeholder_other(),
+            DappWalletInteractionErrorType::RejectedByUser,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = WalletToDappInteractionResponse;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn interaction_id() {
+        assert_eq!(
+            SUT::placeholder().interaction_id(),
+            WalletInteractionId::placeholder()
+        );
+        assert_eq!(
+            SUT::placeholder_other().interaction_id(),
+            WalletInteractionId::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_failure() {
+        let model = new_wallet_to_dapp_interaction_response_failure(
+            WalletInteractionId::placeholder(),
+            DappWalletInteractionErrorType::InvalidOriginURL,
+            Some("Bad origin".to_owned()),
+        );
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "discriminator": "failure",
+                "interactionId": "6ff8c1e6-1a7b-4f4c-8f2d-4a9d1e3c2b10",
+                "error": "invalidOriginURL",
+                "message": "Bad origin"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn to_json_string() {
+        let json = wallet_to_dapp_interaction_response_to_json_string(
+            &SUT::placeholder(),
+        );
+        assert_eq!(
+            serde_json::from_str::<SUT>(&json).unwrap(),
+            SUT::placeholder()
+        );
+    }
+
+    #[test]
+    fn error_type_from_common_error() {
+        assert_eq!(
+            DappWalletInteractionErrorType::from(
+                CommonError::WalletInteractionNetworkMismatch {
+                    expected: NetworkID::Mainnet,
+                    found: NetworkID::Stokenet
+                }
+            ),
+            DappWalletInteractionErrorType::WrongNetwork
+        );
+        assert_eq!(
+            DappWalletInteractionErrorType::from(
+                CommonError::WalletInteractionUnknownPersona(
+                    IdentityAddress::placeholder()
+                )
+            ),
+            DappWalletInteractionErrorType::InvalidPersona
+        );
+        assert_eq!(
+            DappWalletInteractionErrorType::from(CommonError::Unknown),
+            DappWalletInteractionErrorType::InvalidRequest
+        );
+    }
+}
@@ -0,0 +1,748 @@
+use crate::prelude::*;
+
+/// Builds a successful [`WalletToDappInteractionResponse`] to a
+/// [`DappToWalletInteraction`] from the Persona, accounts and PersonaData
+/// the user selected, making sure that the selection fulfills what the
+/// dApp requested.
+///
+/// ```
+/// extern crate profile;
+/// use profile::prelude::*;
+///
+/// let request = DappToWalletInteraction::placeholder();
+/// let response = WalletToDappInteractionResponseBuilder::new(request)
+///     .persona(Persona::placeholder_mainnet())
+///     .ongoing_accounts([Account::placeholder_mainnet()])
+///     .ongoing_persona_data(Persona::placeholder_mainnet().persona_data)
+///     .build();
+/// assert!(response.is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletToDappInteractionResponseBuilder {
+    request: DappToWalletInteraction,
+    persona: Option<Persona>,
+    auth_proof: Option<WalletToDappInteractionAuthProof>,
+    account_proofs: Vec<WalletToDappInteractionAccountProof>,
+    ongoing_accounts: Option<Vec<Account>>,
+    one_time_accounts: Option<Vec<Account>>,
+    ongoing_persona_data: Option<PersonaData>,
+    one_time_persona_data: Option<PersonaData>,
+    transaction_intent_hash: Option<String>,
+}
+
+impl WalletToDappInteractionResponseBuilder {
+    pub fn new(request: DappToWalletInteraction) -> Self {
+        Self {
+            request,
+            persona: None,
+            auth_proof: None,
+            account_proofs: Vec::new(),
+            ongoing_accounts: None,
+            one_time_accounts: None,
+            ongoing_persona_data: None,
+            one_time_persona_data: None,
+            transaction_intent_hash: None,
+        }
+    }
+
+    /// The Persona the user chose to login with.
+    pub fn persona(mut self, persona: Persona) -> Self {
+        self.persona = Some(persona);
+        self
+    }
+
+    /// Proof of ownership of the Persona, required if the dApp requested
+    /// `loginWithChallenge`.
+    pub fn auth_proof(
+        mut self,
+        proof: WalletToDappInteractionAuthProof,
+    ) -> Self {
+        self.auth_proof = Some(proof);
+        self
+    }
+
+    /// Proofs of ownership of the accounts, required if the dApp sent a
+    /// challenge in an accounts request.
+    pub fn account_proofs(
+        mut self,
+        proofs: impl IntoIterator<Item = WalletToDappInteractionAccountProof>,
+    ) -> Self {
+        self.account_proofs = proofs.into_iter().collect_vec();
+        self
+    }
+
+    /// The accounts the user chose to give the dApp ongoing access to.
+    pub fn ongoing_accounts(
+        mut self,
+        accounts: impl IntoIterator<Item = Account>,
+    ) -> Self {
+        self.ongoing_accounts = Some(accounts.into_iter().collect_vec());
+        self
+    }
+
+    /// The accounts the user chose to share once with the dApp.
+    pub fn one_time_accounts(
+        mut self,
+        accounts: impl IntoIterator<Item = Account>,
+    ) -> Self {
+        self.one_time_accounts = Some(accounts.into_iter().collect_vec());
+        self
+    }
+
+    /// The PersonaData entries the user chose to give the dApp ongoing
+    /// access to.
+    pub fn ongoing_persona_data(mut self, persona_data: PersonaData) -> Self {
+        self.ongoing_persona_data = Some(persona_data);
+        self
+    }
+
+    /// The PersonaData entries the user chose to share once with the dApp.
+    pub fn one_time_persona_data(mut self, persona_data: PersonaData) -> Self {
+        self.one_time_persona_data = Some(persona_data);
+        self
+    }
+
+    /// The bech32 encoded hash of the submitted transaction intent.
+    pub fn transaction_intent_hash(
+        mut self,
+        transaction_intent_hash: impl AsRef<str>,
+    ) -> Self {
+        self.transaction_intent_hash =
+            Some(transaction_intent_hash.as_ref().to_owned());
+        self
+    }
+}
+
+impl WalletToDappInteractionResponseBuilder {
+    /// Builds the response, returns `Err` if the selection of the user does
+    /// not fulfill the request.
+    pub fn build(self) -> Result<WalletToDappInteractionResponse> {
+        let items = match self.request.items.clone() {
+            DappToWalletInteractionItems::AuthorizedRequest { value } => {
+                self.build_authorized(value)?.into()
+            }
+            DappToWalletInteractionItems::UnauthorizedRequest { value } => {
+                WalletToDappInteractionUnauthorizedRequestResponseItems {
+                    one_time_accounts: self.respond_to_accounts_request(
+                        value.one_time_accounts,
+                        self.one_time_accounts.clone(),
+                    )?,
+                    one_time_persona_data:
+                        Self::respond_to_persona_data_request(
+                            value.one_time_persona_data,
+                            self.one_time_persona_data.clone(),
+                        )?,
+                }
+                .into()
+            }
+            DappToWalletInteractionItems::Transaction { value: _ } => {
+                WalletToDappInteractionTransactionResponseItems {
+                    send: WalletToDappInteractionSendTransactionResponseItem {
+                        transaction_intent_hash: self
+                            .transaction_intent_hash
+                            .clone()
+                            .ok_or(Self::unfulfilled(
+                                "missing transaction intent hash",
+                            ))?,
+                    },
+                }
+                .into()
+            }
+        };
+        Ok(WalletToDappInteractionResponse::success(
+            self.request.interaction_id.clone(),
+            items,
+        ))
+    }
+
+    fn build_authorized(
+        &self,
+        request: DappToWalletInteractionAuthorizedRequestItems,
+    ) -> Result<WalletToDappInteractionAuthorizedRequestResponseItems> {
+        let persona = self
+            .persona
+            .clone()
+            .ok_or(CommonError::WalletInteractionResponseMissingPersona)?;
+
+        if persona.network_id != self.request.metadata.network_id {
+            return Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: self.request.metadata.network_id,
+                found: persona.network_id,
+            });
+        }
+
+        let auth = match request.auth {
+            DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge => {
+                WalletToDappInteractionAuthRequestResponseItem::LoginWithoutChallenge {
+                    persona: persona.into(),
+                }
+            }
+            DappToWalletInteractionAuthRequestItem::LoginWithChallenge {
+                challenge,
+            } => WalletToDappInteractionAuthRequestResponseItem::LoginWithChallenge {
+                persona: persona.into(),
+                challenge,
+                proof: self
+                    .auth_proof
+                    .clone()
+                    .ok_or(Self::unfulfilled("missing proof of Persona"))?,
+            },
+            DappToWalletInteractionAuthRequestItem::UsePersona {
+                identity_address,
+            } => {
+                if identity_address != persona.address {
+                    return Err(Self::unfulfilled(
+                        "used another Persona than requested",
+                    ));
+                }
+                WalletToDappInteractionAuthRequestResponseItem::UsePersona {
+                    persona: persona.into(),
+                }
+            }
+        };
+
+        Ok(WalletToDappInteractionAuthorizedRequestResponseItems {
+            auth,
+            ongoing_accounts: self.respond_to_accounts_request(
+                request.ongoing_accounts,
+                self.ongoing_accounts.clone(),
+            )?,
+            ongoing_persona_data: Self::respond_to_persona_data_request(
+                request.ongoing_persona_data,
+                self.ongoing_persona_data.clone(),
+            )?,
+            one_time_accounts: self.respond_to_accounts_request(
+                request.one_time_accounts,
+                self.one_time_accounts.clone(),
+            )?,
+            one_time_persona_data: Self::respond_to_persona_data_request(
+                request.one_time_persona_data,
+                self.one_time_persona_data.clone(),
+            )?,
+        })
+    }
+
+    fn respond_to_accounts_request(
+        &self,
+        request: Option<DappToWalletInteractionAccountsRequestItem>,
+        accounts: Option<Vec<Account>>,
+    ) -> Result<Option<WalletToDappInteractionAccountsRequestResponseItem>>
+    {
+        let Some(request) = request else {
+            return Ok(None);
+        };
+        let accounts = accounts.unwrap_or_default();
+        if let Some(duplicate) =
+            accounts.iter().map(|a| &a.address).duplicates().next()
+        {
+            return Err(Self::unfulfilled(format!(
+                "account {} shared more than once",
+                duplicate
+            )));
+        }
+        if !request
+            .number_of_accounts
+            .is_fulfilled_by_ids(accounts.len())
+        {
+            return Err(Self::unfulfilled(format!(
+                "requested {} accounts, got #{}",
+                request.number_of_accounts,
+                accounts.len()
+            )));
+        }
+        if let Some(account) = accounts
+            .iter()
+            .find(|a| a.network_id != self.request.metadata.network_id)
+        {
+            return Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: self.request.metadata.network_id,
+                found: account.network_id,
+            });
+        }
+
+        let proofs = match request.challenge {
+            None => None,
+            Some(_) => {
+                let proofs = accounts
+                    .iter()
+                    .map(|a| {
+                        self.account_proofs
+                            .iter()
+                            .find(|p| p.account_address == a.address)
+                            .cloned()
+                            .ok_or(Self::unfulfilled(format!(
+                                "missing proof for account {}",
+                                a.address
+                            )))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Some(proofs)
+            }
+        };
+
+        Ok(Some(WalletToDappInteractionAccountsRequestResponseItem {
+            accounts: accounts.into_iter().map(|a| a.into()).collect_vec(),
+            challenge: request.challenge,
+            proofs,
+        }))
+    }
+
+    fn respond_to_persona_data_request(
+        request: Option<DappToWalletInteractionPersonaDataRequestItem>,
+        persona_data: Option<PersonaData>,
+    ) -> Result<Option<WalletToDappInteractionPersonaDataRequestResponseItem>>
+    {
+        let Some(request) = request else {
+            return Ok(None);
+        };
+        let persona_data = persona_data.unwrap_or_default();
+
+        let name = if request.requests_name() {
+            Some(
+                persona_data
+                    .name
+                    .clone()
+                    .map(|n| n.value)
+                    .ok_or(Self::unfulfilled("missing name"))?,
+            )
+        } else {
+            None
+        };
+
+        let email_addresses = request
+            .number_of_requested_email_addresses
+            .map(|quantity| {
+                Self::values_fulfilling(
+                    "email addresses",
+                    &quantity,
+                    persona_data
+                        .email_addresses
+                        .iter()
+                        .map(|e| e.value.clone())
+                        .collect_vec(),
+                )
+            })
+            .transpose()?;
+
+        let phone_numbers = request
+            .number_of_requested_phone_numbers
+            .map(|quantity| {
+                Self::values_fulfilling(
+                    "phone numbers",
+                    &quantity,
+                    persona_data
+                        .phone_numbers
+                        .iter()
+                        .map(|e| e.value.clone())
+                        .collect_vec(),
+                )
+            })
+            .transpose()?;
+
+        Ok(Some(
+            WalletToDappInteractionPersonaDataRequestResponseItem {
+                name,
+                email_addresses,
+                phone_numbers,
+            },
+        ))
+    }
+
+    fn values_fulfilling<T>(
+        kind: &str,
+        quantity: &RequestedQuantity,
+        values: Vec<T>,
+    ) -> Result<Vec<T>> {
+        if !quantity.is_fulfilled_by_ids(values.len()) {
+            return Err(Self::unfulfilled(format!(
+                "requested {} {}, got #{}",
+                quantity,
+                kind,
+                values.len()
+            )));
+        }
+        Ok(values)
+    }
+
+    fn unfulfilled(reason: impl AsRef<str>) -> CommonError {
+        CommonError::WalletInteractionResponseDoesNotFulfillRequest(
+            reason.as_ref().to_owned(),
+        )
+    }
+}
+
+impl From<WalletToDappInteractionAuthorizedRequestResponseItems>
+    for WalletToDappInteractionResponseItems
+{
+    fn from(
+        value: WalletToDappInteractionAuthorizedRequestResponseItems,
+    ) -> Self {
+        Self::AuthorizedRequest { value }
+    }
+}
+
+impl From<WalletToDappInteractionUnauthorizedRequestResponseItems>
+    for WalletToDappInteractionResponseItems
+{
+    fn from(
+        value: WalletToDappInteractionUnauthorizedRequestResponseItems,
+    ) -> Self {
+        Self::UnauthorizedRequest { value }
+    }
+}
+
+impl From<WalletToDappInteractionTransactionResponseItems>
+    for WalletToDappInteractionResponseItems
+{
+    fn from(value: WalletToDappInteractionTransactionResponseItems) -> Self {
+        Self::Transaction { value }
+    }
+}
+
+/// Builds a successful response to `request` from the selection of the
+/// user, see [`WalletToDappInteractionResponseBuilder`].
+///
+/// `auth_proof` is required if the dApp requested `loginWithChallenge`, and
+/// `account_proofs` must contain a proof for each shared account if the dApp
+/// sent a challenge in an accounts request.
+#[allow(clippy::too_many_arguments)]
+#[uniffi::export]
+pub fn new_wallet_to_dapp_interaction_response_success(
+    request: DappToWalletInteraction,
+    persona: Option<Persona>,
+    auth_proof: Option<WalletToDappInteractionAuthProof>,
+    ongoing_accounts: Vec<Account>,
+    ongoing_persona_data: Option<PersonaData>,
+    one_time_accounts: Vec<Account>,
+    one_time_persona_data: Option<PersonaData>,
+    account_proofs: Vec<WalletToDappInteractionAccountProof>,
+    transaction_intent_hash: Option<String>,
+) -> Result<WalletToDappInteractionResponse> {
+    let mut builder = WalletToDappInteractionResponseBuilder::new(request)
+        .ongoing_accounts(ongoing_accounts)
+        .one_time_accounts(one_time_accounts)
+        .account_proofs(account_proofs);
+    if let Some(persona) = persona {
+        builder = builder.persona(persona);
+    }
+    if let Some(proof) = auth_proof {
+        builder = builder.auth_proof(proof);
+    }
+    if let Some(persona_data) = ongoing_persona_data {
+        builder = builder.ongoing_persona_data(persona_data);
+    }
+    if let Some(persona_data) = one_time_persona_data {
+        builder = builder.one_time_persona_data(persona_data);
+    }
+    if let Some(hash) = transaction_intent_hash {
+        builder = builder.transaction_intent_hash(hash);
+    }
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = WalletToDappInteractionResponseBuilder;
+
+    #[test]
+    fn build_authorized_email_quantity_not_fulfilled() {
+        let persona = Persona::placeholder_mainnet();
+        let mut request = DappToWalletInteraction::placeholder();
+        let DappToWalletInteractionItems::AuthorizedRequest { value: items } =
+            &mut request.items
+        else {
+            panic!("Expected authorized request")
+        };
+        items.ongoing_persona_data =
+            Some(DappToWalletInteractionPersonaDataRequestItem::new(
+                None,
+                RequestedQuantity::exactly(1),
+                None,
+            ));
+        // Satoshi has two email addresses, but exactly one was requested.
+        assert_eq!(
+            SUT::new(request)
+                .persona(persona.clone())
+                .ongoing_accounts([Account::placeholder_mainnet()])
+                .ongoing_persona_data(persona.persona_data)
+                .build(),
+            Err(CommonError::WalletInteractionResponseDoesNotFulfillRequest(
+                "requested Exactly: 1 email addresses, got #2".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn build_authorized_fulfilled_persona_data() {
+        let persona = Persona::placeholder_mainnet();
+        let mut persona_data = persona.persona_data.clone();
+        persona_data.email_addresses = CollectionOfEmailAddresses::entries(
+            persona_data.email_addresses.items().into_iter().take(1),
+        );
+        let response = SUT::new(DappToWalletInteraction::placeholder())
+            .persona(persona.clone())
+            .ongoing_accounts([Account::placeholder_mainnet()])
+            .ongoing_persona_data(persona_data.clone())
+            .build()
+            .unwrap();
+        let WalletToDappInteractionResponse::Success { value } = response
+        else {
+            panic!("Expected success")
+        };
+        let WalletToDappInteractionResponseItems::AuthorizedRequest {
+            value: items,
+        } = value.items
+        else {
+            panic!("Expected authorized request")
+        };
+        let persona_data_response = items.ongoing_persona_data.unwrap();
+        assert_eq!(
+            persona_data_response.name,
+            persona_data.name.map(|n| n.value)
+        );
+        assert_eq!(
+            persona_data_response.email_addresses,
+            Some(vec![persona_data.email_addresses[0].value.clone()])
+        );
+        assert_eq!(persona_data_response.phone_numbers, None);
+    }
+
+    #[test]
+    fn build_authorized_missing_persona() {
+        assert_eq!(
+            SUT::new(DappToWalletInteraction::placeholder()).build(),
+            Err(CommonError::WalletInteractionResponseMissingPersona)
+        );
+    }
+
+    #[test]
+    fn build_authorized_persona_on_wrong_network() {
+        assert_eq!(
+            SUT::new(DappToWalletInteraction::placeholder())
+                .persona(Persona::placeholder_stokenet())
+                .build(),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+
+    #[test]
+    fn build_authorized_too_few_accounts() {
+        assert_eq!(
+            SUT::new(DappToWalletInteraction::placeholder())
+                .persona(Persona::placeholder_mainnet())
+                .build(),
+            Err(CommonError::WalletInteractionResponseDoesNotFulfillRequest(
+                "requested AtLeast: 1 accounts, got #0".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn build_authorized_duplicate_accounts() {
+        let mut request = DappToWalletInteraction::placeholder();
+        let DappToWalletInteractionItems::AuthorizedRequest { value: items } =
+            &mut request.items
+        else {
+            panic!("Expected authorized request")
+        };
+        items.ongoing_accounts =
+            Some(DappToWalletInteractionAccountsRequestItem::new(
+                RequestedQuantity::exactly(2),
+                None,
+            ));
+        let account = Account::placeholder_mainnet();
+        assert_eq!(
+            SUT::new(request)
+                .persona(Persona::placeholder_mainnet())
+                .ongoing_accounts([account.clone(), account.clone()])
+                .build(),
+            Err(CommonError::WalletInteractionResponseDoesNotFulfillRequest(
+                format!("account {} shared more than once", account.address)
+            ))
+        );
+    }
+
+    #[test]
+    fn build_use_persona_with_other_persona_fails() {
+        assert_eq!(
+            SUT::new(DappToWalletInteraction::placeholder_other())
+                .persona(Persona::placeholder_stokenet_hermione())
+                .build(),
+            Err(CommonError::WalletInteractionResponseDoesNotFulfillRequest(
+                "used another Persona than requested".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn build_login_with_challenge_requires_proof() {
+        let mut request = DappToWalletInteraction::placeholder();
+        request.items = DappToWalletInteractionAuthorizedRequestItems {
+            auth: DappToWalletInteractionAuthRequestItem::LoginWithChallenge {
+                challenge: Hex32Bytes::placeholder(),
+            },
+            reset: None,
+            ongoing_accounts: None,
+            ongoing_persona_data: None,
+            one_time_accounts: None,
+            one_time_persona_data: None,
+        }
+        .into();
+        let sut = SUT::new(request).persona(Persona::placeholder_mainnet());
+        assert_eq!(
+            sut.clone().build(),
+            Err(CommonError::WalletInteractionResponseDoesNotFulfillRequest(
+                "missing proof of Persona".to_owned()
+            ))
+        );
+        assert!(sut.auth_proof(proof()).build().is_ok());
+    }
+
+    #[test]
+    fn build_transaction() {
+        let mut request = DappToWalletInteraction::placeholder_other();
+        request.items = DappToWalletInteractionTransactionItems {
+            send: DappToWalletInteractionSendTransactionItem::placeholder(),
+        }
+        .into();
+        let sut = SUT::new(request);
+        assert_eq!(
+            sut.clone().build(),
+            Err(CommonError::WalletInteractionResponseDoesNotFulfillRequest(
+                "missing transaction intent hash".to_owned()
+            ))
+        );
+        assert_eq!(
+            sut.transaction_intent_hash("txid_tdx_2_1mwuvufnewv6qkxdaesx0gcwap7n79knhkn0crsc8dg9g9k7qknjs6vkd3n")
+                .build(),
+            Ok(WalletToDappInteractionResponse::success(
+                WalletInteractionId::placeholder_other(),
+                WalletToDappInteractionResponseItems::placeholder_other()
+            ))
+        );
+    }
+
+    #[test]
+    fn build_unauthorized_via_uniffi() {
+        let mut request = DappToWalletInteraction::placeholder_other();
+        request.items = DappToWalletInteractionUnauthorizedRequestItems {
+            one_time_accounts: Some(
+                DappToWalletInteractionAccountsRequestItem::placeholder(),
+            ),
+            one_time_persona_data: None,
+        }
+        .into();
+        let account = Account::placeholder_stokenet_carol();
+        let response = new_wallet_to_dapp_interaction_response_success(
+            request,
+            None,
+            None,
+            Vec::new(),
+            None,
+            vec![account.clone()],
+            None,
+            Vec::new(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            response,
+            WalletToDappInteractionResponse::success(
+                WalletInteractionId::placeholder_other(),
+                WalletToDappInteractionUnauthorizedRequestResponseItems {
+                    one_time_accounts: Some(
+                        WalletToDappInteractionAccountsRequestResponseItem {
+                            accounts: vec![account.into()],
+                            challenge: None,
+                            proofs: None,
+                        }
+                    ),
+                    one_time_persona_data: None,
+                }
+                .into()
+            )
+        );
+    }
+
+    fn proof() -> WalletToDappInteractionAuthProof {
+        WalletToDappInteractionAuthProof {
+            public_key: PublicKey::placeholder().to_hex(),
+            curve: SLIP10Curve::Curve25519,
+            signature: "deadbeef".to_owned(),
+        }
+    }
+
+    #[test]
+    fn build_login_with_challenge_and_account_proofs_via_uniffi() {
+        let challenge = Hex32Bytes::placeholder();
+        let mut request = DappToWalletInteraction::placeholder();
+        request.items = DappToWalletInteractionAuthorizedRequestItems {
+            auth: DappToWalletInteractionAuthRequestItem::LoginWithChallenge {
+                challenge: challenge.clone(),
+            },
+            reset: None,
+            ongoing_accounts: Some(
+                DappToWalletInteractionAccountsRequestItem::new(
+                    RequestedQuantity::exactly(1),
+                    challenge.clone(),
+                ),
+            ),
+            ongoing_persona_data: None,
+            one_time_accounts: None,
+            one_time_persona_data: None,
+        }
+        .into();
+        let persona = Persona::placeholder_mainnet();
+        let account = Account::placeholder_mainnet();
+        let account_proof = WalletToDappInteractionAccountProof {
+            account_address: account.address.clone(),
+            proof: proof(),
+        };
+
+        let response = new_wallet_to_dapp_interaction_response_success(
+            request,
+            Some(persona.clone()),
+            Some(proof()),
+            vec![account.clone()],
+            None,
+            Vec::new(),
+            None,
+            vec![account_proof.clone()],
+            None,
+        )
+        .unwrap();
+
+        let WalletToDappInteractionResponse::Success { value } = response
+        else {
+            panic!("Expected success")
+        };
+        let WalletToDappInteractionResponseItems::AuthorizedRequest {
+            value: items,
+        } = value.items
+        else {
+            panic!("Expected authorized request")
+        };
+        assert_eq!(
+            items.auth,
+            WalletToDappInteractionAuthRequestResponseItem::LoginWithChallenge {
+                persona: persona.into(),
+                challenge: challenge.clone(),
+                proof: proof(),
+            }
+        );
+        assert_eq!(
+            items.ongoing_accounts,
+            Some(WalletToDappInteractionAccountsRequestResponseItem {
+                accounts: vec![account.into()],
+                challenge: Some(challenge),
+                proofs: Some(vec![account_proof]),
+            })
+        );
+    }
+}
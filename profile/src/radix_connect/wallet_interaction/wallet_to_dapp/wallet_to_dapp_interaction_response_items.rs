@@ -0,0 +1,336 @@
+use crate::prelude::*;
+
+/// The items of a successful response, matching the kind of the request.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum WalletToDappInteractionResponseItems {
+    AuthorizedRequest {
+        #[serde(flatten)]
+        value: WalletToDappInteractionAuthorizedRequestResponseItems,
+    },
+
+    UnauthorizedRequest {
+        #[serde(flatten)]
+        value: WalletToDappInteractionUnauthorizedRequestResponseItems,
+    },
+
+    Transaction {
+        #[serde(flatten)]
+        value: WalletToDappInteractionTransactionResponseItems,
+    },
+}
+
+/// Response to a [`DappToWalletInteractionAuthorizedRequestItems`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionAuthorizedRequestResponseItems {
+    pub auth: WalletToDappInteractionAuthRequestResponseItem,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ongoing_accounts:
+        Option<WalletToDappInteractionAccountsRequestResponseItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ongoing_persona_data:
+        Option<WalletToDappInteractionPersonaDataRequestResponseItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_accounts:
+        Option<WalletToDappInteractionAccountsRequestResponseItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_persona_data:
+        Option<WalletToDappInteractionPersonaDataRequestResponseItem>,
+}
+
+/// Response to a [`DappToWalletInteractionUnauthorizedRequestItems`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionUnauthorizedRequestResponseItems {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_accounts:
+        Option<WalletToDappInteractionAccountsRequestResponseItem>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_persona_data:
+        Option<WalletToDappInteractionPersonaDataRequestResponseItem>,
+}
+
+/// Response to a [`DappToWalletInteractionTransactionItems`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct WalletToDappInteractionTransactionResponseItems {
+    pub send: WalletToDappInteractionSendTransactionResponseItem,
+}
+
+/// The hash of the transaction intent the wallet submitted.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionSendTransactionResponseItem {
+    /// Bech32 encoded transaction intent hash, e.g. `"txid_rdx1..."`.
+    pub transaction_intent_hash: String,
+}
+
+/// The Persona the user logged in with.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DappWalletInteractionPersona {
+    pub identity_address: IdentityAddress,
+    pub label: String,
+}
+
+impl From<Persona> for DappWalletInteractionPersona {
+    fn from(value: Persona) -> Self {
+        Self {
+            identity_address: value.address,
+            label: value.display_name.value,
+        }
+    }
+}
+
+/// A signature of a challenge, proving ownership of an entity.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionAuthProof {
+    /// Hex encoded public key used to sign the challenge.
+    pub public_key: String,
+    pub curve: SLIP10Curve,
+    /// Hex encoded signature of the challenge.
+    pub signature: String,
+}
+
+/// A signature of a challenge, proving ownership of the account.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionAccountProof {
+    pub account_address: AccountAddress,
+    pub proof: WalletToDappInteractionAuthProof,
+}
+
+/// How the user authenticated, matching the requested
+/// [`DappToWalletInteractionAuthRequestItem`].
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum WalletToDappInteractionAuthRequestResponseItem {
+    LoginWithoutChallenge {
+        persona: DappWalletInteractionPersona,
+    },
+    LoginWithChallenge {
+        persona: DappWalletInteractionPersona,
+        challenge: Hex32Bytes,
+        proof: WalletToDappInteractionAuthProof,
+    },
+    UsePersona {
+        persona: DappWalletInteractionPersona,
+    },
+}
+
+/// An account shared with a dApp.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletInteractionWalletAccount {
+    pub address: AccountAddress,
+    pub label: DisplayName,
+    pub appearance_id: AppearanceID,
+}
+
+impl From<Account> for WalletInteractionWalletAccount {
+    fn from(value: Account) -> Self {
+        Self {
+            address: value.address,
+            label: value.display_name,
+            appearance_id: value.appearance_id,
+        }
+    }
+}
+
+/// The accounts the user chose to share with a dApp.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionAccountsRequestResponseItem {
+    pub accounts: Vec<WalletInteractionWalletAccount>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<Hex32Bytes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proofs: Option<Vec<WalletToDappInteractionAccountProof>>,
+}
+
+/// The PersonaData values the user chose to share with a dApp.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletToDappInteractionPersonaDataRequestResponseItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<PersonaDataEntryName>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_addresses: Option<Vec<PersonaDataEntryEmailAddress>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_numbers: Option<Vec<PersonaDataEntryPhoneNumber>>,
+}
+
+impl HasPlaceholder for WalletToDappInteractionResponseItems {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::AuthorizedRequest {
+            value: WalletToDappInteractionAuthorizedRequestResponseItems {
+                auth: WalletToDappInteractionAuthRequestResponseItem::LoginWithoutChallenge {
+                    persona: Persona::placeholder_mainnet().into(),
+                },
+                ongoing_accounts: Some(
+                    WalletToDappInteractionAccountsRequestResponseItem {
+                        accounts: vec![Account::placeholder_mainnet().into()],
+                        challenge: None,
+                        proofs: None,
+                    },
+                ),
+                ongoing_persona_data: None,
+                one_time_accounts: None,
+                one_time_persona_data: None,
+            },
+        }
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::Transaction {
+            value: WalletToDappInteractionTransactionResponseItems {
+                send: WalletToDappInteractionSendTransactionResponseItem {
+                    transaction_intent_hash: "txid_tdx_2_1mwuvufnewv6qkxdaesx0gcwap7n79knhkn0crsc8dg9g9k7qknjs6vkd3n".to_owned(),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = WalletToDappInteractionResponseItems;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn json_roundtrip_transaction() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder_other(),
+            r#"
+            {
+                "discriminator": "transaction",
+                "send": {
+                    "transactionIntentHash": "txid_tdx_2_1mwuvufnewv6qkxdaesx0gcwap7n79knhkn0crsc8dg9g9k7qknjs6vkd3n"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_persona_data() {
+        let model = WalletToDappInteractionPersonaDataRequestResponseItem {
+            name: Some(PersonaDataEntryName::placeholder()),
+            email_addresses: Some(vec![
+                PersonaDataEntryEmailAddress::placeholder(),
+            ]),
+            phone_numbers: None,
+        };
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "name": {
+                    "variant": "western",
+                    "familyName": "Wayne",
+                    "givenNames": "Bruce",
+                    "nickname": "Batman"
+                },
+                "emailAddresses": ["alan@turing.hero"]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_login_without_challenge() {
+        let model =
+            WalletToDappInteractionAuthRequestResponseItem::LoginWithoutChallenge {
+                persona: DappWalletInteractionPersona {
+                    identity_address: IdentityAddress::placeholder_mainnet(),
+                    label: "Satoshi".to_owned(),
+                },
+            };
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "discriminator": "loginWithoutChallenge",
+                "persona": {
+                    "identityAddress": "identity_rdx122kttqch0eehzj6f9nkkxcw7msfeg9udurq5u0ysa0e92c59w0mg6x",
+                    "label": "Satoshi"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn persona_from_persona() {
+        let persona = Persona::placeholder_mainnet();
+        let sut = DappWalletInteractionPersona::from(persona.clone());
+        assert_eq!(sut.identity_address, persona.address);
+        assert_eq!(sut.label, "Satoshi");
+    }
+
+    #[test]
+    fn wallet_account_from_account() {
+        let account = Account::placeholder_mainnet();
+        let sut = WalletInteractionWalletAccount::from(account.clone());
+        assert_eq!(sut.address, account.address);
+        assert_eq!(sut.label, account.display_name);
+        assert_eq!(sut.appearance_id, account.appearance_id);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::prelude::*;
+
+use radix_engine_common::address::AddressBech32Decoder;
+use radix_engine_common::types::{
+    EntityType as EngineEntityType, NodeId as EngineNodeId,
+};
+
+/// The address of an AccessController, controlling a securified entity, a
+/// bech32 encoding that starts with the prefix `"accesscontroller_"`,
+/// dependent on NetworkID, e.g. `"accesscontroller_rdx1..."` on Mainnet.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    SerializeDisplay,
+    DeserializeFromStr,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{address}")]
+pub struct AccessControllerAddress {
+    pub address: String,
+    pub network_id: NetworkID,
+}
+
+#[uniffi::export]
+pub fn new_access_controller_address(
+    bech32: String,
+) -> Result<AccessControllerAddress> {
+    AccessControllerAddress::try_from_bech32(bech32.as_str())
+}
+
+impl EntityAddress for AccessControllerAddress {
+    fn entity_type() -> AbstractEntityType {
+        AbstractEntityType::AccessController
+    }
+
+    // Underscored to decrease visibility. You SHOULD NOT call this function directly,
+    // instead use `try_from_bech32` which performs proper validation. Impl types SHOULD
+    // `panic` if `address` does not start with `Self::entity_type().hrp()`
+    fn __with_address_and_network_id(
+        address: &str,
+        network_id: NetworkID,
+    ) -> Self {
+        assert!(address.starts_with(&Self::entity_type().hrp()), "Invalid address, you SHOULD NOT call this function directly, you should use `try_from_bech32` instead.");
+        Self {
+            address: address.to_string(),
+            network_id,
+        }
+    }
+}
+
+impl FromStr for AccessControllerAddress {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AccessControllerAddress::try_from_bech32(s)
+    }
+}
+
+impl JsonSchema for AccessControllerAddress {
+    fn schema_name() -> String {
+        "AccessControllerAddress".to_owned()
+    }
+
+    /// A Bech32 encoded address starting with `accesscontroller_`.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_json_schema(Some("bech32"), None)
+    }
+}
+
+impl From<AccessControllerAddress>
+    for radix_engine_common::types::ComponentAddress
+{
+    fn from(value: AccessControllerAddress) -> Self {
+        radix_engine_common::types::ComponentAddress::try_from_bech32(
+            &AddressBech32Decoder::new(&value.network_id.network_definition()),
+            value.address.as_str(),
+        )
+        .unwrap()
+    }
+}
+
+impl AccessControllerAddress {
+    /// An access controller address with a node id made up of `byte`
+    /// repeated, used to facilitate unit tests.
+    fn placeholder_with_byte(byte: u8, network_id: NetworkID) -> Self {
+        Self::try_from_node_id(
+            EngineNodeId::new(
+                EngineEntityType::GlobalAccessController as u8,
+                &[byte; EngineNodeId::RID_LENGTH],
+            ),
+            network_id,
+        )
+        .expect("Valid placeholder.")
+    }
+
+    pub fn placeholder_mainnet() -> Self {
+        Self::placeholder_with_byte(0x3c, NetworkID::Mainnet)
+    }
+
+    pub fn placeholder_mainnet_other() -> Self {
+        Self::placeholder_with_byte(0xc3, NetworkID::Mainnet)
+    }
+
+    pub fn placeholder_stokenet() -> Self {
+        Self::placeholder_with_byte(0x3c, NetworkID::Stokenet)
+    }
+}
+
+impl HasPlaceholder for AccessControllerAddress {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::placeholder_mainnet()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::placeholder_mainnet_other()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccessControllerAddress;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+        assert_ne!(SUT::placeholder_mainnet(), SUT::placeholder_stokenet());
+    }
+
+    #[test]
+    fn prefix_and_network() {
+        assert!(SUT::placeholder()
+            .address
+            .starts_with("accesscontroller_rdx1"));
+        let sut = SUT::placeholder_stokenet();
+        assert!(sut.address.starts_with("accesscontroller_tdx_2_1"));
+        assert_eq!(sut.network_id, NetworkID::Stokenet);
+    }
+
+    #[test]
+    fn string_roundtrip() {
+        let sut = SUT::placeholder();
+        assert_eq!(
+            new_access_controller_address(sut.to_string()),
+            Ok(sut.clone())
+        );
+        assert_eq!(sut.address.parse::<SUT>(), Ok(sut));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = SUT::placeholder_stokenet();
+        assert_json_value_eq_after_roundtrip(&sut, json!(sut.address));
+        assert_json_roundtrip(&sut);
+    }
+
+    #[test]
+    fn validator_address_is_not_access_controller_address() {
+        assert_eq!(
+            SUT::try_from_bech32(&ValidatorAddress::placeholder().address),
+            Err(CommonError::MismatchingEntityTypeWhileDecodingAddress)
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use radix_engine_common::address::AddressBech32Decoder;
+
 /// The address of an Account, a bech32 encoding of a public key hash
 /// that starts with the prefix `"account_"`, dependent on NetworkID, meaning the same
 /// public key used for two AccountAddresses on two different networks will not have
@@ -90,6 +92,16 @@ impl AccountAddress {
     }
 }
 
+impl From<AccountAddress> for radix_engine_common::types::ComponentAddress {
+    fn from(value: AccountAddress) -> Self {
+        radix_engine_common::types::ComponentAddress::try_from_bech32(
+            &AddressBech32Decoder::new(&value.network_id.network_definition()),
+            value.address.as_str(),
+        )
+        .unwrap()
+    }
+}
+
 impl FromStr for AccountAddress {
     type Err = CommonError;
 
@@ -98,6 +110,17 @@ impl FromStr for AccountAddress {
     }
 }
 
+impl JsonSchema for AccountAddress {
+    fn schema_name() -> String {
+        "AccountAddress".to_owned()
+    }
+
+    /// A Bech32 encoded address starting with `account_`.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_json_schema(Some("bech32"), None)
+    }
+}
+
 impl EntityAddress for AccountAddress {
     /// Identifies that AccountAddress uses the `EntityType::Account`, which are used
     /// to validate the HRP (`"account_"`) and is also used when forming HD derivation
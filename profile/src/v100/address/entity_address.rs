@@ -29,6 +29,18 @@ pub trait EntityAddress: Sized {
         format!("{node}")
     }
 
+    /// Creates a new address from the Radix Engine `node_id` on the network
+    /// `network_id`, fails if `node_id` is not an entity of `Self::entity_type()`.
+    fn try_from_node_id(
+        node_id: NodeId,
+        network_id: NetworkID,
+    ) -> Result<Self> {
+        Self::try_from_bech32(&Self::address_from_node_id(
+            node_id,
+            network_id.discriminant(),
+        ))
+    }
+
     /// Creates a new address from `public_key` and `network_id` by bech32 encoding
     /// it.
     #[cfg(not(tarpaulin_include))] // false negative
@@ -44,6 +56,10 @@ pub trait EntityAddress: Sized {
                 virtual_identity_address_from_public_key(&public_key)
             }
             AbstractEntityType::Resource => panic!("resource"),
+            AbstractEntityType::Validator => panic!("validator"),
+            AbstractEntityType::AccessController => {
+                panic!("access controller")
+            }
         };
 
         let address = Self::address_from_node_id(
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use radix_engine_common::address::AddressBech32Decoder;
+
 /// The address of an identity, used by Personas, a bech32 encoding of a public key hash
 /// that starts with the prefix `"identity_"`, dependent on NetworkID, meaning the same
 /// public key used for two IdentityAddresses on two different networks will not have
@@ -98,6 +100,16 @@ impl HasPlaceholder for IdentityAddress {
     }
 }
 
+impl From<IdentityAddress> for radix_engine_common::types::ComponentAddress {
+    fn from(value: IdentityAddress) -> Self {
+        radix_engine_common::types::ComponentAddress::try_from_bech32(
+            &AddressBech32Decoder::new(&value.network_id.network_definition()),
+            value.address.as_str(),
+        )
+        .unwrap()
+    }
+}
+
 impl FromStr for IdentityAddress {
     type Err = CommonError;
 
@@ -106,6 +118,17 @@ impl FromStr for IdentityAddress {
     }
 }
 
+impl JsonSchema for IdentityAddress {
+    fn schema_name() -> String {
+        "IdentityAddress".to_owned()
+    }
+
+    /// A Bech32 encoded address starting with `identity_`.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_json_schema(Some("bech32"), None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
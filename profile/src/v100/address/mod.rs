@@ -1,3 +1,4 @@
+mod access_controller_address;
 mod account_address;
 mod decode_address_helper;
 mod entity_address;
@@ -5,7 +6,9 @@ mod identity_address;
 mod non_fungible_global_id;
 mod non_fungible_local_id;
 mod resource_address;
+mod validator_address;
 
+pub use access_controller_address::*;
 pub use account_address::*;
 pub use decode_address_helper::*;
 pub use entity_address::*;
@@ -13,3 +16,4 @@ pub use identity_address::*;
 pub use non_fungible_global_id::*;
 pub use non_fungible_local_id::*;
 pub use resource_address::*;
+pub use validator_address::*;
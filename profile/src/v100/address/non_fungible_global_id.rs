@@ -56,7 +56,7 @@ impl NonFungibleGlobalId {
         }
     }
 
-    fn engine_global_id(&self) -> EngineNonFungibleGlobalId {
+    pub(crate) fn engine_global_id(&self) -> EngineNonFungibleGlobalId {
         EngineNonFungibleGlobalId::new(
             self.resource_address.clone().into(),
             self.non_fungible_local_id.clone().try_into().unwrap(),
@@ -103,6 +103,17 @@ impl FromStr for NonFungibleGlobalId {
     }
 }
 
+impl JsonSchema for NonFungibleGlobalId {
+    fn schema_name() -> String {
+        "NonFungibleGlobalId".to_owned()
+    }
+
+    /// A Bech32 encoded resource address and a local ID, separated by `:`.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_json_schema(None, None)
+    }
+}
+
 impl NonFungibleGlobalId {
     /// Returns the canonical string representation of a NonFungibleGlobalID: "<resource>:<local>"
     ///
@@ -114,6 +125,20 @@ impl NonFungibleGlobalId {
     }
 }
 
+impl HasPlaceholder for NonFungibleGlobalId {
+    fn placeholder() -> Self {
+        "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd:#2244#"
+            .parse()
+            .expect("Valid placeholder.")
+    }
+
+    fn placeholder_other() -> Self {
+        "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd:#9999#"
+            .parse()
+            .expect("Valid placeholder.")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
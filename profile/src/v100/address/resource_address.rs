@@ -57,6 +57,44 @@ impl FromStr for ResourceAddress {
     }
 }
 
+impl JsonSchema for ResourceAddress {
+    fn schema_name() -> String {
+        "ResourceAddress".to_owned()
+    }
+
+    /// A Bech32 encoded address starting with `resource_`.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_json_schema(Some("bech32"), None)
+    }
+}
+
+impl HasPlaceholder for ResourceAddress {
+    /// The XRD resource on Mainnet.
+    fn placeholder() -> Self {
+        Self::placeholder_mainnet_xrd()
+    }
+
+    fn placeholder_other() -> Self {
+        "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq"
+            .parse()
+            .expect("Valid placeholder.")
+    }
+}
+
+impl ResourceAddress {
+    pub fn placeholder_mainnet_xrd() -> Self {
+        "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd"
+            .parse()
+            .expect("Valid placeholder.")
+    }
+
+    pub fn placeholder_stokenet_xrd() -> Self {
+        "resource_tdx_2_1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxtfd2jc"
+            .parse()
+            .expect("Valid placeholder.")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -114,6 +152,14 @@ mod tests {
                 .unwrap();
         assert_eq!(a.network_id, NetworkID::Mainnet);
     }
+
+    #[test]
+    fn non_fungible_resource() {
+        let s = "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd";
+        let a: ResourceAddress = s.parse().unwrap();
+        assert_eq!(a.address, s);
+        assert_eq!(NonFungibleGlobalId::placeholder().resource_address, a);
+    }
 }
 
 #[cfg(test)]
@@ -130,4 +176,16 @@ mod uniffi_tests {
         assert_eq!(b.address, s);
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn placeholders() {
+        assert_ne!(
+            ResourceAddress::placeholder(),
+            ResourceAddress::placeholder_other()
+        );
+        assert_eq!(
+            ResourceAddress::placeholder_stokenet_xrd().network_id,
+            NetworkID::Stokenet
+        );
+    }
 }
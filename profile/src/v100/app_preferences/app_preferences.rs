@@ -9,6 +9,7 @@ use crate::prelude::*;
     Debug,
     Default,
     Deserialize,
+    JsonSchema,
     Serialize,
     PartialEq,
     Eq,
@@ -6,6 +6,7 @@ use std::ops::Deref;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     PartialEq,
     Eq,
@@ -23,6 +24,7 @@ pub struct Gateway {
     pub network: NetworkDefinition,
 
     /// The URL to the gateways API endpoint
+    #[schemars(schema_with = "url_json_schema")]
     pub url: Url,
 }
 
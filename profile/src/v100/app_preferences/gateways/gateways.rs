@@ -96,6 +96,21 @@ impl<'de> Deserialize<'de> for Gateways {
     }
 }
 
+impl JsonSchema for Gateways {
+    fn schema_name() -> String {
+        "Gateways".to_owned()
+    }
+
+    /// The `current` Gateway is referenced by URL and MUST be one of the
+    /// `saved` Gateways, which this schema cannot express.
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        object_json_schema([
+            ("current", url_json_schema(gen)),
+            ("saved", gen.subschema_for::<Vec<Gateway>>()),
+        ])
+    }
+}
+
 impl Gateways {
     pub fn new(current: Gateway) -> Self {
         Self {
@@ -6,6 +6,7 @@ use crate::NetworkID::{self, *};
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     Debug,
     PartialEq,
@@ -8,6 +8,7 @@ use radix_engine_common::crypto::Hash;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     PartialEq,
     Eq,
@@ -7,6 +7,7 @@ use radix_engine_common::crypto::Hash;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     PartialEq,
     Eq,
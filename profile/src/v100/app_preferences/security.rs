@@ -9,7 +9,15 @@ pub type SecurityStructureConfigurationReference = bool;
 /// developer mode is enabled or not. In future (MFA) we will also save a list of
 /// MFA security structure configurations.
 #[derive(
-    Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash,
+    uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct Security {
@@ -25,6 +25,10 @@ pub enum AbstractEntityType {
     Identity,
     /// Resource address
     Resource,
+    /// The entity type used by Validators.
+    Validator,
+    /// The entity type used by AccessControllers.
+    AccessController,
 }
 impl AbstractEntityType {
     /// Conversion of the Radix Engines type for EntityType to Self.
@@ -37,9 +41,14 @@ impl AbstractEntityType {
             EngineEntityType::GlobalVirtualEd25519Identity => {
                 Ok(Self::Identity)
             }
-            EngineEntityType::GlobalFungibleResourceManager => {
+            EngineEntityType::GlobalFungibleResourceManager
+            | EngineEntityType::GlobalNonFungibleResourceManager => {
                 Ok(Self::Resource)
             }
+            EngineEntityType::GlobalValidator => Ok(Self::Validator),
+            EngineEntityType::GlobalAccessController => {
+                Ok(Self::AccessController)
+            }
             _ => Err(CommonError::UnsupportedEntityType),
         }
     }
@@ -50,6 +59,8 @@ impl AbstractEntityType {
             Self::Account => "account".to_string(),
             Self::Identity => "identity".to_string(),
             Self::Resource => "resource".to_string(),
+            Self::Validator => "validator".to_string(),
+            Self::AccessController => "accesscontroller".to_string(),
         }
     }
 }
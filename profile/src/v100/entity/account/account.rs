@@ -20,6 +20,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     Debug,
     PartialEq,
@@ -115,16 +116,17 @@ impl PartialOrd for Account {
 
 impl Ord for Account {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.security_state, &other.security_state) {
-            (
-                EntitySecurityState::Unsecured { value: l },
-                EntitySecurityState::Unsecured { value: r },
-            ) => l
-                .transaction_signing
-                .derivation_path()
-                .last_component()
-                .cmp(r.transaction_signing.derivation_path().last_component()),
-        }
+        self.security_state
+            .entity_creating_factor_instance()
+            .derivation_path()
+            .last_component()
+            .cmp(
+                other
+                    .security_state
+                    .entity_creating_factor_instance()
+                    .derivation_path()
+                    .last_component(),
+            )
     }
 }
 
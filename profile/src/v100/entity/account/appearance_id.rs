@@ -122,6 +122,18 @@ impl From<AppearanceID> for u8 {
     }
 }
 
+impl JsonSchema for AppearanceID {
+    fn schema_name() -> String {
+        "AppearanceID".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = u8::json_schema(gen).into_object();
+        schema.number().maximum = Some(Self::MAX as f64);
+        schema.into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
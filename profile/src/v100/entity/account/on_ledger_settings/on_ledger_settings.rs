@@ -12,6 +12,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Default,
     Clone,
     Debug,
@@ -1,9 +1,11 @@
 use crate::prelude::*;
+use radix_engine_interface::blueprints::account::ResourcePreference as ScryptoResourcePreference;
 
 /// The exception kind for deposit address
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     FromRepr,
     Clone,
     Copy,
@@ -25,6 +27,15 @@ pub enum DepositAddressExceptionRule {
     Deny,
 }
 
+impl From<DepositAddressExceptionRule> for ScryptoResourcePreference {
+    fn from(value: DepositAddressExceptionRule) -> Self {
+        match value {
+            DepositAddressExceptionRule::Allow => Self::Allowed,
+            DepositAddressExceptionRule::Deny => Self::Disallowed,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
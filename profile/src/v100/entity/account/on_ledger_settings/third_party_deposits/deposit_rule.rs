@@ -1,9 +1,11 @@
 use crate::prelude::*;
+use radix_engine_interface::blueprints::account::DefaultDepositRule as ScryptoDefaultDepositRule;
 
 /// The general deposit rule to apply
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     FromRepr,
     Clone,
     Copy,
@@ -34,6 +36,16 @@ impl Default for DepositRule {
     }
 }
 
+impl From<DepositRule> for ScryptoDefaultDepositRule {
+    fn from(value: DepositRule) -> Self {
+        match value {
+            DepositRule::AcceptKnown => Self::AllowExisting,
+            DepositRule::AcceptAll => Self::Accept,
+            DepositRule::DenyAll => Self::Reject,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -47,6 +59,23 @@ mod tests {
         assert_json_roundtrip(&DepositRule::AcceptAll);
     }
 
+    #[test]
+    fn into_scrypto() {
+        use radix_engine_interface::blueprints::account::DefaultDepositRule as ScryptoDefaultDepositRule;
+        assert_eq!(
+            ScryptoDefaultDepositRule::from(DepositRule::AcceptKnown),
+            ScryptoDefaultDepositRule::AllowExisting
+        );
+        assert_eq!(
+            ScryptoDefaultDepositRule::from(DepositRule::AcceptAll),
+            ScryptoDefaultDepositRule::Accept
+        );
+        assert_eq!(
+            ScryptoDefaultDepositRule::from(DepositRule::DenyAll),
+            ScryptoDefaultDepositRule::Reject
+        );
+    }
+
     #[test]
     fn inequality() {
         assert_ne!(DepositRule::AcceptAll, DepositRule::DenyAll);
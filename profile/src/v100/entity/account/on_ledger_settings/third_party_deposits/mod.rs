@@ -3,9 +3,11 @@ mod deposit_address_exception_rule;
 mod deposit_rule;
 mod resource_or_non_fungible;
 mod third_party_deposits;
+mod third_party_deposits_delta;
 
 pub use asset_exception::*;
 pub use deposit_address_exception_rule::*;
 pub use deposit_rule::*;
 pub use resource_or_non_fungible::*;
 pub use third_party_deposits::*;
+pub use third_party_deposits_delta::*;
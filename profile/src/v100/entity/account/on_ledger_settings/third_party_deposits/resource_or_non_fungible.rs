@@ -1,9 +1,11 @@
 use crate::prelude::*;
+use radix_engine_interface::blueprints::resource::ResourceOrNonFungible as ScryptoResourceOrNonFungible;
 
 /// The addresses that can be added as exception to the `DepositRule`
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     Debug,
     PartialEq,
@@ -21,6 +23,29 @@ pub enum ResourceOrNonFungible {
     NonFungible { value: NonFungibleGlobalId },
 }
 
+impl ResourceOrNonFungible {
+    /// The address of the resource, or of the resource of the non fungible.
+    pub fn resource_address(&self) -> &ResourceAddress {
+        match self {
+            Self::Resource { value } => value,
+            Self::NonFungible { value } => &value.resource_address,
+        }
+    }
+}
+
+impl From<ResourceOrNonFungible> for ScryptoResourceOrNonFungible {
+    fn from(value: ResourceOrNonFungible) -> Self {
+        match value {
+            ResourceOrNonFungible::Resource { value } => {
+                Self::Resource(value.into())
+            }
+            ResourceOrNonFungible::NonFungible { value } => {
+                Self::NonFungible(value.engine_global_id())
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
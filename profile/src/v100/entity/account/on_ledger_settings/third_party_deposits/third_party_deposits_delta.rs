@@ -0,0 +1,209 @@
+use crate::prelude::*;
+
+/// The changes needed to move the third-party deposit settings of an account
+/// on-ledger from one `ThirdPartyDeposits` to another, each change maps to
+/// one method call on the account component.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ThirdPartyDepositsDelta {
+    /// The new default deposit rule, `None` if unchanged.
+    pub deposit_rule: Option<DepositRule>,
+
+    /// Asset exceptions which are new or have a changed exception rule.
+    pub asset_exceptions_to_add_or_update: Vec<AssetException>,
+
+    /// Resources which should no longer have an asset exception.
+    pub asset_exceptions_to_be_removed: Vec<ResourceAddress>,
+
+    /// Depositors to add to the allow list.
+    pub depositors_to_add: Vec<ResourceOrNonFungible>,
+
+    /// Depositors to remove from the allow list.
+    pub depositors_to_remove: Vec<ResourceOrNonFungible>,
+}
+
+impl ThirdPartyDepositsDelta {
+    /// Diffs `from` against `to`, the order of the changes follows the order
+    /// of the lists in `to` (additions) and `from` (removals).
+    pub fn new(from: &ThirdPartyDeposits, to: &ThirdPartyDeposits) -> Self {
+        let deposit_rule =
+            Some(to.deposit_rule).filter(|rule| *rule != from.deposit_rule);
+
+        let asset_exceptions_to_add_or_update = to
+            .assets_exception_list
+            .iter()
+            .filter(|e| from.assets_exception_list.get(&e.address) != Some(e))
+            .cloned()
+            .collect_vec();
+
+        let asset_exceptions_to_be_removed = from
+            .assets_exception_list
+            .iter()
+            .filter(|e| !to.assets_exception_list.contains_id(&e.address))
+            .map(|e| e.address.clone())
+            .collect_vec();
+
+        let depositors_to_add = to
+            .depositors_allow_list
+            .iter()
+            .filter(|d| !from.depositors_allow_list.contains_id(d))
+            .cloned()
+            .collect_vec();
+
+        let depositors_to_remove = from
+            .depositors_allow_list
+            .iter()
+            .filter(|d| !to.depositors_allow_list.contains_id(d))
+            .cloned()
+            .collect_vec();
+
+        Self {
+            deposit_rule,
+            asset_exceptions_to_add_or_update,
+            asset_exceptions_to_be_removed,
+            depositors_to_add,
+            depositors_to_remove,
+        }
+    }
+
+    /// `true` if `from` and `to` are equal, i.e. nothing needs to change.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Checks that the resources of the asset exceptions and the depositors
+    /// are all on the network `expected`, that of the account.
+    pub(crate) fn validate_network(&self, expected: NetworkID) -> Result<()> {
+        let mut found = self
+            .asset_exceptions_to_add_or_update
+            .iter()
+            .map(|e| &e.address)
+            .chain(self.asset_exceptions_to_be_removed.iter())
+            .chain(
+                self.depositors_to_add
+                    .iter()
+                    .chain(self.depositors_to_remove.iter())
+                    .map(|d| d.resource_address()),
+            )
+            .map(|r| r.network_id);
+        match found.find(|n| *n != expected) {
+            Some(found) => Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected,
+                found,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = ThirdPartyDepositsDelta;
+
+    fn xrd() -> ResourceAddress {
+        "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd"
+            .parse()
+            .unwrap()
+    }
+
+    fn other_resource() -> ResourceAddress {
+        "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq"
+            .parse()
+            .unwrap()
+    }
+
+    fn nft() -> ResourceOrNonFungible {
+        ResourceOrNonFungible::NonFungible {
+            value: "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd:#2244#"
+                .parse()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn no_changes_is_empty() {
+        let settings = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::DenyAll,
+            [AssetException::new(
+                xrd(),
+                DepositAddressExceptionRule::Allow,
+            )],
+            [nft()],
+        );
+        assert!(SUT::new(&settings, &settings).is_empty());
+    }
+
+    #[test]
+    fn deposit_rule_changed() {
+        let sut = SUT::new(
+            &ThirdPartyDeposits::new(DepositRule::AcceptAll),
+            &ThirdPartyDeposits::new(DepositRule::AcceptKnown),
+        );
+        assert_eq!(
+            sut,
+            SUT {
+                deposit_rule: Some(DepositRule::AcceptKnown),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn asset_exceptions_added_updated_and_removed() {
+        let from = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [
+                AssetException::new(xrd(), DepositAddressExceptionRule::Deny),
+                AssetException::new(
+                    other_resource(),
+                    DepositAddressExceptionRule::Deny,
+                ),
+            ],
+            [],
+        );
+        let to = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [AssetException::new(
+                xrd(),
+                DepositAddressExceptionRule::Allow,
+            )],
+            [],
+        );
+        assert_eq!(
+            SUT::new(&from, &to),
+            SUT {
+                asset_exceptions_to_add_or_update: vec![AssetException::new(
+                    xrd(),
+                    DepositAddressExceptionRule::Allow
+                )],
+                asset_exceptions_to_be_removed: vec![other_resource()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn depositors_added_and_removed() {
+        let resource = ResourceOrNonFungible::Resource { value: xrd() };
+        let from = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [],
+            [resource.clone()],
+        );
+        let to = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [],
+            [nft()],
+        );
+        assert_eq!(
+            SUT::new(&from, &to),
+            SUT {
+                depositors_to_add: vec![nft()],
+                depositors_to_remove: vec![resource],
+                ..Default::default()
+            }
+        );
+    }
+}
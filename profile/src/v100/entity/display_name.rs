@@ -56,6 +56,19 @@ impl FromStr for DisplayName {
     }
 }
 
+impl JsonSchema for DisplayName {
+    fn schema_name() -> String {
+        "DisplayName".to_owned()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let mut schema = string_json_schema(None, None).into_object();
+        schema.string().min_length = Some(1);
+        schema.string().max_length = Some(Self::MAX_LEN as u32);
+        schema.into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
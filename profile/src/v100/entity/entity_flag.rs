@@ -5,6 +5,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     FromRepr,
     Clone,
     Copy,
@@ -22,6 +22,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     Debug,
     PartialEq,
@@ -289,16 +290,17 @@ impl Persona {
 
 impl Ord for Persona {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.security_state, &other.security_state) {
-            (
-                EntitySecurityState::Unsecured { value: l },
-                EntitySecurityState::Unsecured { value: r },
-            ) => l
-                .transaction_signing
-                .derivation_path()
-                .last_component()
-                .cmp(r.transaction_signing.derivation_path().last_component()),
-        }
+        self.security_state
+            .entity_creating_factor_instance()
+            .derivation_path()
+            .last_component()
+            .cmp(
+                other
+                    .security_state
+                    .entity_creating_factor_instance()
+                    .derivation_path()
+                    .last_component(),
+            )
     }
 }
 
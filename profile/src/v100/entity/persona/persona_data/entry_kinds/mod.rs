@@ -1,7 +1,15 @@
+mod persona_data_entry_company_name;
+mod persona_data_entry_date_of_birth;
 mod persona_data_entry_email_address;
 mod persona_data_entry_name;
 mod persona_data_entry_phone_number;
+mod persona_data_entry_postal_address;
+mod persona_data_entry_url;
 
+pub use persona_data_entry_company_name::*;
+pub use persona_data_entry_date_of_birth::*;
 pub use persona_data_entry_email_address::*;
 pub use persona_data_entry_name::*;
 pub use persona_data_entry_phone_number::*;
+pub use persona_data_entry_postal_address::*;
+pub use persona_data_entry_url::*;
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+/// The name of a company a person has chosen to associate with a Persona,
+/// e.g. `"Radix Publishing Ltd"`, typically used by dApps needing a business
+/// name, e.g. for invoicing.
+///
+/// Current implementation does not validate the company name other than it
+/// cannot be empty.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    PartialEq,
+    Hash,
+    Eq,
+    derive_more::Display,
+    derive_more::Debug,
+    uniffi::Record,
+)]
+#[display("{name}")]
+#[debug("{name}")]
+#[serde(transparent)]
+pub struct PersonaDataEntryCompanyName {
+    pub name: String,
+}
+
+impl FromStr for PersonaDataEntryCompanyName {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl PersonaDataEntryCompanyName {
+    pub fn new(name: impl AsRef<str>) -> Result<Self> {
+        let name = name.as_ref().trim().to_owned();
+        if name.is_empty() {
+            return Err(CommonError::PersonaDataInvalidCompanyNameEmpty);
+        }
+        Ok(Self { name })
+    }
+}
+
+impl HasPlaceholder for PersonaDataEntryCompanyName {
+    fn placeholder() -> Self {
+        Self::new("Wayne Enterprises").expect("Valid placeholder.")
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new("Radix Publishing Ltd").expect("Valid placeholder.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataEntryCompanyName;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn invalid_empty() {
+        assert_eq!(
+            SUT::new(" "),
+            Err(CommonError::PersonaDataInvalidCompanyNameEmpty)
+        );
+    }
+
+    #[test]
+    fn new_trims() {
+        assert_eq!(
+            SUT::new(" Wayne Enterprises\n").unwrap(),
+            SUT::placeholder()
+        );
+    }
+
+    #[test]
+    fn new_with_fromstr() {
+        let company: SUT = "Wayne Enterprises".parse().unwrap();
+        assert_eq!(company, SUT::placeholder());
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_json_value_eq_after_roundtrip(
+            &SUT::placeholder(),
+            json!("Wayne Enterprises"),
+        );
+    }
+}
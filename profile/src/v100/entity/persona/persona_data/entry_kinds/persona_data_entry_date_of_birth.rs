@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+/// A persons date of birth they have chosen to associate with a Persona,
+/// typically used by dApps for age checks.
+///
+/// The date of birth cannot be in the future.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    PartialEq,
+    Hash,
+    Eq,
+    derive_more::Display,
+    derive_more::Debug,
+    uniffi::Record,
+)]
+#[display("{}", date(&self.date))]
+#[debug("{}", iso8601(&self.date))]
+#[serde(transparent)]
+pub struct PersonaDataEntryDateOfBirth {
+    #[schemars(schema_with = "timestamp_json_schema")]
+    pub date: Timestamp,
+}
+
+impl PersonaDataEntryDateOfBirth {
+    pub fn new(date: Timestamp) -> Result<Self> {
+        if date > now() {
+            return Err(CommonError::PersonaDataInvalidDateOfBirthInFuture);
+        }
+        Ok(Self { date })
+    }
+}
+
+impl HasPlaceholder for PersonaDataEntryDateOfBirth {
+    fn placeholder() -> Self {
+        Self::new(Timestamp::parse("1939-05-27T00:00:00Z").unwrap())
+            .expect("Valid placeholder.")
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new(Timestamp::parse("1940-11-27T00:00:00Z").unwrap())
+            .expect("Valid placeholder.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataEntryDateOfBirth;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn invalid_in_future() {
+        assert_eq!(
+            SUT::new(Timestamp::parse("2999-01-01T00:00:00Z").unwrap()),
+            Err(CommonError::PersonaDataInvalidDateOfBirthInFuture)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", SUT::placeholder()), "1939-05-27");
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_json_value_eq_after_roundtrip(
+            &SUT::placeholder(),
+            json!("1939-05-27T00:00:00.000Z"),
+        );
+    }
+}
@@ -12,6 +12,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     PartialEq,
     Hash,
@@ -81,7 +82,15 @@ impl HasPlaceholder for PersonaDataEntryName {
 }
 
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Hash, Eq, uniffi::Enum,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Hash,
+    Eq,
+    uniffi::Enum,
 )]
 #[serde(rename_all = "lowercase")]
 pub enum Variant {
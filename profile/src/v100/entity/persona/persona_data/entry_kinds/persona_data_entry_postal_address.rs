@@ -0,0 +1,369 @@
+use crate::prelude::*;
+
+/// A postal address a person has chosen to associate with a Persona, e.g.
+/// used by dApps for shipping.
+///
+/// Which fields make up an address, and in which order they are written,
+/// depends on the country, see [`PostalAddressCountry::field_layout`]. An
+/// address is always normalized to the layout of its country, with trimmed
+/// values and empty optional fields omitted.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    PartialEq,
+    Hash,
+    Eq,
+    derive_more::Display,
+    derive_more::Debug,
+    uniffi::Record,
+)]
+#[display("{}", self.lines().join("\n"))]
+#[debug("{}", self.lines().join(", "))]
+#[serde(rename_all = "camelCase")]
+pub struct PersonaDataEntryPostalAddress {
+    pub country: PostalAddressCountry,
+    pub fields: Vec<PostalAddressField>,
+}
+
+/// The value of one field of a postal address, e.g. the city.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Hash,
+    Eq,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{value}")]
+pub struct PostalAddressField {
+    pub kind: PostalAddressFieldKind,
+    pub value: String,
+}
+
+impl PostalAddressField {
+    pub fn new(kind: PostalAddressFieldKind, value: impl AsRef<str>) -> Self {
+        Self {
+            kind,
+            value: value.as_ref().trim().to_owned(),
+        }
+    }
+}
+
+/// The different kinds of fields used in postal addresses, which kinds are
+/// used depend on the country.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Hash,
+    Eq,
+    strum_macros::Display,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PostalAddressFieldKind {
+    StreetLine0,
+    StreetLine1,
+    PostalCode,
+    Postcode,
+    Zip,
+    City,
+    Suburb,
+    County,
+    State,
+    Province,
+    Prefecture,
+}
+
+impl PostalAddressFieldKind {
+    /// Optional fields can be omitted, all other fields of the layout of a
+    /// country are required.
+    pub fn is_optional(&self) -> bool {
+        matches!(self, Self::StreetLine1 | Self::County)
+    }
+}
+
+/// The countries for which the field layout of postal addresses is known.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Hash,
+    Eq,
+    strum_macros::Display,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PostalAddressCountry {
+    Australia,
+    Canada,
+    Germany,
+    Japan,
+    Sweden,
+    #[strum(to_string = "United Kingdom")]
+    UnitedKingdom,
+    #[strum(to_string = "United States")]
+    UnitedStates,
+}
+
+impl PostalAddressCountry {
+    /// The kinds of fields, in the order they are written, making up a postal
+    /// address in this country.
+    pub fn field_layout(&self) -> Vec<PostalAddressFieldKind> {
+        use PostalAddressFieldKind::*;
+        match self {
+            Self::Australia => {
+                vec![StreetLine0, StreetLine1, Suburb, State, Postcode]
+            }
+            Self::Canada => {
+                vec![StreetLine0, StreetLine1, City, Province, PostalCode]
+            }
+            Self::Germany | Self::Sweden => {
+                vec![StreetLine0, StreetLine1, PostalCode, City]
+            }
+            Self::Japan => {
+                vec![PostalCode, Prefecture, City, StreetLine0, StreetLine1]
+            }
+            Self::UnitedKingdom => {
+                vec![StreetLine0, StreetLine1, City, County, Postcode]
+            }
+            Self::UnitedStates => {
+                vec![StreetLine0, StreetLine1, City, State, Zip]
+            }
+        }
+    }
+}
+
+impl PersonaDataEntryPostalAddress {
+    /// Validates `fields` against the field layout of `country`, every field
+    /// must be used in the country and all required fields must be non-empty.
+    /// If a kind of field occurs more than once, the first one is used.
+    pub fn new(
+        country: PostalAddressCountry,
+        fields: impl IntoIterator<Item = PostalAddressField>,
+    ) -> Result<Self> {
+        let layout = country.field_layout();
+        let fields = fields
+            .into_iter()
+            .map(|f| PostalAddressField::new(f.kind, f.value))
+            .collect_vec();
+
+        if let Some(unsupported) =
+            fields.iter().find(|f| !layout.contains(&f.kind))
+        {
+            return Err(
+                CommonError::PersonaDataInvalidPostalAddressUnsupportedField {
+                    field: unsupported.kind,
+                    country,
+                },
+            );
+        }
+
+        let mut normalized = Vec::new();
+        for kind in layout {
+            match fields
+                .iter()
+                .find(|f| f.kind == kind && !f.value.is_empty())
+            {
+                Some(field) => normalized.push(field.clone()),
+                None if kind.is_optional() => {}
+                None => return Err(
+                    CommonError::PersonaDataInvalidPostalAddressMissingField(
+                        kind,
+                    ),
+                ),
+            }
+        }
+
+        Ok(Self {
+            country,
+            fields: normalized,
+        })
+    }
+
+    /// The value of the field of `kind`, if present.
+    pub fn value_of(&self, kind: PostalAddressFieldKind) -> Option<String> {
+        self.fields
+            .iter()
+            .find(|f| f.kind == kind)
+            .map(|f| f.value.clone())
+    }
+
+    fn lines(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .map(|f| f.value.clone())
+            .chain(std::iter::once(self.country.to_string()))
+            .collect_vec()
+    }
+}
+
+impl HasPlaceholder for PersonaDataEntryPostalAddress {
+    fn placeholder() -> Self {
+        use PostalAddressFieldKind::*;
+        Self::new(
+            PostalAddressCountry::UnitedStates,
+            [
+                PostalAddressField::new(StreetLine0, "1007 Mountain Drive"),
+                PostalAddressField::new(City, "Gotham City"),
+                PostalAddressField::new(State, "NJ"),
+                PostalAddressField::new(Zip, "07001"),
+            ],
+        )
+        .expect("Valid placeholder.")
+    }
+
+    fn placeholder_other() -> Self {
+        use PostalAddressFieldKind::*;
+        Self::new(
+            PostalAddressCountry::Sweden,
+            [
+                PostalAddressField::new(StreetLine0, "Drottninggatan 1"),
+                PostalAddressField::new(PostalCode, "111 51"),
+                PostalAddressField::new(City, "Stockholm"),
+            ],
+        )
+        .expect("Valid placeholder.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataEntryPostalAddress;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn fields_are_ordered_by_layout() {
+        use PostalAddressFieldKind::*;
+        let sut = SUT::new(
+            PostalAddressCountry::Japan,
+            [
+                PostalAddressField::new(StreetLine0, "1-1 Chiyoda"),
+                PostalAddressField::new(City, "Chiyoda-ku"),
+                PostalAddressField::new(Prefecture, "Tokyo"),
+                PostalAddressField::new(PostalCode, " 100-0001 "),
+                PostalAddressField::new(StreetLine1, ""),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            sut.fields.iter().map(|f| f.kind).collect_vec(),
+            [PostalCode, Prefecture, City, StreetLine0]
+        );
+        assert_eq!(sut.value_of(PostalCode), Some("100-0001".to_owned()));
+        assert_eq!(sut.value_of(StreetLine1), None);
+    }
+
+    #[test]
+    fn missing_required_field() {
+        use PostalAddressFieldKind::*;
+        assert_eq!(
+            SUT::new(
+                PostalAddressCountry::Sweden,
+                [
+                    PostalAddressField::new(StreetLine0, "Drottninggatan 1"),
+                    PostalAddressField::new(City, "Stockholm"),
+                ],
+            ),
+            Err(CommonError::PersonaDataInvalidPostalAddressMissingField(
+                PostalCode
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_required_field() {
+        use PostalAddressFieldKind::*;
+        assert_eq!(
+            SUT::new(
+                PostalAddressCountry::Sweden,
+                [
+                    PostalAddressField::new(StreetLine0, " "),
+                    PostalAddressField::new(PostalCode, "111 51"),
+                    PostalAddressField::new(City, "Stockholm"),
+                ],
+            ),
+            Err(CommonError::PersonaDataInvalidPostalAddressMissingField(
+                StreetLine0
+            ))
+        );
+    }
+
+    #[test]
+    fn unsupported_field() {
+        use PostalAddressFieldKind::*;
+        assert_eq!(
+            SUT::new(
+                PostalAddressCountry::Sweden,
+                [PostalAddressField::new(Zip, "111 51")],
+            ),
+            Err(
+                CommonError::PersonaDataInvalidPostalAddressUnsupportedField {
+                    field: Zip,
+                    country: PostalAddressCountry::Sweden
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", SUT::placeholder()),
+            "1007 Mountain Drive\nGotham City\nNJ\n07001\nUnited States"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder_other() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder_other(),
+            r#"
+            {
+                "country": "sweden",
+                "fields": [
+                    {
+                        "kind": "streetLine0",
+                        "value": "Drottninggatan 1"
+                    },
+                    {
+                        "kind": "postalCode",
+                        "value": "111 51"
+                    },
+                    {
+                        "kind": "city",
+                        "value": "Stockholm"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use crate::prelude::*;
+
+/// A URL a person has chosen to associate with a Persona, e.g. a personal
+/// website or a social media profile, e.g. `https://radixdlt.com`.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    PartialEq,
+    Hash,
+    Eq,
+    derive_more::Display,
+    derive_more::Debug,
+    uniffi::Record,
+)]
+#[display("{url}")]
+#[debug("{url}")]
+#[serde(transparent)]
+pub struct PersonaDataEntryUrl {
+    #[schemars(schema_with = "url_json_schema")]
+    pub url: Url,
+}
+
+impl FromStr for PersonaDataEntryUrl {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl PersonaDataEntryUrl {
+    /// Only `http` and `https` URLs are accepted, since the URL might be
+    /// opened by the wallet or a dApp, e.g. `javascript:` or `file:` URLs
+    /// are rejected.
+    pub fn new(url: impl AsRef<str>) -> Result<Self> {
+        let url = url.as_ref().trim();
+        Url::parse(url)
+            .ok()
+            .filter(|u| matches!(u.scheme(), "http" | "https"))
+            .map(|url| Self { url })
+            .ok_or_else(|| CommonError::PersonaDataInvalidUrl(url.to_owned()))
+    }
+}
+
+impl HasPlaceholder for PersonaDataEntryUrl {
+    fn placeholder() -> Self {
+        Self::new("https://radixdlt.com").expect("Valid placeholder.")
+    }
+
+    fn placeholder_other() -> Self {
+        Self::new("https://github.com/radixdlt").expect("Valid placeholder.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataEntryUrl;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            SUT::new("radix dlt"),
+            Err(CommonError::PersonaDataInvalidUrl("radix dlt".to_owned()))
+        );
+    }
+
+    #[test]
+    fn http_is_valid() {
+        assert_eq!(
+            SUT::new("http://radixdlt.com").unwrap().url.scheme(),
+            "http"
+        );
+    }
+
+    #[test]
+    fn non_http_schemes_are_invalid() {
+        for url in [
+            "javascript:alert(1)",
+            "file:///etc/passwd",
+            "data:text/html,<script>alert(1)</script>",
+            "ftp://radixdlt.com",
+        ] {
+            assert_eq!(
+                SUT::new(url),
+                Err(CommonError::PersonaDataInvalidUrl(url.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn new_with_fromstr() {
+        let url: SUT = "https://radixdlt.com".parse().unwrap();
+        assert_eq!(url, SUT::placeholder());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", SUT::placeholder_other()),
+            "https://github.com/radixdlt"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_json_value_eq_after_roundtrip(
+            &SUT::placeholder(),
+            json!("https://radixdlt.com/"),
+        );
+    }
+}
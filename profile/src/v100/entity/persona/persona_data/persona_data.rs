@@ -22,6 +22,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     Default,
     PartialEq,
@@ -53,6 +54,26 @@ pub struct PersonaData {
     /// `(Uuid, PersonaDataIdentifiedEmailAddress)`, each element is identifiable by its ID. Can be empty, can
     /// contain elements with the same value, but under different IDs.
     pub email_addresses: CollectionOfEmailAddresses,
+
+    /// The date of birth of a person, e.g. used by dApps for age checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<PersonaDataIdentifiedDateOfBirth>,
+
+    /// The name of the company a person is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<PersonaDataIdentifiedCompanyName>,
+
+    /// A collection of [`PersonaDataIdentifiedPostalAddress`]es, e.g. used by
+    /// dApps for shipping, each with a country specific layout.
+    #[serde(
+        default,
+        skip_serializing_if = "CollectionOfPostalAddresses::is_empty"
+    )]
+    pub postal_addresses: CollectionOfPostalAddresses,
+
+    /// A collection of [`PersonaDataIdentifiedUrl`]s, e.g. personal websites.
+    #[serde(default, skip_serializing_if = "CollectionOfUrls::is_empty")]
+    pub urls: CollectionOfUrls,
 }
 
 impl PersonaData {
@@ -65,6 +86,238 @@ impl PersonaData {
             name: name.into(),
             phone_numbers,
             email_addresses,
+            ..Default::default()
+        }
+    }
+}
+
+impl PersonaData {
+    /// Sets the name to `name`, keeping the ID of any existing name entry.
+    /// `name` is validated, returns the identified name entry.
+    pub fn set_name(
+        &mut self,
+        name: PersonaDataEntryName,
+    ) -> Result<PersonaDataIdentifiedName> {
+        let value = PersonaDataEntryName::new(
+            name.variant.clone(),
+            &name.family_name,
+            &name.given_names,
+            &name.nickname,
+        )?;
+        let entry = match self.name.as_ref() {
+            Some(existing) => {
+                PersonaDataIdentifiedName::with_id(existing.id.clone(), value)
+            }
+            None => PersonaDataIdentifiedName::new(value),
+        };
+        self.name = Some(entry.clone());
+        Ok(entry)
+    }
+
+    /// Validates and adds `email` as a new entry with a new ID.
+    pub fn add_email_address(
+        &mut self,
+        email: PersonaDataEntryEmailAddress,
+    ) -> Result<PersonaDataIdentifiedEmailAddress> {
+        let entry = PersonaDataIdentifiedEmailAddress::new(
+            PersonaDataEntryEmailAddress::new(&email.email)?,
+        );
+        self.email_addresses.collection.append(entry.clone());
+        Ok(entry)
+    }
+
+    /// Validates and updates the value of the existing email address entry
+    /// with the same ID as `entry`.
+    pub fn update_email_address(
+        &mut self,
+        entry: PersonaDataIdentifiedEmailAddress,
+    ) -> Result<()> {
+        let value = PersonaDataEntryEmailAddress::new(&entry.email)?;
+        if self
+            .email_addresses
+            .collection
+            .update_with(&entry.id, |e| e.value = value.clone())
+        {
+            Ok(())
+        } else {
+            Err(CommonError::UnknownPersonaDataEntry(entry.id))
+        }
+    }
+
+    /// Validates and adds `number` as a new entry with a new ID.
+    pub fn add_phone_number(
+        &mut self,
+        number: PersonaDataEntryPhoneNumber,
+    ) -> Result<PersonaDataIdentifiedPhoneNumber> {
+        let entry = PersonaDataIdentifiedPhoneNumber::new(
+            PersonaDataEntryPhoneNumber::new(&number.number)?,
+        );
+        self.phone_numbers.collection.append(entry.clone());
+        Ok(entry)
+    }
+
+    /// Validates and updates the value of the existing phone number entry
+    /// with the same ID as `entry`.
+    pub fn update_phone_number(
+        &mut self,
+        entry: PersonaDataIdentifiedPhoneNumber,
+    ) -> Result<()> {
+        let value = PersonaDataEntryPhoneNumber::new(&entry.number)?;
+        if self
+            .phone_numbers
+            .collection
+            .update_with(&entry.id, |e| e.value = value.clone())
+        {
+            Ok(())
+        } else {
+            Err(CommonError::UnknownPersonaDataEntry(entry.id))
+        }
+    }
+
+    /// Sets the date of birth to `date_of_birth`, keeping the ID of any
+    /// existing date of birth entry.
+    pub fn set_date_of_birth(
+        &mut self,
+        date_of_birth: PersonaDataEntryDateOfBirth,
+    ) -> Result<PersonaDataIdentifiedDateOfBirth> {
+        let value = PersonaDataEntryDateOfBirth::new(date_of_birth.date)?;
+        let entry = match self.date_of_birth.as_ref() {
+            Some(existing) => PersonaDataIdentifiedDateOfBirth::with_id(
+                existing.id.clone(),
+                value,
+            ),
+            None => PersonaDataIdentifiedDateOfBirth::new(value),
+        };
+        self.date_of_birth = Some(entry.clone());
+        Ok(entry)
+    }
+
+    /// Sets the company name to `company_name`, keeping the ID of any
+    /// existing company name entry.
+    pub fn set_company_name(
+        &mut self,
+        company_name: PersonaDataEntryCompanyName,
+    ) -> Result<PersonaDataIdentifiedCompanyName> {
+        let value = PersonaDataEntryCompanyName::new(&company_name.name)?;
+        let entry = match self.company_name.as_ref() {
+            Some(existing) => PersonaDataIdentifiedCompanyName::with_id(
+                existing.id.clone(),
+                value,
+            ),
+            None => PersonaDataIdentifiedCompanyName::new(value),
+        };
+        self.company_name = Some(entry.clone());
+        Ok(entry)
+    }
+
+    /// Validates and adds `postal_address` as a new entry with a new ID.
+    pub fn add_postal_address(
+        &mut self,
+        postal_address: PersonaDataEntryPostalAddress,
+    ) -> Result<PersonaDataIdentifiedPostalAddress> {
+        let entry = PersonaDataIdentifiedPostalAddress::new(
+            PersonaDataEntryPostalAddress::new(
+                postal_address.country,
+                postal_address.fields,
+            )?,
+        );
+        self.postal_addresses.collection.append(entry.clone());
+        Ok(entry)
+    }
+
+    /// Validates and updates the value of the existing postal address entry
+    /// with the same ID as `entry`.
+    pub fn update_postal_address(
+        &mut self,
+        entry: PersonaDataIdentifiedPostalAddress,
+    ) -> Result<()> {
+        let value = PersonaDataEntryPostalAddress::new(
+            entry.country,
+            entry.fields.clone(),
+        )?;
+        if self
+            .postal_addresses
+            .collection
+            .update_with(&entry.id, |e| e.value = value.clone())
+        {
+            Ok(())
+        } else {
+            Err(CommonError::UnknownPersonaDataEntry(entry.id))
+        }
+    }
+
+    /// Validates and adds `url` as a new entry with a new ID.
+    pub fn add_url(
+        &mut self,
+        url: PersonaDataEntryUrl,
+    ) -> Result<PersonaDataIdentifiedUrl> {
+        let entry = PersonaDataIdentifiedUrl::new(PersonaDataEntryUrl::new(
+            url.url.as_str(),
+        )?);
+        self.urls.collection.append(entry.clone());
+        Ok(entry)
+    }
+
+    /// Validates and updates the value of the existing URL entry with the
+    /// same ID as `entry`.
+    pub fn update_url(
+        &mut self,
+        entry: PersonaDataIdentifiedUrl,
+    ) -> Result<()> {
+        let value = PersonaDataEntryUrl::new(entry.url.as_str())?;
+        if self
+            .urls
+            .collection
+            .update_with(&entry.id, |e| e.value = value.clone())
+        {
+            Ok(())
+        } else {
+            Err(CommonError::UnknownPersonaDataEntry(entry.id))
+        }
+    }
+
+    /// Removes the entry of `kind` with ID `id`, entries of other kinds
+    /// are left untouched even if they have the same ID.
+    pub fn remove_entry(
+        &mut self,
+        kind: PersonaDataEntryKind,
+        id: &PersonaDataEntryID,
+    ) -> Result<()> {
+        fn take_if<T: Identifiable<ID = PersonaDataEntryID>>(
+            entry: &mut Option<T>,
+            id: &PersonaDataEntryID,
+        ) -> bool {
+            if entry.as_ref().map(|e| e.id()).as_ref() != Some(id) {
+                return false;
+            }
+            *entry = None;
+            true
+        }
+        let removed = match kind {
+            PersonaDataEntryKind::Name => take_if(&mut self.name, id),
+            PersonaDataEntryKind::DateOfBirth => {
+                take_if(&mut self.date_of_birth, id)
+            }
+            PersonaDataEntryKind::CompanyName => {
+                take_if(&mut self.company_name, id)
+            }
+            PersonaDataEntryKind::EmailAddress => {
+                self.email_addresses.collection.remove_by_id(id).is_some()
+            }
+            PersonaDataEntryKind::PhoneNumber => {
+                self.phone_numbers.collection.remove_by_id(id).is_some()
+            }
+            PersonaDataEntryKind::PostalAddress => {
+                self.postal_addresses.collection.remove_by_id(id).is_some()
+            }
+            PersonaDataEntryKind::Url => {
+                self.urls.collection.remove_by_id(id).is_some()
+            }
+        };
+        if removed {
+            Ok(())
+        } else {
+            Err(CommonError::UnknownPersonaDataEntry(id.clone()))
         }
     }
 }
@@ -128,8 +381,39 @@ impl PersonaData {
             .map(|v| format!("email: {v}"))
             .join("\n");
 
+        let describe = |v: String, d: String| if include_id { d } else { v };
+        let date_of_birth = self
+            .date_of_birth
+            .as_ref()
+            .map(|v| describe(v.to_string(), v.dbg_string()))
+            .map(|v| format!("date of birth: {v}"));
+        let company_name = self
+            .company_name
+            .as_ref()
+            .map(|v| describe(v.to_string(), v.dbg_string()))
+            .map(|v| format!("company name: {v}"));
+        let postal_addresses = self
+            .postal_addresses
+            .iter()
+            .map(|v| describe(v.to_string(), v.dbg_string()))
+            .map(|v| format!("postal address: {v}"));
+        let urls = self
+            .urls
+            .iter()
+            .map(|v| describe(v.to_string(), v.dbg_string()))
+            .map(|v| format!("url: {v}"));
+
+        // Only present entries of the kinds added after name, phone and email
+        // are included, to not change the representation of existing data.
+        let others = date_of_birth
+            .into_iter()
+            .chain(company_name)
+            .chain(postal_addresses)
+            .chain(urls);
+
         [name.unwrap_or_default(), phones, emails]
             .into_iter()
+            .chain(others)
             .join("\n")
     }
 }
@@ -265,4 +549,265 @@ mod tests {
     fn debug() {
         assert_eq!(format!("{:?}", PersonaData::placeholder()), "name: Bruce Batman Wayne\nphone: +46123456789 - 00000000-0000-0000-0000-000000000001\nphone: +44987654321 - 00000000-0000-0000-0000-000000000002\nemail: alan@turing.hero - 00000000-0000-0000-0000-000000000001\nemail: satoshi@nakamoto.btc - 00000000-0000-0000-0000-000000000002");
     }
+
+    #[test]
+    fn set_name_keeps_id() {
+        let mut sut = PersonaData::placeholder();
+        let id = sut.name.as_ref().unwrap().id.clone();
+        let entry = sut
+            .set_name(PersonaDataEntryName::placeholder_other())
+            .unwrap();
+        assert_eq!(entry.id, id);
+        assert_eq!(sut.name, Some(entry));
+    }
+
+    #[test]
+    fn set_name_invalid() {
+        let mut sut = PersonaData::default();
+        let mut name = PersonaDataEntryName::placeholder();
+        name.family_name = " ".to_owned();
+        assert_eq!(
+            sut.set_name(name),
+            Err(CommonError::PersonaDataInvalidNameFamilyNameEmpty)
+        );
+        assert_eq!(sut, PersonaData::default());
+    }
+
+    #[test]
+    fn add_update_remove_email_address() {
+        let mut sut = PersonaData::default();
+        let entry = sut
+            .add_email_address(PersonaDataEntryEmailAddress::placeholder())
+            .unwrap();
+        let mut updated = entry.clone();
+        updated.value = PersonaDataEntryEmailAddress::placeholder_other();
+        sut.update_email_address(updated.clone()).unwrap();
+        assert_eq!(sut.email_addresses.items(), [updated]);
+        sut.remove_entry(PersonaDataEntryKind::EmailAddress, &entry.id)
+            .unwrap();
+        assert!(sut.email_addresses.is_empty());
+        assert_eq!(
+            sut.remove_entry(PersonaDataEntryKind::EmailAddress, &entry.id),
+            Err(CommonError::UnknownPersonaDataEntry(entry.id))
+        );
+    }
+
+    #[test]
+    fn add_email_address_invalid() {
+        let mut sut = PersonaData::default();
+        assert_eq!(
+            sut.add_email_address(PersonaDataEntryEmailAddress {
+                email: "".to_owned()
+            }),
+            Err(CommonError::PersonaDataInvalidEmailAddressEmpty)
+        );
+    }
+
+    #[test]
+    fn update_phone_number_invalid_or_unknown() {
+        let mut sut = PersonaData::default();
+        let mut entry = sut
+            .add_phone_number(PersonaDataEntryPhoneNumber::placeholder())
+            .unwrap();
+        entry.value.number = "".to_owned();
+        assert_eq!(
+            sut.update_phone_number(entry),
+            Err(CommonError::PersonaDataInvalidPhoneNumberEmpty)
+        );
+        let unknown = PersonaDataIdentifiedPhoneNumber::placeholder();
+        assert_eq!(
+            sut.update_phone_number(unknown.clone()),
+            Err(CommonError::UnknownPersonaDataEntry(unknown.id))
+        );
+    }
+
+    #[test]
+    fn set_date_of_birth_and_company_name_keeps_ids() {
+        let mut sut = PersonaData::default();
+        let dob = sut
+            .set_date_of_birth(PersonaDataEntryDateOfBirth::placeholder())
+            .unwrap();
+        let company = sut
+            .set_company_name(PersonaDataEntryCompanyName::placeholder())
+            .unwrap();
+        assert_eq!(
+            sut.set_date_of_birth(
+                PersonaDataEntryDateOfBirth::placeholder_other()
+            )
+            .unwrap()
+            .id,
+            dob.id
+        );
+        assert_eq!(
+            sut.set_company_name(
+                PersonaDataEntryCompanyName::placeholder_other()
+            )
+            .unwrap()
+            .id,
+            company.id
+        );
+        assert_eq!(
+            sut.set_company_name(PersonaDataEntryCompanyName {
+                name: "".to_owned()
+            }),
+            Err(CommonError::PersonaDataInvalidCompanyNameEmpty)
+        );
+        sut.remove_entry(PersonaDataEntryKind::DateOfBirth, &dob.id)
+            .unwrap();
+        assert_eq!(sut.date_of_birth, None);
+    }
+
+    #[test]
+    fn remove_entry_leaves_same_id_of_other_kind() {
+        let id = PersonaDataEntryID::placeholder();
+        let email = PersonaDataIdentifiedEmailAddress::with_id(
+            id.clone(),
+            PersonaDataEntryEmailAddress::placeholder(),
+        );
+        let phone = PersonaDataIdentifiedPhoneNumber::with_id(
+            id.clone(),
+            PersonaDataEntryPhoneNumber::placeholder(),
+        );
+        let mut sut = PersonaData::new(
+            None,
+            CollectionOfPhoneNumbers::entries([phone.clone()]),
+            CollectionOfEmailAddresses::entries([email]),
+        );
+        sut.remove_entry(PersonaDataEntryKind::EmailAddress, &id)
+            .unwrap();
+        assert!(sut.email_addresses.is_empty());
+        assert_eq!(sut.phone_numbers.items(), [phone]);
+        assert_eq!(
+            sut.remove_entry(PersonaDataEntryKind::Name, &id),
+            Err(CommonError::UnknownPersonaDataEntry(id))
+        );
+    }
+
+    #[test]
+    fn add_update_remove_postal_address_and_url() {
+        let mut sut = PersonaData::default();
+        let mut address = sut
+            .add_postal_address(PersonaDataEntryPostalAddress::placeholder())
+            .unwrap();
+        address.value = PersonaDataEntryPostalAddress::placeholder_other();
+        sut.update_postal_address(address.clone()).unwrap();
+        assert_eq!(sut.postal_addresses.items(), [address.clone()]);
+
+        let url = sut.add_url(PersonaDataEntryUrl::placeholder()).unwrap();
+        sut.remove_entry(PersonaDataEntryKind::Url, &url.id)
+            .unwrap();
+        sut.remove_entry(PersonaDataEntryKind::PostalAddress, &address.id)
+            .unwrap();
+        assert_eq!(sut, PersonaData::default());
+    }
+
+    #[test]
+    fn update_postal_address_invalid() {
+        let mut sut = PersonaData::default();
+        let mut address = sut
+            .add_postal_address(PersonaDataEntryPostalAddress::placeholder())
+            .unwrap();
+        address.value.fields.clear();
+        assert_eq!(
+            sut.update_postal_address(address),
+            Err(CommonError::PersonaDataInvalidPostalAddressMissingField(
+                PostalAddressFieldKind::StreetLine0
+            ))
+        );
+    }
+
+    #[test]
+    fn json_without_new_kinds_is_backward_compatible() {
+        let json = r#"
+        {
+            "name": {
+                "id": "00000000-0000-0000-0000-000000000001",
+                "value": {
+                    "variant": "western",
+                    "familyName": "Wayne",
+                    "givenNames": "Bruce",
+                    "nickname": "Batman"
+                }
+            },
+            "phoneNumbers": [],
+            "emailAddresses": []
+        }
+        "#;
+        let sut = serde_json::from_str::<PersonaData>(json).unwrap();
+        assert_eq!(sut.date_of_birth, None);
+        assert!(sut.postal_addresses.is_empty());
+        assert_eq_after_json_roundtrip(&sut, json);
+    }
+
+    #[test]
+    fn json_roundtrip_new_kinds() {
+        let sut = PersonaData {
+            date_of_birth: Some(PersonaDataIdentifiedDateOfBirth::placeholder()),
+            company_name: Some(
+                PersonaDataIdentifiedCompanyName::placeholder_other(),
+            ),
+            postal_addresses: CollectionOfPostalAddresses::placeholder_other(),
+            urls: CollectionOfUrls::placeholder_other(),
+            ..Default::default()
+        };
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "name": null,
+                "phoneNumbers": [],
+                "emailAddresses": [],
+                "dateOfBirth": {
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "value": "1939-05-27T00:00:00.000Z"
+                },
+                "companyName": {
+                    "id": "00000000-0000-0000-0000-000000000002",
+                    "value": "Radix Publishing Ltd"
+                },
+                "postalAddresses": [
+                    {
+                        "id": "00000000-0000-0000-0000-000000000002",
+                        "value": {
+                            "country": "sweden",
+                            "fields": [
+                                {
+                                    "kind": "streetLine0",
+                                    "value": "Drottninggatan 1"
+                                },
+                                {
+                                    "kind": "postalCode",
+                                    "value": "111 51"
+                                },
+                                {
+                                    "kind": "city",
+                                    "value": "Stockholm"
+                                }
+                            ]
+                        }
+                    }
+                ],
+                "urls": [
+                    {
+                        "id": "00000000-0000-0000-0000-000000000002",
+                        "value": "https://github.com/radixdlt"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn display_new_kinds() {
+        let sut = PersonaData {
+            company_name: Some(PersonaDataIdentifiedCompanyName::placeholder()),
+            urls: CollectionOfUrls::placeholder_other(),
+            ..Default::default()
+        };
+        assert_eq!(
+            format!("{sut}"),
+            "\n\n\ncompany name: Wayne Enterprises\nurl: https://github.com/radixdlt"
+        );
+    }
 }
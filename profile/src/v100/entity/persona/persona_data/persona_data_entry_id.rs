@@ -7,6 +7,7 @@ uniffi::custom_newtype!(PersonaDataEntryID, Uuid);
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     PartialEq,
     Eq,
@@ -15,7 +16,9 @@ uniffi::custom_newtype!(PersonaDataEntryID, Uuid);
     Hash,
 )]
 #[serde(transparent)]
-pub struct PersonaDataEntryID(pub Uuid); // Unfortunately we have to declare this since we want it to impl `Identifiable`, which is not our trait and `Uuid` is not our type... => force to have a newtype.
+pub struct PersonaDataEntryID(
+    #[schemars(schema_with = "uuid_json_schema")] pub Uuid,
+); // Unfortunately we have to declare this since we want it to impl `Identifiable`, which is not our trait and `Uuid` is not our type... => force to have a newtype.
 
 impl Identifiable for PersonaDataEntryID {
     type ID = Self;
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+
+/// The kind of a PersonaData entry, [`PersonaDataEntryID`]s are only unique
+/// among the entries of the same kind, so an entry is identified by its
+/// kind and ID.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::Display, uniffi::Enum,
+)]
+pub enum PersonaDataEntryKind {
+    Name,
+    EmailAddress,
+    PhoneNumber,
+    DateOfBirth,
+    CompanyName,
+    PostalAddress,
+    Url,
+}
@@ -9,6 +9,7 @@ macro_rules! declare_collection_of_identified_entry {
         #[derive(
             Serialize,
             Deserialize,
+            JsonSchema,
             Clone,
             PartialEq,
             Hash,
@@ -52,6 +53,11 @@ macro_rules! declare_collection_of_identified_entry {
                 Self::entries([value])
             }
 
+            /// Returns `true` if the collection contains no entries.
+            pub fn is_empty(&self) -> bool {
+                self.collection.is_empty()
+            }
+
             /// Creates a new CollectionOf PersonaDataEntries using just the *value*, which will be given a
             /// generated ID and put in an identified entry.
             pub fn single_value(
@@ -103,6 +109,16 @@ declare_collection_of_identified_entry!(
     CollectionOfEmailAddresses
 );
 
+declare_collection_of_identified_entry!(
+    PersonaDataIdentifiedPostalAddress,
+    CollectionOfPostalAddresses
+);
+
+declare_collection_of_identified_entry!(
+    PersonaDataIdentifiedUrl,
+    CollectionOfUrls
+);
+
 #[cfg(test)]
 mod collection_of_phone_numbers_tests {
     use crate::prelude::*;
@@ -351,3 +367,47 @@ mod collection_of_email_addresses_tests {
         )
     }
 }
+
+#[cfg(test)]
+mod collection_of_urls_tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = CollectionOfUrls;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(SUT::default().is_empty());
+        assert!(!SUT::placeholder().is_empty());
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            [
+                {
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "value": "https://radixdlt.com/"
+                },
+                {
+                    "id": "00000000-0000-0000-0000-000000000002",
+                    "value": "https://github.com/radixdlt"
+                }
+            ]
+            "#,
+        );
+    }
+}
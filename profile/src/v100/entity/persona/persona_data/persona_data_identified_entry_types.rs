@@ -12,6 +12,7 @@ macro_rules! declare_identified_entry {
         #[derive(
             Serialize,
             Deserialize,
+            JsonSchema,
             Clone,
             PartialEq,
             Hash,
@@ -91,6 +92,19 @@ declare_identified_entry!(
     PersonaDataEntryEmailAddress,
     PersonaDataIdentifiedEmailAddress
 );
+declare_identified_entry!(
+    PersonaDataEntryDateOfBirth,
+    PersonaDataIdentifiedDateOfBirth
+);
+declare_identified_entry!(
+    PersonaDataEntryCompanyName,
+    PersonaDataIdentifiedCompanyName
+);
+declare_identified_entry!(
+    PersonaDataEntryPostalAddress,
+    PersonaDataIdentifiedPostalAddress
+);
+declare_identified_entry!(PersonaDataEntryUrl, PersonaDataIdentifiedUrl);
 
 #[cfg(test)]
 mod identified_name_tests {
@@ -375,3 +389,104 @@ mod identified_email_tests {
         )
     }
 }
+
+#[cfg(test)]
+mod identified_postal_address_tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataIdentifiedPostalAddress;
+    type V = PersonaDataEntryPostalAddress;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+        assert_ne!(SUT::new(V::placeholder()), SUT::new(V::placeholder()));
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            {
+                "id": "00000000-0000-0000-0000-000000000001",
+                "value": {
+                    "country": "unitedStates",
+                    "fields": [
+                        {
+                            "kind": "streetLine0",
+                            "value": "1007 Mountain Drive"
+                        },
+                        {
+                            "kind": "city",
+                            "value": "Gotham City"
+                        },
+                        {
+                            "kind": "state",
+                            "value": "NJ"
+                        },
+                        {
+                            "kind": "zip",
+                            "value": "07001"
+                        }
+                    ]
+                }
+            }
+            "#,
+        );
+    }
+}
+
+#[cfg(test)]
+mod identified_date_of_birth_tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataIdentifiedDateOfBirth;
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", SUT::placeholder()), "1939-05-27");
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder_other() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder_other(),
+            r#"
+            {
+                "id": "00000000-0000-0000-0000-000000000002",
+                "value": "1940-11-27T00:00:00.000Z"
+            }
+            "#,
+        );
+    }
+}
+
+#[cfg(test)]
+mod identified_company_name_tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataIdentifiedCompanyName;
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_eq_after_json_roundtrip(
+            &SUT::placeholder(),
+            r#"
+            {
+                "id": "00000000-0000-0000-0000-000000000001",
+                "value": "Wayne Enterprises"
+            }
+            "#,
+        );
+    }
+}
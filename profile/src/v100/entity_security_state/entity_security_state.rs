@@ -21,6 +21,13 @@ pub enum EntitySecurityState {
         #[serde(rename = "unsecuredEntityControl")]
         value: UnsecuredEntityControl,
     },
+
+    /// The account is controlled by an `AccessController` holding its owner
+    /// badge, with a potential Multi-Factor setup.
+    Securified {
+        #[serde(rename = "securedEntityControl")]
+        value: SecuredEntityControl,
+    },
 }
 
 impl<'de> Deserialize<'de> for EntitySecurityState {
@@ -53,17 +60,93 @@ impl Serialize for EntitySecurityState {
                 state.serialize_field("discriminator", "unsecured")?;
                 state.serialize_field("unsecuredEntityControl", value)?;
             }
+            EntitySecurityState::Securified { value } => {
+                state.serialize_field("discriminator", "securified")?;
+                state.serialize_field("securedEntityControl", value)?;
+            }
         }
         state.end()
     }
 }
 
+impl JsonSchema for EntitySecurityState {
+    fn schema_name() -> String {
+        "EntitySecurityState".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        discriminated_union_json_schema([
+            (
+                "unsecured",
+                "unsecuredEntityControl",
+                gen.subschema_for::<UnsecuredEntityControl>(),
+            ),
+            (
+                "securified",
+                "securedEntityControl",
+                gen.subschema_for::<SecuredEntityControl>(),
+            ),
+        ])
+    }
+}
+
 impl From<UnsecuredEntityControl> for EntitySecurityState {
     fn from(value: UnsecuredEntityControl) -> Self {
         Self::Unsecured { value }
     }
 }
 
+impl From<SecuredEntityControl> for EntitySecurityState {
+    fn from(value: SecuredEntityControl) -> Self {
+        Self::Securified { value }
+    }
+}
+
+impl EntitySecurityState {
+    /// The factor instance which was used to create the entity, for an
+    /// unsecured entity it is the one still controlling it.
+    pub fn entity_creating_factor_instance(
+        &self,
+    ) -> &HierarchicalDeterministicFactorInstance {
+        match self {
+            Self::Unsecured { value } => &value.transaction_signing,
+            Self::Securified { value } => {
+                &value.virtual_entity_creating_instance
+            }
+        }
+    }
+
+    /// All factor instances of the entity, the one which created it first.
+    pub fn factor_instances(
+        &self,
+    ) -> Vec<HierarchicalDeterministicFactorInstance> {
+        match self {
+            Self::Unsecured { value } => [value.transaction_signing.clone()]
+                .into_iter()
+                .chain(value.authentication_signing.clone())
+                .collect(),
+            Self::Securified { value } => {
+                let structure = &value.security_structure;
+                [value.virtual_entity_creating_instance.clone()]
+                    .into_iter()
+                    .chain(structure.primary_role.all_factors().cloned())
+                    .chain(structure.recovery_role.all_factors().cloned())
+                    .chain(structure.confirmation_role.all_factors().cloned())
+                    .collect()
+            }
+        }
+    }
+
+    /// The control of the entity by its `AccessController`, fails if the
+    /// entity is not securified.
+    pub fn secured_entity_control(&self) -> Result<&SecuredEntityControl> {
+        match self {
+            Self::Unsecured { .. } => Err(CommonError::EntityNotSecurified),
+            Self::Securified { value } => Ok(value),
+        }
+    }
+}
+
 impl HasPlaceholder for EntitySecurityState {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -104,6 +187,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_creating_factor_instance() {
+        let unsecured = UnsecuredEntityControl::placeholder();
+        assert_eq!(
+            EntitySecurityState::from(unsecured.clone())
+                .entity_creating_factor_instance(),
+            &unsecured.transaction_signing
+        );
+        let secured = SecuredEntityControl::placeholder();
+        assert_eq!(
+            EntitySecurityState::from(secured.clone())
+                .entity_creating_factor_instance(),
+            &secured.virtual_entity_creating_instance
+        );
+    }
+
+    #[test]
+    fn factor_instances() {
+        let unsecured = UnsecuredEntityControl::placeholder();
+        assert_eq!(
+            EntitySecurityState::from(unsecured.clone()).factor_instances(),
+            vec![unsecured.transaction_signing]
+        );
+        let secured = SecuredEntityControl::placeholder();
+        let sut = EntitySecurityState::from(secured.clone()).factor_instances();
+        assert_eq!(sut[0], secured.virtual_entity_creating_instance);
+        assert_eq!(sut.len(), 5);
+    }
+
+    #[test]
+    fn secured_entity_control() {
+        assert_eq!(
+            EntitySecurityState::placeholder().secured_entity_control(),
+            Err(CommonError::EntityNotSecurified)
+        );
+        let secured = SecuredEntityControl::placeholder();
+        assert_eq!(
+            EntitySecurityState::from(secured.clone()).secured_entity_control(),
+            Ok(&secured)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_securified() {
+        let model: EntitySecurityState =
+            SecuredEntityControl::placeholder().into();
+        assert_json_roundtrip(&model);
+        let json = serde_json::to_value(&model).unwrap();
+        assert_eq!(json["discriminator"], "securified");
+        assert!(json["securedEntityControl"].is_object());
+    }
+
     #[test]
     fn json_roundtrip() {
         let model = EntitySecurityState::placeholder();
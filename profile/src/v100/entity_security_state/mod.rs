@@ -1,5 +1,11 @@
 mod entity_security_state;
+mod role_of_factor_instances;
+mod secured_entity_control;
+mod security_structure_of_factor_instances;
 mod unsecured_entity_control;
 
 pub use entity_security_state::*;
+pub use role_of_factor_instances::*;
+pub use secured_entity_control::*;
+pub use security_structure_of_factor_instances::*;
 pub use unsecured_entity_control::*;
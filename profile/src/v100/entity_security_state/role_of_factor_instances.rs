@@ -0,0 +1,189 @@
+use crate::prelude::*;
+
+/// The factor instances of one role of an AccessController, e.g. the primary
+/// role, the role is fulfilled if `threshold` many of the `threshold_factors`
+/// or any single one of the `override_factors` sign.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleOfFactorInstances {
+    /// Factors of which at least `threshold` many must sign.
+    pub threshold_factors: Vec<HierarchicalDeterministicFactorInstance>,
+
+    /// How many of the `threshold_factors` must sign.
+    pub threshold: u8,
+
+    /// Factors of which any single one can sign on its own.
+    pub override_factors: Vec<HierarchicalDeterministicFactorInstance>,
+}
+
+impl RoleOfFactorInstances {
+    pub fn new(
+        threshold_factors: impl IntoIterator<
+            Item = HierarchicalDeterministicFactorInstance,
+        >,
+        threshold: u8,
+        override_factors: impl IntoIterator<
+            Item = HierarchicalDeterministicFactorInstance,
+        >,
+    ) -> Result<Self> {
+        let role = Self {
+            threshold_factors: threshold_factors.into_iter().collect(),
+            threshold,
+            override_factors: override_factors.into_iter().collect(),
+        };
+        role.validate()?;
+        Ok(role)
+    }
+
+    /// A role which is fulfilled by the signature of `factor` alone.
+    pub fn single_override(
+        factor: HierarchicalDeterministicFactorInstance,
+    ) -> Self {
+        Self {
+            threshold_factors: Vec::new(),
+            threshold: 0,
+            override_factors: vec![factor],
+        }
+    }
+
+    /// All factor instances of this role, threshold factors first.
+    pub fn all_factors(
+        &self,
+    ) -> impl Iterator<Item = &HierarchicalDeterministicFactorInstance> {
+        self.threshold_factors
+            .iter()
+            .chain(self.override_factors.iter())
+    }
+
+    /// The ids of the factor sources of all factor instances of this role,
+    /// without duplicates.
+    pub fn factor_source_ids(&self) -> Vec<FactorSourceIDFromHash> {
+        self.all_factors()
+            .map(|f| f.factor_source_id.clone())
+            .unique()
+            .collect()
+    }
+
+    /// Checks that this role can be fulfilled at all and that it does not
+    /// contain the same factor instance twice.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: &str| {
+            Err(CommonError::InvalidSecurityStructure(reason.to_owned()))
+        };
+        if self.threshold as usize > self.threshold_factors.len() {
+            return invalid("threshold exceeds number of threshold factors");
+        }
+        if !self.threshold_factors.is_empty() && self.threshold == 0 {
+            return invalid("threshold must not be zero");
+        }
+        if self.threshold_factors.is_empty() && self.override_factors.is_empty()
+        {
+            return invalid("role has no factors");
+        }
+        if !self.all_factors().all_unique() {
+            return invalid("role contains the same factor instance twice");
+        }
+        Ok(())
+    }
+}
+
+impl HasPlaceholder for RoleOfFactorInstances {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            [
+                HierarchicalDeterministicFactorInstance::placeholder_transaction_signing_0(),
+                HierarchicalDeterministicFactorInstance::placeholder_transaction_signing_1(),
+            ],
+            1,
+            [],
+        )
+        .expect("Valid placeholder.")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::single_override(
+            HierarchicalDeterministicFactorInstance::placeholder_auth_signing(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = RoleOfFactorInstances;
+
+    fn factor() -> HierarchicalDeterministicFactorInstance {
+        HierarchicalDeterministicFactorInstance::placeholder()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn threshold_exceeding_factors_is_invalid() {
+        assert!(matches!(
+            SUT::new([factor()], 2, []),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+
+    #[test]
+    fn zero_threshold_is_invalid() {
+        assert!(matches!(
+            SUT::new([factor()], 0, []),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+
+    #[test]
+    fn empty_role_is_invalid() {
+        assert!(matches!(
+            SUT::new([], 0, []),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+
+    #[test]
+    fn duplicate_factor_is_invalid() {
+        assert!(matches!(
+            SUT::new([factor()], 1, [factor()]),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+
+    #[test]
+    fn factor_source_ids_are_unique() {
+        assert_eq!(
+            SUT::placeholder().factor_source_ids(),
+            vec![factor().factor_source_id]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&SUT::placeholder());
+        assert_json_roundtrip(&SUT::placeholder_other());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::prelude::*;
+
+/// Security control of a securified entity, i.e. an entity whose owner badge
+/// is held by an `AccessController` with a potential Multi-Factor setup.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SecuredEntityControl {
+    /// The factor instance which was used to create this entity when it was
+    /// still unsecured, its public key is what the address of the entity was
+    /// derived from, kept so that the derivation index is not reused.
+    pub virtual_entity_creating_instance:
+        HierarchicalDeterministicFactorInstance,
+
+    /// The address of the `AccessController` controlling the entity.
+    pub access_controller_address: AccessControllerAddress,
+
+    /// The factor instances of the roles of the `AccessController`.
+    pub security_structure: SecurityStructureOfFactorInstances,
+}
+
+impl SecuredEntityControl {
+    pub fn new(
+        virtual_entity_creating_instance: HierarchicalDeterministicFactorInstance,
+        access_controller_address: AccessControllerAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Self {
+        Self {
+            virtual_entity_creating_instance,
+            access_controller_address,
+            security_structure,
+        }
+    }
+}
+
+impl HasPlaceholder for SecuredEntityControl {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            HierarchicalDeterministicFactorInstance::placeholder(),
+            AccessControllerAddress::placeholder(),
+            SecurityStructureOfFactorInstances::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            HierarchicalDeterministicFactorInstance::placeholder_other(),
+            AccessControllerAddress::placeholder_other(),
+            SecurityStructureOfFactorInstances::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SecuredEntityControl;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&SUT::placeholder());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::prelude::*;
+
+/// The factor instances of the primary, recovery and confirmation roles of an
+/// AccessController securifying an entity.
+#[derive(
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityStructureOfFactorInstances {
+    /// The role used for signing transactions of the entity.
+    pub primary_role: RoleOfFactorInstances,
+
+    /// The role used to initiate recovery, i.e. replacing the factors.
+    pub recovery_role: RoleOfFactorInstances,
+
+    /// The role used to confirm a recovery initiated by the recovery role.
+    pub confirmation_role: RoleOfFactorInstances,
+
+    /// The number of days after which a recovery initiated by the recovery
+    /// role is confirmed without the confirmation role.
+    pub number_of_days_until_auto_confirmation: u16,
+}
+
+impl SecurityStructureOfFactorInstances {
+    pub fn new(
+        primary_role: RoleOfFactorInstances,
+        recovery_role: RoleOfFactorInstances,
+        confirmation_role: RoleOfFactorInstances,
+        number_of_days_until_auto_confirmation: u16,
+    ) -> Result<Self> {
+        let structure = Self {
+            primary_role,
+            recovery_role,
+            confirmation_role,
+            number_of_days_until_auto_confirmation,
+        };
+        structure.validate()?;
+        Ok(structure)
+    }
+
+    /// Checks each role, and that the delay until auto confirmation is not
+    /// zero since that would let the recovery role alone take over the
+    /// entity immediately.
+    pub fn validate(&self) -> Result<()> {
+        self.primary_role.validate()?;
+        self.recovery_role.validate()?;
+        self.confirmation_role.validate()?;
+        if self.number_of_days_until_auto_confirmation == 0 {
+            return Err(CommonError::InvalidSecurityStructure(
+                "number of days until auto confirmation must not be zero"
+                    .to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// The delay until auto confirmation in the minutes the AccessController
+    /// uses.
+    pub fn timed_recovery_delay_in_minutes(&self) -> u32 {
+        self.number_of_days_until_auto_confirmation as u32 * 24 * 60
+    }
+}
+
+impl HasPlaceholder for SecurityStructureOfFactorInstances {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            RoleOfFactorInstances::placeholder(),
+            RoleOfFactorInstances::placeholder_other(),
+            RoleOfFactorInstances::placeholder_other(),
+            14,
+        )
+        .expect("Valid placeholder.")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            RoleOfFactorInstances::placeholder_other(),
+            RoleOfFactorInstances::placeholder(),
+            RoleOfFactorInstances::placeholder(),
+            7,
+        )
+        .expect("Valid placeholder.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SecurityStructureOfFactorInstances;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn zero_days_until_auto_confirmation_is_invalid() {
+        assert!(matches!(
+            SUT::new(
+                RoleOfFactorInstances::placeholder(),
+                RoleOfFactorInstances::placeholder_other(),
+                RoleOfFactorInstances::placeholder_other(),
+                0
+            ),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_role_is_invalid() {
+        let mut sut = SUT::placeholder();
+        sut.confirmation_role.threshold = 3;
+        assert!(matches!(
+            sut.validate(),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+
+    #[test]
+    fn timed_recovery_delay_in_minutes() {
+        assert_eq!(SUT::placeholder().timed_recovery_delay_in_minutes(), 20160);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&SUT::placeholder());
+    }
+}
@@ -69,6 +69,20 @@ impl Serialize for FactorInstanceBadgeVirtualSource {
     }
 }
 
+impl JsonSchema for FactorInstanceBadgeVirtualSource {
+    fn schema_name() -> String {
+        "FactorInstanceBadgeVirtualSource".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        discriminated_union_json_schema([(
+            "hierarchicalDeterministicPublicKey",
+            "hierarchicalDeterministicPublicKey",
+            gen.subschema_for::<HierarchicalDeterministicPublicKey>(),
+        )])
+    }
+}
+
 impl HasPlaceholder for FactorInstanceBadgeVirtualSource {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
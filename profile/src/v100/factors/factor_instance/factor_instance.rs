@@ -1,7 +1,15 @@
 use crate::prelude::*;
 
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
 )]
 pub struct FactorInstance {
     /// The ID of the `FactorSource` that was used to produce this
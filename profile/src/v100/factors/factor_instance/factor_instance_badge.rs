@@ -88,6 +88,20 @@ impl Serialize for FactorInstanceBadge {
     }
 }
 
+impl JsonSchema for FactorInstanceBadge {
+    fn schema_name() -> String {
+        "FactorInstanceBadge".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        discriminated_union_json_schema([(
+            "virtualSource",
+            "virtualSource",
+            gen.subschema_for::<FactorInstanceBadgeVirtualSource>(),
+        )])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
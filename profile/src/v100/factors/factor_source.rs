@@ -95,6 +95,27 @@ impl Serialize for FactorSource {
     }
 }
 
+impl JsonSchema for FactorSource {
+    fn schema_name() -> String {
+        "FactorSource".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        discriminated_union_json_schema([
+            (
+                "device",
+                "device",
+                gen.subschema_for::<DeviceFactorSource>(),
+            ),
+            (
+                "ledgerHQHardwareWallet",
+                "ledgerHQHardwareWallet",
+                gen.subschema_for::<LedgerHardwareWalletFactorSource>(),
+            ),
+        ])
+    }
+}
+
 impl HasPlaceholder for FactorSource {
     fn placeholder() -> Self {
         Self::placeholder_device()
@@ -13,7 +13,15 @@ impl Identifiable for FactorSourceFlag {
 /// Common properties shared between FactorSources of different kinds, describing
 /// its state, when added, and supported cryptographic parameters.
 #[derive(
-    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct FactorSourceCommon {
@@ -27,6 +35,7 @@ pub struct FactorSourceCommon {
     pub crypto_parameters: FactorSourceCryptoParameters,
 
     /// When this factor source for originally added by the user.
+    #[schemars(schema_with = "timestamp_json_schema")]
     pub added_on: Timestamp,
 
     /// Date of last usage of this factor source
@@ -34,6 +43,7 @@ pub struct FactorSourceCommon {
     /// This is the only mutable property, it is mutable
     /// since we will update it every time this FactorSource
     /// is used.
+    #[schemars(schema_with = "timestamp_json_schema")]
     pub last_used_on: Timestamp,
 
     /// Flags which describe a certain state a FactorSource might be in, e.g. `Main` (BDFS).
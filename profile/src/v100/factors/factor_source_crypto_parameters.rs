@@ -4,7 +4,15 @@ use crate::prelude::*;
 /// it supports and which Hierarchical Deterministic (HD) derivations schemes it supports,
 /// if any.
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct FactorSourceCryptoParameters {
@@ -86,6 +86,27 @@ impl Serialize for FactorSourceID {
     }
 }
 
+impl JsonSchema for FactorSourceID {
+    fn schema_name() -> String {
+        "FactorSourceID".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        discriminated_union_json_schema([
+            (
+                "fromHash",
+                "fromHash",
+                gen.subschema_for::<FactorSourceIDFromHash>(),
+            ),
+            (
+                "fromAddress",
+                "fromAddress",
+                gen.subschema_for::<FactorSourceIDFromAddress>(),
+            ),
+        ])
+    }
+}
+
 impl HasPlaceholder for FactorSourceID {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -6,6 +6,7 @@ use radix_engine_common::crypto::{blake2b_256_hash, Hash};
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     PartialEq,
     Eq,
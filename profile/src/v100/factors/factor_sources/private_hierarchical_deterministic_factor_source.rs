@@ -83,14 +83,44 @@ impl PrivateHierarchicalDeterministicFactorSource {
     where
         T: IsEntityPath + Clone,
     {
-        let path = T::new(network_id, CAP26KeyKind::TransactionSigning, index);
-        let hd_private_key =
-            self.mnemonic_with_passphrase.derive_private_key(path);
-        let hd_factor_instance = HierarchicalDeterministicFactorInstance::new(
-            self.factor_source.id.clone(),
-            hd_private_key.public_key(),
-        );
-        HDFactorInstanceTransactionSigning::new(hd_factor_instance).unwrap()
+        self.derive_entity_creation_factor_instances(network_id, [index])
+            .remove(0)
+    }
+
+    /// Derives the factor instances creating entities at each of `indices`,
+    /// computing the BIP39 seed only once.
+    pub fn derive_entity_creation_factor_instances<T, I>(
+        &self,
+        network_id: NetworkID,
+        indices: I,
+    ) -> Vec<HDFactorInstanceTransactionSigning<T>>
+    where
+        T: IsEntityPath + Clone,
+        I: IntoIterator<Item = HDPathValue>,
+    {
+        let seed = self.mnemonic_with_passphrase.to_seed();
+        indices
+            .into_iter()
+            .map(|index| {
+                let path =
+                    T::new(network_id, CAP26KeyKind::TransactionSigning, index);
+                let key = MnemonicWithPassphrase::derive_ed25519_private_key(
+                    &seed,
+                    path.hd_path(),
+                );
+                let hd_private_key = HierarchicalDeterministicPrivateKey::new(
+                    key.into(),
+                    path.derivation_path(),
+                );
+                let hd_factor_instance =
+                    HierarchicalDeterministicFactorInstance::new(
+                        self.factor_source.id.clone(),
+                        hd_private_key.public_key(),
+                    );
+                HDFactorInstanceTransactionSigning::new(hd_factor_instance)
+                    .unwrap()
+            })
+            .collect()
     }
 }
 
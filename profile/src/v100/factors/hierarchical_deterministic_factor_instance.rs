@@ -115,6 +115,21 @@ impl<'de> serde::Deserialize<'de> for HierarchicalDeterministicFactorInstance {
     }
 }
 
+/// Serialized as a [`FactorInstance`], thus has the same schema.
+impl JsonSchema for HierarchicalDeterministicFactorInstance {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        FactorInstance::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        gen.subschema_for::<FactorInstance>()
+    }
+}
+
 impl HasPlaceholder for HierarchicalDeterministicFactorInstance {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -8,6 +8,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Default,
     Clone,
     Debug,
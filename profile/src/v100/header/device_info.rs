@@ -5,6 +5,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Clone,
     Debug,
     PartialEq,
@@ -22,11 +23,13 @@ pub struct DeviceInfo {
     /// query iOS for a unique identifier of the device, thus
     /// the iOS team has made their own impl of a best effort
     /// stable identifier.
+    #[schemars(schema_with = "uuid_json_schema")]
     pub id: Uuid,
 
     /// The date this description of the device was made, might
     /// be equal to when the app was first ever launched on the
     /// device.
+    #[schemars(schema_with = "timestamp_json_schema")]
     pub date: Timestamp,
 
     /// A short description of the device, we devices should
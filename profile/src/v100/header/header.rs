@@ -7,6 +7,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Debug,
     Clone,
     PartialEq,
@@ -32,6 +33,7 @@ pub struct Header {
     pub last_used_on_device: DeviceInfo,
 
     /// When the Profile was last modified.
+    #[schemars(schema_with = "timestamp_json_schema")]
     pub last_modified: Timestamp,
 
     /// Hint about the contents of the profile, e.g. number of Accounts and Personas.
@@ -4,6 +4,7 @@ use crate::prelude::*;
 #[derive(
     Serialize,
     Deserialize,
+    JsonSchema,
     Debug,
     derive_more::Display,
     Clone,
@@ -12,7 +13,9 @@ use crate::prelude::*;
     Hash,
 )]
 #[serde(transparent)]
-pub struct ProfileID(pub(crate) Uuid);
+pub struct ProfileID(
+    #[schemars(schema_with = "uuid_json_schema")] pub(crate) Uuid,
+);
 uniffi::custom_newtype!(ProfileID, Uuid);
 
 impl FromStr for ProfileID {
@@ -2,7 +2,15 @@ use crate::prelude::*;
 
 /// A connection made between a Radix Dapp and the user.
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Hash, Eq, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Hash,
+    Eq,
+    uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedDapp {
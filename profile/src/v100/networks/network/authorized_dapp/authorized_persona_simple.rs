@@ -10,7 +10,15 @@ use crate::prelude::*;
 /// to migrate `Sargon` into iOS/Android clients, thus we will defer the work
 /// of mapping `AuthorizedPersonaSimple` -> `AuthorizedPersonaDetailed`.
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Hash, Eq, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    PartialEq,
+    Hash,
+    Eq,
+    uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedPersonaSimple {
@@ -19,6 +27,7 @@ pub struct AuthorizedPersonaSimple {
     pub identity_address: IdentityAddress,
 
     /// Date of last login for this persona.
+    #[schemars(schema_with = "timestamp_json_schema")]
     pub last_login: Timestamp,
 
     /// List of "ongoing accountAddresses" that user given the dApp access to.
@@ -2,7 +2,16 @@ use crate::prelude::*;
 
 /// Identities for PersonaData entry values a user have shared with a dApp.
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Hash, Eq, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Hash,
+    Eq,
+    uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct SharedPersonaData {
@@ -17,6 +26,27 @@ pub struct SharedPersonaData {
     /// IDs of a `PersonaDataEntryPhoneNumber`s the user has shared with some dApp on some network
     /// can be `None`, or can be `Some(<EMPTY>)`.
     pub phone_numbers: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// ID of a `PersonaDataEntryDateOfBirth` the user has shared with some dApp on some network,
+    /// can be `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<PersonaDataEntryID>,
+
+    /// ID of a `PersonaDataEntryCompanyName` the user has shared with some dApp on some network,
+    /// can be `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<PersonaDataEntryID>,
+
+    /// IDs of a `PersonaDataEntryPostalAddress`es the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_addresses:
+        Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// IDs of a `PersonaDataEntryUrl`s the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub urls: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
 }
 
 impl SharedPersonaData {
@@ -33,6 +63,7 @@ impl SharedPersonaData {
             name: name.into(),
             email_addresses: email_addresses.into(),
             phone_numbers: phone_numbers.into(),
+            ..Default::default()
         }
     }
 }
@@ -156,4 +187,36 @@ mod tests {
             "#,
         );
     }
+
+    #[test]
+    fn json_roundtrip_new_kinds() {
+        let model = SUT {
+            company_name: Some(PersonaDataEntryID::placeholder_one()),
+            urls: Some(SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                RequestedQuantity::at_least(1),
+                IdentifiedVecVia::from_iter([
+                    PersonaDataEntryID::placeholder_two(),
+                ]),
+            )),
+            ..Default::default()
+        };
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "name": null,
+                "emailAddresses": null,
+                "phoneNumbers": null,
+                "companyName": "00000000-0000-0000-0000-000000000001",
+                "urls": {
+                    "request": {
+                        "quantifier": "atLeast",
+                        "quantity": 1
+                    },
+                    "ids": ["00000000-0000-0000-0000-000000000002"]
+                }
+            }
+            "#,
+        );
+    }
 }
@@ -10,6 +10,7 @@ macro_rules! declare_shared_with_dapp {
         #[derive(
             Serialize,
             Deserialize,
+            JsonSchema,
             Clone,
             PartialEq,
             Hash,
@@ -4,6 +4,7 @@ use radix_engine_common::network::NetworkDefinition as NativeNetworkDefinition;
 #[derive(
     Serialize_repr,
     Deserialize_repr,
+    JsonSchema_repr,
     FromRepr,
     Clone,
     Copy,
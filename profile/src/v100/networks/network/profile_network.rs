@@ -4,7 +4,15 @@ use crate::prelude::*;
 /// which user has created/interacted with, all on the same [Radix Network][`NetworkDefinition`],
 /// identified by `id` ([`NetworkID`]).
 #[derive(
-    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
 )]
 pub struct ProfileNetwork {
     /// The ID of the network that has been used to generate the `accounts` and `personas`
@@ -78,6 +86,9 @@ impl ProfileNetwork {
 
 impl ProfileNetwork {
     /// Returns a clone of the updated account if found, else None.
+    ///
+    /// If the account got hidden by `mutate`, it is no longer shared with
+    /// any dApp, see [`ProfileNetwork::remove_references_to_account`].
     pub fn update_account<F>(
         &mut self,
         address: &AccountAddress,
@@ -86,11 +97,162 @@ impl ProfileNetwork {
     where
         F: FnMut(&mut Account),
     {
-        if self.accounts.update_with(address, mutate) {
-            self.accounts.get(address).cloned()
-        } else {
-            None
+        if !self.accounts.update_with(address, mutate) {
+            return None;
+        }
+        let account = self.accounts.get(address).cloned()?;
+        if account.flags.contains(&EntityFlag::DeletedByUser) {
+            self.remove_references_to_account(address);
+        }
+        Some(account)
+    }
+
+    /// Returns a clone of the updated persona if found, else None.
+    ///
+    /// If the persona got hidden by `mutate`, it is removed from all dApps,
+    /// see [`ProfileNetwork::remove_references_to_persona`].
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        if !self.personas.update_with(address, mutate) {
+            return None;
         }
+        let persona = self.personas.get(address).cloned()?;
+        if persona.flags.contains(&EntityFlag::DeletedByUser) {
+            self.remove_references_to_persona(address);
+        }
+        Some(persona)
+    }
+}
+
+impl ProfileNetwork {
+    /// Removes the account with `address` from the shared accounts of every
+    /// Persona of every [`AuthorizedDapp`] on this network, accounts which no
+    /// longer fulfill the request they were shared for are no longer shared,
+    /// like [`Profile::repair`] does.
+    pub fn remove_references_to_account(&mut self, address: &AccountAddress) {
+        self.authorized_dapps = self
+            .authorized_dapps
+            .iter()
+            .cloned()
+            .map(|mut dapp| {
+                dapp.references_to_authorized_personas = dapp
+                    .references_to_authorized_personas
+                    .iter()
+                    .cloned()
+                    .map(|mut persona| {
+                        if let Some(shared) = persona.shared_accounts.as_mut() {
+                            shared.ids = shared
+                                .ids
+                                .iter()
+                                .filter(|a| *a != address)
+                                .cloned()
+                                .collect();
+                            if !shared
+                                .request
+                                .is_fulfilled_by_ids(shared.ids.len())
+                            {
+                                persona.shared_accounts = None;
+                            }
+                        }
+                        persona
+                    })
+                    .collect();
+                dapp
+            })
+            .collect();
+    }
+
+    /// Removes the PersonaData entry of `kind` with `id` of the Persona with
+    /// `persona_address` from the [`SharedPersonaData`] of every
+    /// [`AuthorizedDapp`] on this network, entries which no longer fulfill
+    /// the request they were shared for are no longer shared. Shared entries
+    /// of other kinds are left untouched even if they have the same ID.
+    pub fn remove_references_to_persona_data_entry(
+        &mut self,
+        persona_address: &IdentityAddress,
+        kind: PersonaDataEntryKind,
+        id: &PersonaDataEntryID,
+    ) {
+        let remove_single = |shared: &mut Option<PersonaDataEntryID>| {
+            if shared.as_ref() == Some(id) {
+                *shared = None;
+            }
+        };
+        let remove_id = |shared: &mut Option<
+            SharedToDappWithPersonaIDsOfPersonaDataEntries,
+        >| {
+            if let Some(entries) = shared.as_mut() {
+                entries.ids =
+                    entries.ids.iter().filter(|i| *i != id).cloned().collect();
+                if !entries.request.is_fulfilled_by_ids(entries.ids.len()) {
+                    *shared = None;
+                }
+            }
+        };
+        self.authorized_dapps = self
+            .authorized_dapps
+            .iter()
+            .cloned()
+            .map(|mut dapp| {
+                _ = dapp.references_to_authorized_personas.update_with(
+                    persona_address,
+                    |persona| {
+                        let shared = &mut persona.shared_persona_data;
+                        match kind {
+                            PersonaDataEntryKind::Name => {
+                                remove_single(&mut shared.name)
+                            }
+                            PersonaDataEntryKind::DateOfBirth => {
+                                remove_single(&mut shared.date_of_birth)
+                            }
+                            PersonaDataEntryKind::CompanyName => {
+                                remove_single(&mut shared.company_name)
+                            }
+                            PersonaDataEntryKind::EmailAddress => {
+                                remove_id(&mut shared.email_addresses)
+                            }
+                            PersonaDataEntryKind::PhoneNumber => {
+                                remove_id(&mut shared.phone_numbers)
+                            }
+                            PersonaDataEntryKind::PostalAddress => {
+                                remove_id(&mut shared.postal_addresses)
+                            }
+                            PersonaDataEntryKind::Url => {
+                                remove_id(&mut shared.urls)
+                            }
+                        }
+                    },
+                );
+                dapp
+            })
+            .collect();
+    }
+
+    /// Removes the Persona with `address` - and thus all PersonaData entries
+    /// shared using it - from every [`AuthorizedDapp`] on this network, dApps
+    /// no longer referencing any Persona are forgotten.
+    pub fn remove_references_to_persona(&mut self, address: &IdentityAddress) {
+        self.authorized_dapps = self
+            .authorized_dapps
+            .iter()
+            .cloned()
+            .filter_map(|mut dapp| {
+                _ = dapp
+                    .references_to_authorized_personas
+                    .remove_by_id(address);
+                if dapp.references_to_authorized_personas.is_empty() {
+                    None
+                } else {
+                    Some(dapp)
+                }
+            })
+            .collect();
     }
 }
 
@@ -145,6 +307,197 @@ mod tests {
         assert_eq!(ProfileNetwork::placeholder().id(), NetworkID::Mainnet);
     }
 
+    #[test]
+    fn hiding_account_removes_it_from_shared_accounts() {
+        let mut sut = ProfileNetwork::placeholder_stokenet();
+        let address = AccountAddress::placeholder_stokenet();
+        let is_shared = |n: &ProfileNetwork| {
+            n.authorized_dapps.iter().any(|d| {
+                d.references_to_authorized_personas.iter().any(|p| {
+                    p.shared_accounts
+                        .as_ref()
+                        .map(|s| s.ids.contains_id(&address))
+                        .unwrap_or(false)
+                })
+            })
+        };
+        assert!(is_shared(&sut));
+        sut.update_account(&address, |a| {
+            a.flags.insert_flag(EntityFlag::DeletedByUser);
+        })
+        .unwrap();
+        assert!(!is_shared(&sut));
+        assert_eq!(sut.authorized_dapps.len(), 2);
+    }
+
+    #[test]
+    fn hiding_account_stops_sharing_accounts_no_longer_fulfilling_request() {
+        let mut sut = ProfileNetwork::placeholder_stokenet();
+        let shared_accounts = |n: &ProfileNetwork| {
+            n.authorized_dapps
+                .iter()
+                .flat_map(|d| {
+                    d.references_to_authorized_personas.iter().map(|p| {
+                        (
+                            d.dapp_definition_address.clone(),
+                            p.identity_address.clone(),
+                            p.shared_accounts.clone(),
+                        )
+                    })
+                })
+                .collect_vec()
+        };
+        let exactly_two =
+            SharedToDappWithPersonaAccountAddresses::placeholder_stokenet();
+        let before = shared_accounts(&sut);
+        assert!(before
+            .iter()
+            .any(|(_, _, s)| s == &Some(exactly_two.clone())));
+
+        sut.update_account(&AccountAddress::placeholder_stokenet(), |a| {
+            a.flags.insert_flag(EntityFlag::DeletedByUser);
+        })
+        .unwrap();
+
+        for ((dapp, persona, shared_before), (_, _, shared_after)) in
+            before.into_iter().zip(shared_accounts(&sut))
+        {
+            if shared_before == Some(exactly_two.clone()) {
+                assert_eq!(shared_after, None, "{} {}", dapp, persona);
+            } else if let Some(shared_after) = shared_after {
+                assert!(shared_after
+                    .request
+                    .is_fulfilled_by_ids(shared_after.ids.len()));
+            }
+        }
+    }
+
+    #[test]
+    fn renaming_account_keeps_shared_accounts() {
+        let mut sut = ProfileNetwork::placeholder_stokenet();
+        sut.update_account(&AccountAddress::placeholder_stokenet(), |a| {
+            a.display_name = DisplayName::new("Renamed").unwrap();
+        })
+        .unwrap();
+        assert_eq!(
+            sut.authorized_dapps,
+            ProfileNetwork::placeholder_stokenet().authorized_dapps
+        );
+    }
+
+    #[test]
+    fn hiding_persona_removes_it_from_dapps() {
+        let mut sut = ProfileNetwork::placeholder_stokenet();
+        let hermione = IdentityAddress::placeholder_stokenet_other();
+        sut.update_persona(&hermione, |p| {
+            p.flags.insert_flag(EntityFlag::DeletedByUser);
+        })
+        .unwrap();
+        // Sandbox was only used with Hermione and is thus forgotten.
+        assert_eq!(
+            sut.authorized_dapps.ids(),
+            [AuthorizedDapp::placeholder_stokenet_devconsole()
+                .dapp_definition_address]
+        );
+        assert!(!sut.authorized_dapps[0]
+            .references_to_authorized_personas
+            .contains_id(&hermione));
+    }
+
+    #[test]
+    fn remove_references_to_persona_data_entry() {
+        let mut sut = ProfileNetwork::placeholder_mainnet();
+        let satoshi = IdentityAddress::placeholder_mainnet();
+        let batman = IdentityAddress::placeholder_mainnet_other();
+        let id = PersonaDataEntryID::placeholder_four();
+        let shared_of = |n: &ProfileNetwork, p: &IdentityAddress| {
+            n.authorized_dapps[0]
+                .references_to_authorized_personas
+                .get(p)
+                .unwrap()
+                .shared_persona_data
+                .clone()
+        };
+        assert!(shared_of(&sut, &satoshi)
+            .phone_numbers
+            .unwrap()
+            .ids
+            .contains_id(&id));
+
+        sut.remove_references_to_persona_data_entry(
+            &satoshi,
+            PersonaDataEntryKind::PhoneNumber,
+            &id,
+        );
+
+        assert!(!shared_of(&sut, &satoshi)
+            .phone_numbers
+            .unwrap()
+            .ids
+            .contains_id(&id));
+        // Other Personas are not affected
+        assert_eq!(
+            shared_of(&sut, &batman),
+            shared_of(&ProfileNetwork::placeholder_mainnet(), &batman)
+        );
+    }
+
+    #[test]
+    fn remove_references_to_persona_data_entry_no_longer_fulfilling_request() {
+        let mut sut = ProfileNetwork::placeholder_mainnet();
+        let satoshi = IdentityAddress::placeholder_mainnet();
+        let id = PersonaDataEntryID::placeholder_one();
+        let dapp = sut.authorized_dapps[0].dapp_definition_address.clone();
+        sut.authorized_dapps.update_with(&dapp, |d| {
+            d.references_to_authorized_personas
+                .update_with(&satoshi, |p| {
+                    p.shared_persona_data.phone_numbers = Some(
+                        SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                            RequestedQuantity::exactly(1),
+                            IdentifiedVecVia::from_iter([id.clone()]),
+                        ),
+                    );
+                });
+        });
+
+        sut.remove_references_to_persona_data_entry(
+            &satoshi,
+            PersonaDataEntryKind::PhoneNumber,
+            &id,
+        );
+
+        let shared = sut.authorized_dapps[0]
+            .references_to_authorized_personas
+            .get(&satoshi)
+            .unwrap()
+            .shared_persona_data
+            .clone();
+        assert_eq!(shared.phone_numbers, None);
+    }
+
+    #[test]
+    fn remove_references_to_persona_data_entry_of_other_kind_is_noop() {
+        let mut sut = ProfileNetwork::placeholder_mainnet();
+        let satoshi = IdentityAddress::placeholder_mainnet();
+        let id = PersonaDataEntryID::placeholder_four();
+
+        sut.remove_references_to_persona_data_entry(
+            &satoshi,
+            PersonaDataEntryKind::EmailAddress,
+            &id,
+        );
+
+        assert_eq!(sut, ProfileNetwork::placeholder_mainnet());
+    }
+
+    #[test]
+    fn update_unknown_persona() {
+        let mut sut = ProfileNetwork::placeholder_stokenet();
+        assert!(sut
+            .update_persona(&IdentityAddress::placeholder_mainnet(), |_| {})
+            .is_none());
+    }
+
     #[test]
     fn get_accounts() {
         let sut = ProfileNetwork::placeholder();
@@ -30,6 +30,12 @@ impl ProfileNetworks {
             .cloned()
     }
 
+    pub fn get_persona(&self, address: &IdentityAddress) -> Option<Persona> {
+        self.get(&address.network_id)
+            .and_then(|n| n.personas.get_persona_by_address(address))
+            .cloned()
+    }
+
     /// Returns a clone of the updated account if found, else None.
     pub fn update_account<F>(
         &mut self,
@@ -44,6 +50,21 @@ impl ProfileNetworks {
         });
         self.get_account(address)
     }
+
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mut mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        self.update_with(&address.network_id, |n| {
+            _ = n.update_persona(address, |p| mutate(p))
+        });
+        self.get_persona(address)
+    }
 }
 
 impl ProfileNetworks {
@@ -927,4 +948,18 @@ mod tests {
         	"#,
         );
     }
+
+    #[test]
+    fn get_persona() {
+        let sut = ProfileNetworks::placeholder();
+        assert_eq!(
+            sut.get_persona(&IdentityAddress::placeholder_stokenet()),
+            Some(Persona::placeholder_stokenet_leia_skywalker())
+        );
+        assert_eq!(
+            ProfileNetworks::placeholder_other()
+                .get_persona(&IdentityAddress::placeholder_mainnet()),
+            None
+        );
+    }
 }
@@ -4,7 +4,15 @@ use crate::prelude::*;
 /// the user has used. It also contains all FactorSources,
 /// FactorInstances and wallet App preferences.
 #[derive(
-    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, uniffi::Record,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct Profile {
@@ -101,6 +109,18 @@ impl Profile {
         self.networks.update_account(address, mutate)
     }
 
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        self.networks.update_persona(address, mutate)
+    }
+
     pub fn update_factor_source<S, M>(
         &mut self,
         factor_source_id: &FactorSourceID,
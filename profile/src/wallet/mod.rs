@@ -1,9 +1,25 @@
 mod secure_storage;
 mod wallet;
 mod wallet_accounts;
+mod wallet_dapp_interactions;
+mod wallet_entity_queries;
+mod wallet_factor_sources;
+mod wallet_mnemonic_recovery;
+mod wallet_personas;
+mod wallet_profile_change_listener;
 mod wallet_profile_io;
+mod wallet_profile_persistence;
+mod wallet_transactions;
 
 pub use secure_storage::*;
 pub use wallet::*;
 pub use wallet_accounts::*;
+pub use wallet_dapp_interactions::*;
+pub use wallet_entity_queries::*;
+pub use wallet_factor_sources::*;
+pub use wallet_mnemonic_recovery::*;
+pub use wallet_personas::*;
+pub use wallet_profile_change_listener::*;
 pub use wallet_profile_io::*;
+pub use wallet_profile_persistence::*;
+pub use wallet_transactions::*;
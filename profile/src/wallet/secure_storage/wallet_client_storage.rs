@@ -131,6 +131,23 @@ impl WalletClientStorage {
         )
     }
 
+    /// Checks if a MnemonicWithPassphrase with a `FactorSourceIDFromHash` is
+    /// saved, without deserializing it.
+    ///
+    /// `SecureStorage` has no way to check for a key without reading its
+    /// data, so this loads the mnemonic, which might make the Wallet Client
+    /// prompt the user, e.g. for biometrics.
+    pub fn contains_mnemonic(
+        &self,
+        id: &FactorSourceIDFromHash,
+    ) -> Result<bool> {
+        self.interface
+            .load_data(SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: id.clone(),
+            })
+            .map(|o| o.is_some())
+    }
+
     /// Deletes a MnemonicWithPassphrase with a `FactorSourceIDFromHash`
     pub fn delete_mnemonic(&self, id: &FactorSourceIDFromHash) -> Result<()> {
         self.interface.delete_data_for_key(
@@ -235,6 +252,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contains_mnemonic() {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder_other();
+        let id = private.factor_source.id.clone();
+        let sut = make_sut();
+        assert_eq!(sut.contains_mnemonic(&id), Ok(false));
+        sut.save_mnemonic_with_passphrase(
+            &private.mnemonic_with_passphrase,
+            &id,
+        )
+        .unwrap();
+        assert_eq!(sut.contains_mnemonic(&id), Ok(true));
+    }
+
     #[test]
     fn save_mnemonic_with_passphrase() {
         let private =
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use std::sync::{Once, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{Once, PoisonError, RwLock};
 
 pub type HeadersList = IdentifiedVecVia<Header>;
 
@@ -7,7 +7,11 @@ pub type HeadersList = IdentifiedVecVia<Header>;
 pub struct Wallet {
     // This is pub(crate) for testing purposes only, i.e. causing the RwLock to be poisoned.
     pub(crate) profile: RwLock<Profile>,
+    /// Rebuilt whenever `profile` is updated, while holding its write lock.
+    pub(crate) index: RwLock<ProfileIndex>,
     pub(crate) wallet_client_storage: WalletClientStorage,
+    pub(crate) profile_change_listeners: ProfileChangeListeners,
+    pub(crate) persistence: ProfilePersistence,
 }
 
 impl Wallet {
@@ -25,20 +29,23 @@ impl Wallet {
     fn with_imported_profile(
         profile: Profile,
         secure_storage: Arc<dyn SecureStorage>,
-    ) -> Self {
+    ) -> Result<Self> {
         // Init WalletClient's storage
         let wallet_client_storage = WalletClientStorage::new(secure_storage);
 
         // Init wallet
         let wallet = Self {
+            index: RwLock::new(profile.index()),
             profile: RwLock::new(profile.clone()),
             wallet_client_storage,
+            profile_change_listeners: ProfileChangeListeners::default(),
+            persistence: ProfilePersistence::default(),
         };
 
         // Save new profile (also sets activeProfileID)
-        wallet.save_new_profile_or_panic(&profile);
+        wallet.save_new_profile(&profile)?;
 
-        wallet
+        Ok(wallet)
     }
 
     fn new_load_profile_with_id(
@@ -58,12 +65,15 @@ impl Wallet {
 
         // Create wallet
         let wallet = Self {
+            index: RwLock::new(profile.index()),
             profile: RwLock::new(profile),
             wallet_client_storage,
+            profile_change_listeners: ProfileChangeListeners::default(),
+            persistence: ProfilePersistence::default(),
         };
 
         // Set active profile ID
-        wallet.save_active_profile_id_or_panic(&profile_id);
+        wallet.save_active_profile_id(&profile_id)?;
 
         Ok(wallet)
     }
@@ -99,7 +109,7 @@ impl Wallet {
             private_hd_factor_source.clone(),
             wallet_client_name.as_str(),
         );
-        let wallet = Self::with_imported_profile(profile, secure_storage);
+        let wallet = Self::with_imported_profile(profile, secure_storage)?;
         wallet.wallet_client_storage.save(
             SecureStorageKey::DeviceFactorSourceMnemonic {
                 factor_source_id: private_hd_factor_source
@@ -112,12 +122,15 @@ impl Wallet {
         Ok(wallet)
     }
 
-    /// Creates wallet by *importing* a Profile.
+    /// Creates wallet by *importing* a Profile, which is first validated,
+    /// see [`Profile::validate`], and then rejected, repaired or accepted as
+    /// is according to `policy`.
     #[uniffi::constructor]
     pub fn by_importing_profile(
         profile: Profile,
         secure_storage: Arc<dyn SecureStorage>,
-    ) -> Self {
+        policy: ProfileImportPolicy,
+    ) -> Result<Self> {
         Wallet::init_logging();
 
         log::info!(
@@ -125,6 +138,7 @@ impl Wallet {
             profile.id()
         );
 
+        let profile = profile.validated_for_import(policy)?;
         Self::with_imported_profile(profile, secure_storage)
     }
 
@@ -176,7 +190,12 @@ impl Wallet {
     ) -> (Self, Arc<EphemeralSecureStorage>) {
         let storage = EphemeralSecureStorage::new();
         (
-            Self::by_importing_profile(profile, storage.clone()),
+            Self::by_importing_profile(
+                profile,
+                storage.clone(),
+                ProfileImportPolicy::Accept,
+            )
+            .unwrap(),
             storage,
         )
     }
@@ -198,55 +217,82 @@ impl HasPlaceholder for Wallet {
 #[uniffi::export]
 impl Wallet {
     /// Takes a snapshot of the profile and serialize it as a String of JSON.
-    pub fn json_snapshot(&self) -> String {
-        serde_json::to_string(&self.profile())
-            .expect("Should always be able to JSON serialize a Profile.")
+    pub fn json_snapshot(&self) -> Result<String> {
+        serde_json::to_string(&self.profile()?)
+            .map_err(|_| CommonError::FailedToSerializeToJSON)
     }
 
     /// Clone the profile and return it.
-    pub fn profile(&self) -> Profile {
+    pub fn profile(&self) -> Result<Profile> {
         self.access_profile_with(|p| p.clone())
     }
 }
 
 impl Wallet {
-    pub(crate) fn access_profile_with<T: Clone, F>(&self, access: F) -> T
+    /// Reads Profile, blocking while it is being updated. Any number of
+    /// threads can read Profile concurrently. Returns `Err` if the lock
+    /// has been poisoned by a thread panicking while holding it.
+    pub(crate) fn access_profile_with<T, F>(&self, access: F) -> Result<T>
     where
-        F: Fn(RwLockReadGuard<'_, Profile>) -> T,
+        F: FnOnce(&Profile) -> T,
     {
         self.profile
-            .try_read()
-            .map(access)
-            .expect("Implementing Wallet clients should not read and write Profile from Wallet from multiple threads.")
+            .read()
+            .map(|p| access(&p))
+            .map_err(|_| CommonError::UnableToAcquireReadLockForProfile)
     }
 
-    pub(crate) fn update_profile_with<F, R>(&self, mutate: F) -> R
+    /// Applies `mutate` to a copy of Profile, see
+    /// [`Wallet::try_update_profile_with`].
+    pub(crate) fn update_profile_with<F, R>(&self, mutate: F) -> Result<R>
     where
-        F: Fn(RwLockWriteGuard<'_, Profile>) -> R,
+        F: FnOnce(&mut Profile) -> R,
     {
-        let value = self.profile
-            .try_write()
-            .map(mutate)
-            .expect("Implementing Wallet clients should not read and write Profile from Wallet from multiple threads.");
-
-        self.save_existing_profile()
-            .expect("Failed to save Profile to secure storage.");
-
-        value
+        self.try_update_profile_with(|p| Ok(mutate(p)))
     }
 
+    /// Applies `mutate` to a copy of Profile and saves the copy to secure
+    /// storage, only if both succeed the copy replaces the Profile in
+    /// memory, so that the Profile in memory never diverges from the one
+    /// saved. If the persistence policy defers saving, see
+    /// [`ProfilePersistencePolicy`], the copy replaces the Profile in memory
+    /// right away and Profile is marked as having unsaved changes.
+    ///
+    /// Updates are serialized, blocking until any ongoing read or update is
+    /// done, and the write lock is held throughout, so no other update can
+    /// be lost in between. Returns `Err` if the lock has been poisoned by a
+    /// thread panicking while holding it. Listeners are notified after the
+    /// lock is released, so they can read the Wallet.
     #[cfg(not(tarpaulin_include))] // false negative
     pub(crate) fn try_update_profile_with<F, R>(&self, mutate: F) -> Result<R>
     where
-        F: Fn(RwLockWriteGuard<'_, Profile>) -> Result<R>,
+        F: FnOnce(&mut Profile) -> Result<R>,
     {
-        let res = self
+        let mut guard = self
             .profile
-            .try_write()
-            .map_err(|_| CommonError::UnableToAcquireWriteLockForProfile)
-            .and_then(mutate)?;
-
-        self.save_existing_profile()?;
+            .write()
+            .map_err(|_| CommonError::UnableToAcquireWriteLockForProfile)?;
+
+        let mut updated = guard.clone();
+        let res = mutate(&mut updated)?;
+        if self.persistence.should_save_now() {
+            self.save_profile(&updated)?;
+            self.persistence.mark_saved();
+        } else {
+            self.persistence.mark_dirty();
+        }
+
+        let events = guard.change_events(&updated);
+        // Rebuilding the whole index makes every update O(n) in the number
+        // of entities, as is cloning Profile above, measured by
+        // `benches/profile_index.rs`.
+        let index = updated.index();
+        *guard = updated;
+        // The index is replaced as a whole, so a poisoned lock is harmless.
+        *self.index.write().unwrap_or_else(PoisonError::into_inner) = index;
+        drop(guard);
+
+        self.profile_change_listeners.notify(events);
 
         Ok(res)
     }
@@ -260,15 +306,145 @@ mod tests {
     #[test]
     fn read_header() {
         let wallet = Wallet::placeholder();
-        wallet.access_profile_with(|p| {
-            assert_eq!(p.header, Profile::placeholder().header)
-        })
+        wallet
+            .access_profile_with(|p| {
+                assert_eq!(p.header, Profile::placeholder().header)
+            })
+            .unwrap()
     }
 
     #[test]
     fn take_snapshot() {
         let wallet = Wallet::placeholder();
-        assert_eq!(wallet.profile(), Profile::placeholder())
+        assert_eq!(wallet.profile().unwrap(), Profile::placeholder())
+    }
+
+    #[test]
+    fn poisoned_lock_is_an_error() {
+        let wallet = Arc::new(Wallet::placeholder());
+        let poisoner = wallet.clone();
+        _ = std::thread::spawn(move || {
+            let _guard = poisoner.profile.write().unwrap();
+            panic!("Poisoning the lock");
+        })
+        .join();
+
+        assert_eq!(
+            wallet.profile(),
+            Err(CommonError::UnableToAcquireReadLockForProfile)
+        );
+        assert_eq!(
+            wallet.update_profile_with(|_| ()),
+            Err(CommonError::UnableToAcquireWriteLockForProfile)
+        );
+    }
+
+    #[test]
+    fn concurrent_reads_and_writes() {
+        const THREADS: usize = 8;
+        const ITERATIONS: usize = 25;
+
+        let wallet = Arc::new(Wallet::placeholder());
+        let address = wallet.profile().unwrap().networks[0].accounts[0]
+            .address
+            .clone();
+
+        let writers = (0..THREADS).map(|t| {
+            let wallet = wallet.clone();
+            let address = address.clone();
+            std::thread::spawn(move || {
+                for i in 0..ITERATIONS {
+                    let name =
+                        DisplayName::new(&format!("T{}-{}", t, i)).unwrap();
+                    wallet
+                        .change_name_of_account(address.clone(), name)
+                        .unwrap();
+                    wallet
+                        .update_profile_with(|p| {
+                            p.header.content_hint.number_of_networks += 1
+                        })
+                        .unwrap();
+                }
+            })
+        });
+        let readers = (0..THREADS).map(|_| {
+            let wallet = wallet.clone();
+            let address = address.clone();
+            std::thread::spawn(move || {
+                for _ in 0..ITERATIONS {
+                    let profile = wallet.profile().unwrap();
+                    assert!(profile.networks.get_account(&address).is_some());
+                    assert!(wallet.json_snapshot().is_ok());
+                }
+            })
+        });
+        for handle in writers.chain(readers).collect_vec() {
+            handle.join().unwrap();
+        }
+
+        // No update was lost.
+        assert_eq!(
+            wallet
+                .profile()
+                .unwrap()
+                .header
+                .content_hint
+                .number_of_networks as usize,
+            Profile::placeholder()
+                .header
+                .content_hint
+                .number_of_networks as usize
+                + THREADS * ITERATIONS
+        );
+    }
+
+    #[test]
+    fn concurrent_writes_are_notified_once_each() {
+        #[derive(Debug, Default)]
+        struct CountingListener {
+            count: std::sync::atomic::AtomicUsize,
+        }
+        impl ProfileChangeListener for CountingListener {
+            fn on_profile_changed(&self, events: Vec<ProfileChangeEvent>) {
+                self.count.fetch_add(
+                    events.len(),
+                    std::sync::atomic::Ordering::SeqCst,
+                );
+            }
+        }
+
+        const THREADS: usize = 8;
+        const ITERATIONS: usize = 25;
+
+        let wallet = Arc::new(Wallet::placeholder());
+        let listener = Arc::new(CountingListener::default());
+        wallet.subscribe_to_profile_changes(listener.clone());
+        let address = wallet.profile().unwrap().networks[0].accounts[0]
+            .address
+            .clone();
+
+        (0..THREADS)
+            .map(|t| {
+                let wallet = wallet.clone();
+                let address = address.clone();
+                std::thread::spawn(move || {
+                    for i in 0..ITERATIONS {
+                        let name =
+                            DisplayName::new(&format!("T{}-{}", t, i)).unwrap();
+                        wallet
+                            .change_name_of_account(address.clone(), name)
+                            .unwrap();
+                    }
+                })
+            })
+            .collect_vec()
+            .into_iter()
+            .for_each(|h| h.join().unwrap());
+
+        assert_eq!(
+            listener.count.load(std::sync::atomic::Ordering::SeqCst),
+            THREADS * ITERATIONS
+        );
     }
 }
 
@@ -298,7 +474,7 @@ mod uniffi_tests {
             secure_storage.clone(),
         )
         .unwrap();
-        assert_eq!(wallet.profile(), profile);
+        assert_eq!(wallet.profile().unwrap(), profile);
 
         // Assert an ActiveProfileID has been saved.
         assert_eq!(
@@ -328,17 +504,47 @@ mod uniffi_tests {
             )
             .is_ok());
         let wallet = Wallet::by_loading_profile(secure_storage).unwrap();
-        assert_eq!(wallet.profile(), profile);
+        assert_eq!(wallet.profile().unwrap(), profile);
     }
 
     #[test]
     fn snapshot_json() {
         let profile = Profile::placeholder();
         let secure_storage = EphemeralSecureStorage::new();
-        let wallet =
-            Wallet::by_importing_profile(profile.clone(), secure_storage);
+        let wallet = Wallet::by_importing_profile(
+            profile.clone(),
+            secure_storage,
+            ProfileImportPolicy::Accept,
+        )
+        .unwrap();
         let expected_json = serde_json::to_string(&profile).unwrap();
-        assert_eq!(wallet.json_snapshot(), expected_json);
+        assert_eq!(wallet.json_snapshot(), Ok(expected_json));
+    }
+
+    #[test]
+    fn by_importing_invalid_profile_is_rejected() {
+        let mut profile = Profile::placeholder();
+        profile.factor_sources =
+            FactorSources::from_iter([FactorSource::placeholder_ledger()]);
+        let secure_storage = EphemeralSecureStorage::new();
+        let result = Wallet::by_importing_profile(
+            profile.clone(),
+            secure_storage.clone(),
+            ProfileImportPolicy::Repair,
+        );
+        assert!(matches!(
+            result,
+            Err(CommonError::ProfileFailedValidation(_))
+        ));
+        assert!(secure_storage.storage.read().unwrap().is_empty());
+
+        let wallet = Wallet::by_importing_profile(
+            profile.clone(),
+            secure_storage,
+            ProfileImportPolicy::Accept,
+        )
+        .unwrap();
+        assert_eq!(wallet.profile().unwrap(), profile);
     }
 
     #[test]
@@ -353,7 +559,7 @@ mod uniffi_tests {
         .unwrap();
         let mnemonic_json = secure_storage
             .load_data(SecureStorageKey::DeviceFactorSourceMnemonic {
-                factor_source_id: wallet.profile().bdfs().id,
+                factor_source_id: wallet.profile().unwrap().bdfs().id,
             })
             .unwrap()
             .unwrap();
@@ -369,6 +575,6 @@ mod uniffi_tests {
 
         let active_id =
             serde_json::from_slice::<ProfileID>(&active_id_data).unwrap();
-        assert_eq!(active_id, wallet.profile().id());
+        assert_eq!(active_id, wallet.profile().unwrap().id());
     }
 }
@@ -48,10 +48,9 @@ impl Wallet {
     /// Returns `Err` if `factor_source` is already present in factor source,
     /// or if saving to SecureStorage fails.
     ///
-    /// If only saving to SecureStorage fails, the Profile still remains
-    /// edited.
+    /// If saving to SecureStorage fails, the Profile is left unchanged.
     pub fn add_factor_source(&self, factor_source: FactorSource) -> Result<()> {
-        self.try_update_profile_with(|mut p| {
+        self.try_update_profile_with(|p| {
             trace!(
                 "About to add FactorSource: {}, to list of factor sources: {}",
                 &factor_source,
@@ -114,7 +113,7 @@ impl Wallet {
         id: &FactorSourceIDFromHash,
     ) -> Result<PrivateHierarchicalDeterministicFactorSource> {
         let device_factor_source =
-            self.profile().device_factor_source_by_id(id)?;
+            self.profile()?.device_factor_source_by_id(id)?;
         self.load_private_device_factor_source(&device_factor_source)
     }
 }
@@ -133,7 +132,7 @@ impl Wallet {
         network_id: NetworkID,
         name: DisplayName,
     ) -> Result<Account> {
-        let profile = &self.profile();
+        let profile = &self.profile()?;
         let bdfs = profile.bdfs();
         let index = profile
             .next_derivation_index_for_entity(EntityKind::Accounts, network_id);
@@ -164,7 +163,7 @@ impl Wallet {
         let network_id = account.network_id;
         let err_exists =
             CommonError::AccountAlreadyPresent(account.id().clone());
-        self.try_update_profile_with(|mut p| {
+        self.try_update_profile_with(|p| {
             let networks = &mut p.networks;
             if networks.contains_id(&network_id) {
                 networks
@@ -204,9 +203,9 @@ impl Wallet {
 
     /// Updates `account` as a whole, if it exists, else an error is thrown.
     pub fn update_account(&self, to: Account) -> Result<Account> {
-        self.update_profile_with(|mut p| {
+        self.update_profile_with(|p| {
             p.update_account(&to.address, |a| *a = to.to_owned())
-        })
+        })?
         .ok_or(CommonError::UnknownAccount)
     }
 
@@ -216,9 +215,56 @@ impl Wallet {
         address: AccountAddress,
         to: DisplayName,
     ) -> Result<Account> {
-        self.update_profile_with(|mut p| {
+        self.update_profile_with(|p| {
             p.update_account(&address, |a| a.display_name = to.to_owned())
-        })
+        })?
+        .ok_or(CommonError::UnknownAccount)
+    }
+
+    /// Hides the account with the provided address, it will no longer be
+    /// shared with any dApp. Throws an error if the account is unknown to
+    /// the wallet.
+    pub fn hide_account(&self, address: AccountAddress) -> Result<Account> {
+        self.update_profile_with(|p| {
+            p.update_account(&address, |a| {
+                a.flags.insert_flag(EntityFlag::DeletedByUser);
+            })
+        })?
+        .ok_or(CommonError::UnknownAccount)
+    }
+
+    /// Creates the manifest updating the third-party deposit settings of the
+    /// account with the provided address on-ledger, from its current
+    /// settings in Profile to `to`. Once the user has confirmed and submitted
+    /// the transaction, `update_third_party_deposits_of_account` should be
+    /// called. Throws an error if the account is unknown to the wallet.
+    pub fn manifest_for_third_party_deposits_update(
+        &self,
+        address: AccountAddress,
+        to: ThirdPartyDeposits,
+    ) -> Result<TransactionManifest> {
+        let from = self
+            .access_profile_with(|p| p.networks.get_account(&address))?
+            .ok_or(CommonError::UnknownAccount)?
+            .on_ledger_settings
+            .third_party_deposits;
+        TransactionManifest::third_party_deposit_update(&address, &from, &to)
+    }
+
+    /// Saves `third_party_deposits` to the `OnLedgerSettings` of the account
+    /// with the provided address, throws an error if the account is unknown
+    /// to the wallet.
+    pub fn update_third_party_deposits_of_account(
+        &self,
+        address: AccountAddress,
+        third_party_deposits: ThirdPartyDeposits,
+    ) -> Result<Account> {
+        self.update_profile_with(|p| {
+            p.update_account(&address, |a| {
+                a.on_ledger_settings.third_party_deposits =
+                    third_party_deposits.to_owned()
+            })
+        })?
         .ok_or(CommonError::UnknownAccount)
     }
 }
@@ -240,8 +286,9 @@ mod tests {
     fn change_display_name_of_accounts() {
         let profile = Profile::placeholder();
         let (wallet, _) = Wallet::ephemeral(profile.clone());
-        let account =
-            wallet.access_profile_with(|p| p.networks[0].accounts[0].clone());
+        let account = wallet
+            .access_profile_with(|p| p.networks[0].accounts[0].clone())
+            .unwrap();
         assert_eq!(account.display_name.value, "Alice");
         assert!(wallet
             .change_name_of_account(
@@ -249,9 +296,14 @@ mod tests {
                 DisplayName::new("Stella").unwrap()
             )
             .is_ok());
-        wallet.access_profile_with(|p| {
-            assert_eq!(p.networks[0].accounts[0].display_name.value, "Stella")
-        });
+        wallet
+            .access_profile_with(|p| {
+                assert_eq!(
+                    p.networks[0].accounts[0].display_name.value,
+                    "Stella"
+                )
+            })
+            .unwrap();
 
         assert_eq!(
             wallet.change_name_of_account(
@@ -262,12 +314,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hide_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = Account::placeholder_stokenet_carol().address;
+        let hidden = wallet.hide_account(address.clone()).unwrap();
+        assert!(hidden.flags.contains(&EntityFlag::DeletedByUser));
+        let profile = wallet.profile().unwrap();
+        let network = profile.networks.get(&NetworkID::Stokenet).unwrap();
+        assert!(network.authorized_dapps.iter().all(|d| {
+            d.references_to_authorized_personas.iter().all(|p| {
+                p.shared_accounts
+                    .as_ref()
+                    .map(|s| !s.ids.contains_id(&address))
+                    .unwrap_or(true)
+            })
+        }));
+    }
+
+    #[test]
+    fn hide_unknown_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.hide_account(Account::placeholder_nebunet().address),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
     #[test]
     fn update_account() {
         let profile = Profile::placeholder();
         let (wallet, _) = Wallet::ephemeral(profile.clone());
-        let mut account =
-            wallet.access_profile_with(|p| p.networks[0].accounts[0].clone());
+        let mut account = wallet
+            .access_profile_with(|p| p.networks[0].accounts[0].clone())
+            .unwrap();
         assert_eq!(account.display_name.value, "Alice");
         account.display_name = DisplayName::new("Stella").unwrap();
         account.appearance_id = AppearanceID::new(7).unwrap();
@@ -279,11 +359,13 @@ mod tests {
         );
 
         // Assert account has been updated in `wallet.profile`
-        wallet.access_profile_with(|p| {
-            let account = &p.networks[0].accounts[0];
-            assert_eq!(account.display_name.value, "Stella");
-            assert_eq!(account.appearance_id.value, 7);
-        });
+        wallet
+            .access_profile_with(|p| {
+                let account = &p.networks[0].accounts[0];
+                assert_eq!(account.display_name.value, "Stella");
+                assert_eq!(account.appearance_id.value, 7);
+            })
+            .unwrap();
     }
 
     #[test]
@@ -330,6 +412,7 @@ mod tests {
         assert_eq!(
             wallet
                 .profile()
+                .unwrap()
                 .factor_sources
                 .contains_id(&new.clone().factor_source.factor_source_id()),
             true
@@ -355,6 +438,7 @@ mod tests {
         #[derive(Debug)]
         struct TestStorage {
             delete_data_was_called: Arc<RwLock<Option<SecureStorageKey>>>,
+            fail_saving_profile: AtomicBool,
         }
         impl SecureStorage for TestStorage {
             fn load_data(
@@ -366,10 +450,19 @@ mod tests {
 
             fn save_data(
                 &self,
-                _key: SecureStorageKey,
+                key: SecureStorageKey,
                 _data: Vec<u8>,
             ) -> Result<()> {
-                Ok(()) // mnemonic gets saved
+                match key {
+                    SecureStorageKey::ProfileSnapshot { profile_id: _ }
+                        if self
+                            .fail_saving_profile
+                            .load(std::sync::atomic::Ordering::SeqCst) =>
+                    {
+                        Err(CommonError::Unknown)
+                    }
+                    _ => Ok(()), // mnemonic gets saved
+                }
             }
 
             fn delete_data_for_key(&self, key: SecureStorageKey) -> Result<()> {
@@ -381,11 +474,19 @@ mod tests {
         }
         let storage = Arc::new(TestStorage {
             delete_data_was_called: delete_data_was_called.clone(),
+            fail_saving_profile: AtomicBool::new(false),
         });
-        let wallet = Wallet::by_importing_profile(profile, storage.clone());
+        let wallet = Wallet::by_importing_profile(
+            profile,
+            storage.clone(),
+            ProfileImportPolicy::Accept,
+        )
+        .unwrap();
 
-        // Acquire write lock, in order to make `wallet.add_private_device_factor_source` fail (because cant have multiple writers).
-        let lock = wallet.profile.write().unwrap();
+        // Fail saving Profile, in order to make `wallet.add_private_device_factor_source` fail.
+        storage
+            .fail_saving_profile
+            .store(true, std::sync::atomic::Ordering::SeqCst);
 
         assert_eq!(
             wallet.add_private_device_factor_source(new.clone()),
@@ -393,11 +494,11 @@ mod tests {
                 new.factor_source.factor_source_id()
             ))
         );
-        drop(lock);
 
         assert_eq!(
             wallet
                 .profile()
+                .unwrap()
                 .factor_sources
                 .contains_id(&new.clone().factor_source.factor_source_id()),
             false // should not have been saved.
@@ -410,6 +511,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn change_name_of_account_is_rolled_back_when_save_fails() {
+        #[derive(Debug, Default)]
+        struct FailingProfileSaveStorage {
+            fail: AtomicBool,
+        }
+        impl SecureStorage for FailingProfileSaveStorage {
+            fn load_data(
+                &self,
+                _key: SecureStorageKey,
+            ) -> Result<Option<Vec<u8>>> {
+                Ok(None)
+            }
+
+            fn save_data(
+                &self,
+                _key: SecureStorageKey,
+                _data: Vec<u8>,
+            ) -> Result<()> {
+                if self.fail.load(std::sync::atomic::Ordering::SeqCst) {
+                    Err(CommonError::Unknown)
+                } else {
+                    Ok(())
+                }
+            }
+
+            fn delete_data_for_key(
+                &self,
+                _key: SecureStorageKey,
+            ) -> Result<()> {
+                Ok(())
+            }
+        }
+        let storage = Arc::new(FailingProfileSaveStorage::default());
+        let profile = Profile::placeholder();
+        let wallet = Wallet::by_importing_profile(
+            profile.clone(),
+            storage.clone(),
+            ProfileImportPolicy::Accept,
+        )
+        .unwrap();
+        storage
+            .fail
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let address = profile.networks[0].accounts[0].address.clone();
+        assert_eq!(
+            wallet.change_name_of_account(
+                address,
+                DisplayName::new("Stella").unwrap()
+            ),
+            Err(CommonError::Unknown)
+        );
+        assert_eq!(wallet.profile().unwrap(), profile);
+    }
+
     #[test]
     fn add_factor_source_fails_when_already_exists() {
         let profile = Profile::placeholder();
@@ -463,7 +620,7 @@ mod tests {
         );
 
         let (wallet, storage) = Wallet::ephemeral(init_profile);
-        assert_before(wallet.profile());
+        assert_before(wallet.profile().unwrap());
 
         let data =
             serde_json::to_vec(&private.mnemonic_with_passphrase).unwrap();
@@ -484,7 +641,7 @@ mod tests {
         assert_eq!(account.display_name, account_name);
         assert_eq!(account.network_id, network_id);
 
-        assert_after(account, wallet.profile());
+        assert_after(account, wallet.profile().unwrap());
     }
 
     fn test_create_new_account_first_success<F>(also_save: bool, assert_last: F)
@@ -562,4 +719,44 @@ mod tests {
             assert_eq!(q.networks[0].accounts[2], a);
         })
     }
+
+    #[test]
+    fn third_party_deposits_update_manifest_then_save() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = AccountAddress::placeholder_stokenet();
+        let to = ThirdPartyDeposits::new(DepositRule::DenyAll);
+        let manifest = wallet
+            .manifest_for_third_party_deposits_update(
+                address.clone(),
+                to.clone(),
+            )
+            .unwrap();
+        assert!(manifest
+            .instructions
+            .contains("\"set_default_deposit_rule\""));
+
+        let account = wallet
+            .update_third_party_deposits_of_account(address.clone(), to.clone())
+            .unwrap();
+        assert_eq!(account.on_ledger_settings.third_party_deposits, to);
+        assert_eq!(
+            wallet
+                .manifest_for_third_party_deposits_update(address, to)
+                .unwrap()
+                .instructions,
+            ""
+        );
+    }
+
+    #[test]
+    fn third_party_deposits_update_unknown_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.update_third_party_deposits_of_account(
+                AccountAddress::placeholder_mainnet_other(),
+                ThirdPartyDeposits::default()
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
 }
@@ -0,0 +1,265 @@
+use crate::prelude::*;
+
+//========
+// GET - Dapp Interactions
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Validates a request sent by a dApp against the active Profile, see
+    /// [`Profile::validate_dapp_to_wallet_interaction`].
+    pub fn validate_dapp_to_wallet_interaction(
+        &self,
+        interaction: DappToWalletInteraction,
+    ) -> Result<ValidatedDappToWalletInteraction> {
+        self.access_profile_with(|p| {
+            p.validate_dapp_to_wallet_interaction(interaction.clone())
+        })?
+    }
+
+    /// Evaluates the ongoing requests in `items` against what the user has
+    /// previously shared with the dApp, see
+    /// [`Profile::fulfill_ongoing_requests`].
+    pub fn fulfill_ongoing_requests(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+        persona_address: IdentityAddress,
+        items: DappToWalletInteractionAuthorizedRequestItems,
+    ) -> Result<OngoingRequestsFulfillment> {
+        self.access_profile_with(|p| {
+            p.fulfill_ongoing_requests(
+                &dapp_definition_address,
+                &persona_address,
+                &items,
+            )
+        })?
+    }
+}
+
+//========
+// SET - Authorized Dapps
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Records the ongoing accounts and PersonaData the user has approved to
+    /// share with the dApp, logging in with the Persona with `persona_address`,
+    /// and saves the Profile. See
+    /// [`Profile::update_authorized_dapp_after_approval`].
+    pub fn update_authorized_dapp_after_approval(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+        display_name: Option<String>,
+        persona_address: IdentityAddress,
+        shared_accounts: Option<SharedToDappWithPersonaAccountAddresses>,
+        shared_persona_data: Option<SharedPersonaData>,
+    ) -> Result<AuthorizedDapp> {
+        self.try_update_profile_with(|p| {
+            p.update_authorized_dapp_after_approval(
+                &dapp_definition_address,
+                display_name.clone(),
+                &persona_address,
+                shared_accounts.clone(),
+                shared_persona_data.clone(),
+                now(),
+            )
+        })
+    }
+
+    /// Forgets the dApp with `dapp_definition_address`, removing everything
+    /// shared with it, and saves the Profile.
+    pub fn forget_authorized_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<()> {
+        self.try_update_profile_with(|p| {
+            p.forget_authorized_dapp(&dapp_definition_address)
+        })
+    }
+
+    /// Removes the Persona with `persona_address` from the dApp with
+    /// `dapp_definition_address` and saves the Profile, see
+    /// [`Profile::deauthorize_persona_from_authorized_dapp`].
+    pub fn deauthorize_persona_from_authorized_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+        persona_address: IdentityAddress,
+    ) -> Result<Option<AuthorizedDapp>> {
+        self.try_update_profile_with(|p| {
+            p.deauthorize_persona_from_authorized_dapp(
+                &dapp_definition_address,
+                &persona_address,
+            )
+        })
+    }
+
+    /// Replaces the accounts shared with the dApp using the Persona with
+    /// `persona_address` and saves the Profile, `None` stops sharing accounts.
+    pub fn update_shared_accounts_of_authorized_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+        persona_address: IdentityAddress,
+        shared_accounts: Option<SharedToDappWithPersonaAccountAddresses>,
+    ) -> Result<AuthorizedDapp> {
+        self.try_update_profile_with(|p| {
+            p.update_shared_accounts_of_authorized_dapp(
+                &dapp_definition_address,
+                &persona_address,
+                shared_accounts.clone(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn validate_dapp_to_wallet_interaction() {
+        let mut profile = Profile::placeholder();
+        profile
+            .app_preferences
+            .gateways
+            .change_current(Gateway::mainnet())
+            .unwrap();
+        let (wallet, _) = Wallet::ephemeral(profile);
+        let validated = wallet
+            .validate_dapp_to_wallet_interaction(
+                DappToWalletInteraction::placeholder(),
+            )
+            .unwrap();
+        assert_eq!(
+            validated.interaction,
+            DappToWalletInteraction::placeholder()
+        );
+    }
+
+    #[test]
+    fn validate_dapp_to_wallet_interaction_wrong_network() {
+        let wallet = Wallet::placeholder();
+        assert_eq!(
+            wallet.validate_dapp_to_wallet_interaction(
+                DappToWalletInteraction::placeholder()
+            ),
+            Err(CommonError::WalletInteractionNetworkMismatch {
+                expected: NetworkID::Zabanet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn update_authorized_dapp_after_approval_then_fulfilled() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let dapp_definition_address =
+            AuthorizedDapp::placeholder_stokenet_devconsole()
+                .dapp_definition_address;
+        let persona_address = IdentityAddress::placeholder_stokenet();
+        let request = DappToWalletInteractionAccountsRequestItem::new(
+            RequestedQuantity::exactly(1),
+            None,
+        );
+        let items = DappToWalletInteractionAuthorizedRequestItems {
+            auth: DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge,
+            reset: None,
+            ongoing_accounts: Some(request),
+            ongoing_persona_data: None,
+            one_time_accounts: None,
+            one_time_persona_data: None,
+        };
+        let fulfillment = wallet
+            .fulfill_ongoing_requests(
+                dapp_definition_address.clone(),
+                persona_address.clone(),
+                items.clone(),
+            )
+            .unwrap();
+        assert!(!fulfillment.is_fully_fulfilled());
+
+        wallet
+            .update_authorized_dapp_after_approval(
+                dapp_definition_address.clone(),
+                None,
+                persona_address.clone(),
+                Some(SharedToDappWithPersonaAccountAddresses::new(
+                    RequestedQuantity::exactly(1),
+                    IdentifiedVecVia::from_iter([
+                        Account::placeholder_stokenet_carol().address,
+                    ]),
+                )),
+                None,
+            )
+            .unwrap();
+
+        let fulfillment = wallet
+            .fulfill_ongoing_requests(
+                dapp_definition_address,
+                persona_address,
+                items,
+            )
+            .unwrap();
+        assert_eq!(
+            fulfillment.accounts,
+            Some(OngoingAccountsFulfillment::Fulfilled {
+                accounts: vec![Account::placeholder_stokenet_carol()]
+            })
+        );
+    }
+
+    #[test]
+    fn forget_authorized_dapp() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = AuthorizedDapp::placeholder_mainnet_gumballclub()
+            .dapp_definition_address;
+        wallet.forget_authorized_dapp(address.clone()).unwrap();
+        assert!(!wallet
+            .profile()
+            .unwrap()
+            .networks
+            .get(&NetworkID::Mainnet)
+            .unwrap()
+            .authorized_dapps
+            .contains_id(&address));
+    }
+
+    #[test]
+    fn deauthorize_persona_from_authorized_dapp() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = AuthorizedDapp::placeholder_mainnet_gumballclub()
+            .dapp_definition_address;
+        assert_eq!(
+            wallet.deauthorize_persona_from_authorized_dapp(
+                address.clone(),
+                IdentityAddress::placeholder_mainnet_other()
+            ),
+            Ok(None)
+        );
+        assert_eq!(
+            wallet.deauthorize_persona_from_authorized_dapp(
+                address.clone(),
+                IdentityAddress::placeholder_mainnet_other()
+            ),
+            Err(CommonError::UnknownAuthorizedDapp(address))
+        );
+    }
+
+    #[test]
+    fn update_shared_accounts_of_authorized_dapp() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let updated = wallet
+            .update_shared_accounts_of_authorized_dapp(
+                AuthorizedDapp::placeholder_mainnet_dashboard()
+                    .dapp_definition_address,
+                IdentityAddress::placeholder_mainnet(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            updated
+                .references_to_authorized_personas
+                .get(&IdentityAddress::placeholder_mainnet())
+                .unwrap()
+                .shared_accounts,
+            None
+        );
+    }
+}
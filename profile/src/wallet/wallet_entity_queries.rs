@@ -0,0 +1,160 @@
+use crate::prelude::*;
+
+//========
+// GET - Entities
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The account with `address` on any network, throws an error if the
+    /// account is unknown to the wallet.
+    pub fn account_by_address(
+        &self,
+        address: AccountAddress,
+    ) -> Result<Account> {
+        self.access_index_with(|i| i.account(&address).cloned())?
+            .ok_or(CommonError::UnknownAccount)
+    }
+
+    /// The persona with `address` on any network, throws an error if the
+    /// persona is unknown to the wallet.
+    pub fn persona_by_address(
+        &self,
+        address: IdentityAddress,
+    ) -> Result<Persona> {
+        self.access_index_with(|i| i.persona(&address).cloned())?
+            .ok_or(CommonError::UnknownPersona)
+    }
+
+    /// The account or persona having a factor instance with `public_key`,
+    /// if any.
+    pub fn entity_by_public_key(
+        &self,
+        public_key: PublicKey,
+    ) -> Result<Option<AccountOrPersona>> {
+        self.access_index_with(|i| i.entity_by_public_key(&public_key))
+    }
+
+    /// The accounts and personas having a factor instance of the factor
+    /// source with `factor_source_id`.
+    pub fn entities_controlled_by_factor_source(
+        &self,
+        factor_source_id: FactorSourceID,
+    ) -> Result<Vec<AccountOrPersona>> {
+        self.access_index_with(|i| i.entities_controlled_by(&factor_source_id))
+    }
+
+    /// The personas used to log in to the authorized dApp with
+    /// `dapp_definition_address`.
+    pub fn personas_of_authorized_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<Vec<Persona>> {
+        self.access_index_with(|i| {
+            i.personas_of_authorized_dapp(&dapp_definition_address)
+        })
+    }
+}
+
+impl Wallet {
+    /// Reads the index of Profile, see [`Wallet::access_profile_with`].
+    pub(crate) fn access_index_with<T, F>(&self, access: F) -> Result<T>
+    where
+        F: FnOnce(&ProfileIndex) -> T,
+    {
+        self.index
+            .read()
+            .map(|i| access(&i))
+            .map_err(|_| CommonError::UnableToAcquireReadLockForProfile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn account_by_address() {
+        let wallet = Wallet::placeholder();
+        let account = wallet.profile().unwrap().networks[0].accounts[0].clone();
+        assert_eq!(
+            wallet.account_by_address(account.address.clone()),
+            Ok(account)
+        );
+        assert_eq!(
+            wallet.account_by_address(Account::placeholder_nebunet().address),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn persona_by_address() {
+        let wallet = Wallet::placeholder();
+        let persona = wallet.profile().unwrap().networks[0].personas[0].clone();
+        assert_eq!(
+            wallet.persona_by_address(persona.address.clone()),
+            Ok(persona)
+        );
+    }
+
+    #[test]
+    fn entity_by_public_key() {
+        let wallet = Wallet::placeholder();
+        let account = wallet.profile().unwrap().networks[0].accounts[0].clone();
+        let public_key = account
+            .security_state
+            .entity_creating_factor_instance()
+            .public_key
+            .public_key;
+        assert_eq!(
+            wallet.entity_by_public_key(public_key),
+            Ok(Some(account.into()))
+        );
+    }
+
+    #[test]
+    fn entities_controlled_by_factor_source() {
+        let wallet = Wallet::placeholder();
+        let profile = wallet.profile().unwrap();
+        let id = profile.bdfs().factor_source_id();
+        assert_eq!(
+            wallet.entities_controlled_by_factor_source(id.clone()),
+            Ok(profile.index().entities_controlled_by(&id))
+        );
+    }
+
+    #[test]
+    fn personas_of_authorized_dapp() {
+        let wallet = Wallet::placeholder();
+        let dapp =
+            wallet.profile().unwrap().networks[0].authorized_dapps[0].clone();
+        assert_eq!(
+            wallet
+                .personas_of_authorized_dapp(dapp.dapp_definition_address)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.address)
+                .collect_vec(),
+            dapp.references_to_authorized_personas
+                .iter()
+                .map(|r| r.identity_address.clone())
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn index_is_rebuilt_after_update() {
+        let wallet = Wallet::placeholder();
+        let account = wallet.profile().unwrap().networks[0].accounts[0].clone();
+        let name = DisplayName::new("Renamed").unwrap();
+        wallet
+            .change_name_of_account(account.address.clone(), name.clone())
+            .unwrap();
+        assert_eq!(
+            wallet
+                .account_by_address(account.address)
+                .unwrap()
+                .display_name,
+            name
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::prelude::*;
+
+//========
+// GET - Factor Source Usage
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The accounts, personas, networks and security structures using the
+    /// factor source with `factor_source_id`, throws an error if the factor
+    /// source is unknown to the wallet.
+    pub fn factor_source_usage(
+        &self,
+        factor_source_id: FactorSourceID,
+    ) -> Result<FactorSourceUsage> {
+        self.access_profile_with(|p| {
+            p.factor_sources
+                .get(&factor_source_id)
+                .map(|_| p.factor_source_usage(&factor_source_id))
+                .ok_or(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                    factor_source_id.clone(),
+                ))
+        })?
+    }
+
+    /// The usage of every factor source of the wallet.
+    pub fn factor_source_usages(&self) -> Result<Vec<FactorSourceUsage>> {
+        self.access_profile_with(|p| p.factor_source_usages())
+    }
+}
+
+//========
+// SET - Factor Sources
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Removes the factor source with `factor_source_id` from Profile and,
+    /// if it is a `DeviceFactorSource`, deletes its mnemonic from
+    /// SecureStorage.
+    ///
+    /// Throws an error if the factor source is unknown, if it is the main
+    /// one, or if it is in use, see [`FactorSourceUsage::is_in_use`], in
+    /// which case neither Profile nor SecureStorage is changed. The mnemonic
+    /// is only deleted once the updated Profile has been saved, which is
+    /// done right away regardless of the [`ProfilePersistencePolicy`].
+    pub fn remove_factor_source(
+        &self,
+        factor_source_id: FactorSourceID,
+    ) -> Result<()> {
+        let removed = self.try_update_profile_with(|p| {
+            p.remove_factor_source(&factor_source_id)
+        })?;
+
+        info!("Removed FactorSource: {}", &removed);
+
+        if let FactorSource::Device { value } = removed {
+            // A `Throttled` or `Explicit` policy might not have saved the
+            // update, and a saved Profile must never reference a factor
+            // source whose mnemonic has been deleted.
+            self.flush()?;
+            self.wallet_client_storage.delete_mnemonic(&value.id)?;
+        }
+        Ok(())
+    }
+
+    /// Flags the factor source with `factor_source_id` as deleted by the
+    /// user, hiding it e.g. from Multi-Factor setup flows, its mnemonic, if
+    /// any, is kept in SecureStorage. Throws an error if the factor source
+    /// is unknown or if it is the main one.
+    pub fn mark_factor_source_deleted_by_user(
+        &self,
+        factor_source_id: FactorSourceID,
+    ) -> Result<FactorSource> {
+        self.try_update_profile_with(|p| {
+            p.mark_factor_source_deleted_by_user(&factor_source_id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn mnemonic_key(id: &FactorSourceIDFromHash) -> SecureStorageKey {
+        SecureStorageKey::DeviceFactorSourceMnemonic {
+            factor_source_id: id.clone(),
+        }
+    }
+
+    #[test]
+    fn factor_source_usage() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let profile = wallet.profile().unwrap();
+        let id = profile.bdfs().factor_source_id();
+        assert_eq!(
+            wallet.factor_source_usage(id.clone()),
+            Ok(profile.factor_source_usage(&id))
+        );
+        assert_eq!(
+            wallet.factor_source_usages(),
+            Ok(profile.factor_source_usages())
+        );
+    }
+
+    #[test]
+    fn factor_source_usage_of_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = LedgerHardwareWalletFactorSource::placeholder_other()
+            .factor_source_id();
+        assert_eq!(
+            wallet.factor_source_usage(id.clone()),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(id))
+        );
+    }
+
+    #[test]
+    fn remove_device_factor_source_deletes_mnemonic() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let id = new.factor_source.id.clone();
+        wallet.add_private_device_factor_source(new).unwrap();
+        assert!(storage
+            .storage
+            .read()
+            .unwrap()
+            .contains_key(&mnemonic_key(&id)));
+
+        assert_eq!(wallet.remove_factor_source(id.clone().into()), Ok(()));
+
+        assert!(!wallet
+            .profile()
+            .unwrap()
+            .factor_sources
+            .contains_id(&id.clone().into()));
+        assert!(!storage
+            .storage
+            .read()
+            .unwrap()
+            .contains_key(&mnemonic_key(&id)));
+    }
+
+    #[test]
+    fn remove_device_factor_source_with_explicit_policy_saves_profile() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let id = new.factor_source.id.clone();
+        wallet.add_private_device_factor_source(new).unwrap();
+        wallet
+            .set_persistence_policy(ProfilePersistencePolicy::Explicit)
+            .unwrap();
+
+        assert_eq!(wallet.remove_factor_source(id.clone().into()), Ok(()));
+
+        assert!(!wallet.has_unsaved_changes());
+        let saved: Profile = serde_json::from_slice(
+            &storage
+                .load_data(SecureStorageKey::ProfileSnapshot {
+                    profile_id: wallet.profile().unwrap().id(),
+                })
+                .unwrap()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!saved.factor_sources.contains_id(&id.clone().into()));
+        assert!(!storage
+            .storage
+            .read()
+            .unwrap()
+            .contains_key(&mnemonic_key(&id)));
+    }
+
+    #[test]
+    fn remove_factor_source_in_use_keeps_mnemonic() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        let new = PrivateHierarchicalDeterministicFactorSource::generate_new(
+            WalletClientModel::Unknown,
+        );
+        let id = new.factor_source.id.clone();
+        wallet.add_private_device_factor_source(new).unwrap();
+
+        // Use the new device factor source in the recovery role of the
+        // first account.
+        let mut structure = SecurityStructureOfFactorInstances::placeholder();
+        structure.recovery_role = RoleOfFactorInstances::single_override(
+            HierarchicalDeterministicFactorInstance::new(
+                id.clone(),
+                HierarchicalDeterministicFactorInstance::placeholder()
+                    .public_key,
+            ),
+        );
+        wallet
+            .update_profile_with(|p| {
+                let address = p.networks[0].accounts[0].address.clone();
+                p.update_account(&address, |a| {
+                    a.security_state = SecuredEntityControl::new(
+                        a.security_state.entity_creating_factor_instance(),
+                        AccessControllerAddress::placeholder(),
+                        structure.clone(),
+                    )
+                    .into()
+                });
+            })
+            .unwrap();
+        let profile = wallet.profile().unwrap();
+
+        assert_eq!(
+            wallet.remove_factor_source(id.clone().into()),
+            Err(CommonError::FactorSourceInUse(id.clone().into()))
+        );
+        assert_eq!(wallet.profile().unwrap(), profile);
+        assert!(storage
+            .storage
+            .read()
+            .unwrap()
+            .contains_key(&mnemonic_key(&id)));
+    }
+
+    #[test]
+    fn mark_factor_source_deleted_by_user() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id =
+            LedgerHardwareWalletFactorSource::placeholder().factor_source_id();
+        let marked = wallet
+            .mark_factor_source_deleted_by_user(id.clone())
+            .unwrap();
+        assert_eq!(
+            wallet.profile().unwrap().factor_sources.get(&id).cloned(),
+            Some(marked)
+        );
+    }
+}
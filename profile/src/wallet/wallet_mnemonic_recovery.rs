@@ -0,0 +1,228 @@
+use crate::prelude::*;
+
+/// Whether the mnemonic of a `DeviceFactorSource` is in SecureStorage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum MnemonicStatus {
+    /// The mnemonic is in SecureStorage, the factor source can sign.
+    Present,
+
+    /// The mnemonic is missing from SecureStorage, e.g. after restoring a
+    /// Profile from a backup on a new device, and must be imported with
+    /// [`Wallet::import_mnemonic_for_device_factor_source`] before the
+    /// factor source can sign.
+    NeedsRecovery,
+}
+
+/// A `DeviceFactorSource` of the Profile, the accounts and personas it
+/// controls and whether its mnemonic is in SecureStorage.
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct DeviceFactorSourceMnemonicStatus {
+    pub factor_source: DeviceFactorSource,
+    pub entities: Vec<AccountOrPersona>,
+    pub status: MnemonicStatus,
+}
+
+//========
+// GET - Mnemonic Status
+//========
+#[uniffi::export]
+impl Wallet {
+    /// The mnemonic status of every `DeviceFactorSource` of Profile, in the
+    /// order of `factor_sources`.
+    ///
+    /// The factor sources and the entities they control are read from the
+    /// same snapshot of Profile. The status is read from SecureStorage with
+    /// [`SecureStorage::load_data`], for which the Wallet Client might
+    /// prompt the user, e.g. for biometrics, once per factor source.
+    pub fn device_factor_sources_mnemonic_status(
+        &self,
+    ) -> Result<Vec<DeviceFactorSourceMnemonicStatus>> {
+        let device_factor_sources = self.access_profile_with(|p| {
+            let index = p.index();
+            p.factor_sources
+                .iter()
+                .filter_map(|f| f.as_device().cloned())
+                .map(|factor_source| {
+                    let entities = index.entities_controlled_by(
+                        &factor_source.factor_source_id(),
+                    );
+                    (factor_source, entities)
+                })
+                .collect_vec()
+        })?;
+        device_factor_sources
+            .into_iter()
+            .map(|(factor_source, entities)| {
+                let status = if self
+                    .wallet_client_storage
+                    .contains_mnemonic(&factor_source.id)?
+                {
+                    MnemonicStatus::Present
+                } else {
+                    MnemonicStatus::NeedsRecovery
+                };
+                Ok(DeviceFactorSourceMnemonicStatus {
+                    factor_source,
+                    entities,
+                    status,
+                })
+            })
+            .collect()
+    }
+
+    /// The `DeviceFactorSource`s of Profile whose mnemonic is missing from
+    /// SecureStorage, see [`MnemonicStatus::NeedsRecovery`] and
+    /// [`Wallet::device_factor_sources_mnemonic_status`].
+    pub fn device_factor_sources_needing_recovery(
+        &self,
+    ) -> Result<Vec<DeviceFactorSourceMnemonicStatus>> {
+        self.device_factor_sources_mnemonic_status()
+            .map(|statuses| {
+                statuses
+                    .into_iter()
+                    .filter(|s| s.status == MnemonicStatus::NeedsRecovery)
+                    .collect()
+            })
+    }
+}
+
+//========
+// SET - Mnemonic
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Saves `mnemonic_with_passphrase` to SecureStorage as the mnemonic of
+    /// the `DeviceFactorSource` with `factor_source_id`.
+    ///
+    /// Throws an error if the factor source is not a `DeviceFactorSource`
+    /// of Profile, or if the ID derived from `mnemonic_with_passphrase`
+    /// does not match `factor_source_id`, in which case nothing is saved.
+    pub fn import_mnemonic_for_device_factor_source(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+    ) -> Result<()> {
+        let factor_source = self.access_profile_with(|p| {
+            p.device_factor_source_by_id(&factor_source_id)
+        })??;
+
+        let found = FactorSourceIDFromHash::new_for_device(
+            mnemonic_with_passphrase.clone(),
+        );
+        if found != factor_source.id {
+            error!(
+                "Mnemonic does not match DeviceFactorSource, expected: {}, found: {}",
+                &factor_source.id, &found
+            );
+            return Err(CommonError::MnemonicDoesNotMatchFactorSource {
+                expected: factor_source.id,
+                found,
+            });
+        }
+
+        info!(
+            "Import mnemonic of DeviceFactorSource to SecureStorage, factor source id: {}",
+            &factor_source.id
+        );
+        self.wallet_client_storage.save_mnemonic_with_passphrase(
+            &mnemonic_with_passphrase,
+            &factor_source.id,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn restored_profile_needs_recovery() {
+        let profile = Profile::placeholder();
+        let (wallet, _) = Wallet::ephemeral(profile.clone());
+        let bdfs = profile.bdfs();
+        assert_eq!(
+            wallet.device_factor_sources_needing_recovery(),
+            Ok(vec![DeviceFactorSourceMnemonicStatus {
+                factor_source: bdfs.clone(),
+                entities: profile
+                    .entities_controlled_by(&bdfs.factor_source_id()),
+                status: MnemonicStatus::NeedsRecovery,
+            }])
+        );
+    }
+
+    #[test]
+    fn import_matching_mnemonic() {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let id = private.factor_source.id.clone();
+        let (wallet, storage) =
+            Wallet::ephemeral(Profile::new(private.clone(), "Test"));
+
+        assert_eq!(
+            wallet.import_mnemonic_for_device_factor_source(
+                id.clone(),
+                private.mnemonic_with_passphrase.clone()
+            ),
+            Ok(())
+        );
+
+        assert!(storage.storage.read().unwrap().contains_key(
+            &SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: id,
+            }
+        ));
+        assert_eq!(wallet.device_factor_sources_needing_recovery(), Ok(vec![]));
+        assert_eq!(
+            wallet
+                .device_factor_sources_mnemonic_status()
+                .unwrap()
+                .into_iter()
+                .map(|s| s.status)
+                .collect_vec(),
+            vec![MnemonicStatus::Present]
+        );
+    }
+
+    #[test]
+    fn import_mismatching_mnemonic_fails() {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let id = private.factor_source.id.clone();
+        let (wallet, storage) =
+            Wallet::ephemeral(Profile::new(private, "Test"));
+        let other = MnemonicWithPassphrase::placeholder_other();
+
+        assert_eq!(
+            wallet.import_mnemonic_for_device_factor_source(
+                id.clone(),
+                other.clone()
+            ),
+            Err(CommonError::MnemonicDoesNotMatchFactorSource {
+                expected: id.clone(),
+                found: FactorSourceIDFromHash::new_for_device(other)
+            })
+        );
+        assert!(!storage.storage.read().unwrap().contains_key(
+            &SecureStorageKey::DeviceFactorSourceMnemonic {
+                factor_source_id: id,
+            }
+        ));
+    }
+
+    #[test]
+    fn import_for_unknown_factor_source_fails() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let other =
+            PrivateHierarchicalDeterministicFactorSource::placeholder_other();
+        assert_eq!(
+            wallet.import_mnemonic_for_device_factor_source(
+                other.factor_source.id.clone(),
+                other.mnemonic_with_passphrase
+            ),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                other.factor_source.id.into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use crate::prelude::*;
+
+//========
+// SET - Persona
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Hides the persona with the provided address, it is removed from all
+    /// dApps it has been used with. Throws an error if the persona is unknown
+    /// to the wallet.
+    pub fn hide_persona(&self, address: IdentityAddress) -> Result<Persona> {
+        self.update_profile_with(|p| {
+            p.update_persona(&address, |persona| {
+                persona.flags.insert_flag(EntityFlag::DeletedByUser);
+            })
+        })?
+        .ok_or(CommonError::UnknownPersona)
+    }
+}
+
+//========
+// SET - Persona Data
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Sets the name of the persona with `persona_address`, keeping the ID of
+    /// any existing name entry, and saves the Profile.
+    pub fn set_persona_data_name(
+        &self,
+        persona_address: IdentityAddress,
+        name: PersonaDataEntryName,
+    ) -> Result<PersonaDataIdentifiedName> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.set_name(name.clone())
+            })
+        })
+    }
+
+    /// Adds `email` as a new entry to the persona with `persona_address` and
+    /// saves the Profile.
+    pub fn add_persona_data_email_address(
+        &self,
+        persona_address: IdentityAddress,
+        email: PersonaDataEntryEmailAddress,
+    ) -> Result<PersonaDataIdentifiedEmailAddress> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.add_email_address(email.clone())
+            })
+        })
+    }
+
+    /// Updates the value of the email address entry with the ID of `entry`
+    /// of the persona with `persona_address` and saves the Profile.
+    pub fn update_persona_data_email_address(
+        &self,
+        persona_address: IdentityAddress,
+        entry: PersonaDataIdentifiedEmailAddress,
+    ) -> Result<()> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.update_email_address(entry.clone())
+            })
+        })
+    }
+
+    /// Adds `number` as a new entry to the persona with `persona_address` and
+    /// saves the Profile.
+    pub fn add_persona_data_phone_number(
+        &self,
+        persona_address: IdentityAddress,
+        number: PersonaDataEntryPhoneNumber,
+    ) -> Result<PersonaDataIdentifiedPhoneNumber> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.add_phone_number(number.clone())
+            })
+        })
+    }
+
+    /// Updates the value of the phone number entry with the ID of `entry`
+    /// of the persona with `persona_address` and saves the Profile.
+    pub fn update_persona_data_phone_number(
+        &self,
+        persona_address: IdentityAddress,
+        entry: PersonaDataIdentifiedPhoneNumber,
+    ) -> Result<()> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.update_phone_number(entry.clone())
+            })
+        })
+    }
+
+    /// Sets the date of birth of the persona with `persona_address`, keeping
+    /// the ID of any existing date of birth entry, and saves the Profile.
+    pub fn set_persona_data_date_of_birth(
+        &self,
+        persona_address: IdentityAddress,
+        date_of_birth: PersonaDataEntryDateOfBirth,
+    ) -> Result<PersonaDataIdentifiedDateOfBirth> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.set_date_of_birth(date_of_birth.clone())
+            })
+        })
+    }
+
+    /// Sets the company name of the persona with `persona_address`, keeping
+    /// the ID of any existing company name entry, and saves the Profile.
+    pub fn set_persona_data_company_name(
+        &self,
+        persona_address: IdentityAddress,
+        company_name: PersonaDataEntryCompanyName,
+    ) -> Result<PersonaDataIdentifiedCompanyName> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.set_company_name(company_name.clone())
+            })
+        })
+    }
+
+    /// Adds `postal_address` as a new entry to the persona with
+    /// `persona_address` and saves the Profile.
+    pub fn add_persona_data_postal_address(
+        &self,
+        persona_address: IdentityAddress,
+        postal_address: PersonaDataEntryPostalAddress,
+    ) -> Result<PersonaDataIdentifiedPostalAddress> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.add_postal_address(postal_address.clone())
+            })
+        })
+    }
+
+    /// Updates the value of the postal address entry with the ID of `entry`
+    /// of the persona with `persona_address` and saves the Profile.
+    pub fn update_persona_data_postal_address(
+        &self,
+        persona_address: IdentityAddress,
+        entry: PersonaDataIdentifiedPostalAddress,
+    ) -> Result<()> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.update_postal_address(entry.clone())
+            })
+        })
+    }
+
+    /// Adds `url` as a new entry to the persona with `persona_address` and
+    /// saves the Profile.
+    pub fn add_persona_data_url(
+        &self,
+        persona_address: IdentityAddress,
+        url: PersonaDataEntryUrl,
+    ) -> Result<PersonaDataIdentifiedUrl> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| d.add_url(url.clone()))
+        })
+    }
+
+    /// Updates the value of the URL entry with the ID of `entry` of the
+    /// persona with `persona_address` and saves the Profile.
+    pub fn update_persona_data_url(
+        &self,
+        persona_address: IdentityAddress,
+        entry: PersonaDataIdentifiedUrl,
+    ) -> Result<()> {
+        self.try_update_profile_with(|p| {
+            p.update_persona_data(&persona_address, |d| {
+                d.update_url(entry.clone())
+            })
+        })
+    }
+
+    /// Removes the PersonaData entry of `kind` with `id` from the persona
+    /// with `persona_address`, it is no longer shared with any dApp, and saves
+    /// the Profile.
+    pub fn remove_persona_data_entry(
+        &self,
+        persona_address: IdentityAddress,
+        kind: PersonaDataEntryKind,
+        id: PersonaDataEntryID,
+    ) -> Result<()> {
+        self.try_update_profile_with(|p| {
+            p.remove_persona_data_entry(&persona_address, kind, &id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn hide_persona() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = IdentityAddress::placeholder_stokenet();
+        let hidden = wallet.hide_persona(address.clone()).unwrap();
+        assert!(hidden.flags.contains(&EntityFlag::DeletedByUser));
+        let profile = wallet.profile().unwrap();
+        let network = profile.networks.get(&NetworkID::Stokenet).unwrap();
+        assert!(network.authorized_dapps.iter().all(|d| !d
+            .references_to_authorized_personas
+            .contains_id(&address)));
+    }
+
+    #[test]
+    fn hide_unknown_persona() {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Stokenet, |n| {
+            n.personas = Personas::new();
+        });
+        let (wallet, _) = Wallet::ephemeral(profile);
+        assert_eq!(
+            wallet.hide_persona(IdentityAddress::placeholder_stokenet()),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn set_persona_data_name() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = IdentityAddress::placeholder_stokenet();
+        let id = Persona::placeholder_stokenet_leia_skywalker()
+            .persona_data
+            .name
+            .unwrap()
+            .id;
+        let name = wallet
+            .set_persona_data_name(
+                address.clone(),
+                PersonaDataEntryName::placeholder(),
+            )
+            .unwrap();
+        assert_eq!(name.id, id);
+        assert_eq!(
+            wallet
+                .profile()
+                .unwrap()
+                .networks
+                .get_persona(&address)
+                .unwrap()
+                .persona_data
+                .name,
+            Some(name)
+        );
+    }
+
+    #[test]
+    fn add_update_remove_email_address() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = IdentityAddress::placeholder_stokenet();
+        let mut entry = wallet
+            .add_persona_data_email_address(
+                address.clone(),
+                PersonaDataEntryEmailAddress::placeholder(),
+            )
+            .unwrap();
+        entry.value = PersonaDataEntryEmailAddress::placeholder_other();
+        wallet
+            .update_persona_data_email_address(address.clone(), entry.clone())
+            .unwrap();
+        let emails = || {
+            wallet
+                .profile()
+                .unwrap()
+                .networks
+                .get_persona(&address)
+                .unwrap()
+                .persona_data
+                .email_addresses
+        };
+        assert_eq!(emails().get(&entry.id), Some(&entry));
+        wallet
+            .remove_persona_data_entry(
+                address.clone(),
+                PersonaDataEntryKind::EmailAddress,
+                entry.id.clone(),
+            )
+            .unwrap();
+        assert!(!emails().contains_id(&entry.id));
+    }
+
+    #[test]
+    fn add_and_update_phone_number() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = IdentityAddress::placeholder_stokenet();
+        let mut entry = wallet
+            .add_persona_data_phone_number(
+                address.clone(),
+                PersonaDataEntryPhoneNumber::placeholder(),
+            )
+            .unwrap();
+        entry.value = PersonaDataEntryPhoneNumber::placeholder_other();
+        wallet
+            .update_persona_data_phone_number(address.clone(), entry.clone())
+            .unwrap();
+        assert_eq!(
+            wallet
+                .profile()
+                .unwrap()
+                .networks
+                .get_persona(&address)
+                .unwrap()
+                .persona_data
+                .phone_numbers
+                .get(&entry.id),
+            Some(&entry)
+        );
+    }
+
+    #[test]
+    fn add_invalid_email_address() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.add_persona_data_email_address(
+                IdentityAddress::placeholder_stokenet(),
+                PersonaDataEntryEmailAddress {
+                    email: "".to_owned()
+                },
+            ),
+            Err(CommonError::PersonaDataInvalidEmailAddressEmpty)
+        );
+    }
+
+    #[test]
+    fn add_persona_data_postal_address_then_remove() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = IdentityAddress::placeholder_stokenet();
+        let entry = wallet
+            .add_persona_data_postal_address(
+                address.clone(),
+                PersonaDataEntryPostalAddress::placeholder(),
+            )
+            .unwrap();
+        let persona_data = |w: &Wallet| {
+            w.profile()
+                .unwrap()
+                .networks
+                .get_persona(&address)
+                .unwrap()
+                .persona_data
+        };
+        assert!(persona_data(&wallet)
+            .postal_addresses
+            .contains_id(&entry.id));
+        wallet
+            .remove_persona_data_entry(
+                address.clone(),
+                PersonaDataEntryKind::PostalAddress,
+                entry.id.clone(),
+            )
+            .unwrap();
+        assert!(persona_data(&wallet).postal_addresses.is_empty());
+    }
+}
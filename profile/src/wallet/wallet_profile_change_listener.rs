@@ -0,0 +1,191 @@
+use crate::prelude::*;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    PoisonError, RwLock,
+};
+
+/// A listener implemented by the host, notified by the Wallet with the
+/// changes made to Profile after each successful update of it.
+#[uniffi::export]
+pub trait ProfileChangeListener: Send + Sync + std::fmt::Debug {
+    /// Called with the typed changes of a single update, in the order
+    /// described in [`Profile::change_events`], never with an empty list.
+    fn on_profile_changed(&self, events: Vec<ProfileChangeEvent>);
+}
+
+/// A handle to a subscription of a [`ProfileChangeListener`], used to
+/// unsubscribe it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ProfileChangeSubscription {
+    pub id: u64,
+}
+
+/// The listeners subscribed to changes of the Profile of a Wallet.
+#[derive(Debug, Default)]
+pub(crate) struct ProfileChangeListeners {
+    next_id: AtomicU64,
+    listeners: RwLock<
+        Vec<(ProfileChangeSubscription, Arc<dyn ProfileChangeListener>)>,
+    >,
+}
+
+impl ProfileChangeListeners {
+    pub(crate) fn subscribe(
+        &self,
+        listener: Arc<dyn ProfileChangeListener>,
+    ) -> ProfileChangeSubscription {
+        let subscription = ProfileChangeSubscription {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+        };
+        self.listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((subscription, listener));
+        subscription
+    }
+
+    pub(crate) fn unsubscribe(
+        &self,
+        subscription: ProfileChangeSubscription,
+    ) -> bool {
+        let mut listeners = self
+            .listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let count = listeners.len();
+        listeners.retain(|(s, _)| *s != subscription);
+        listeners.len() != count
+    }
+
+    /// Notifies all listeners of `events`, if any. The listeners are called
+    /// without holding the lock, so that they can unsubscribe themselves.
+    pub(crate) fn notify(&self, events: Vec<ProfileChangeEvent>) {
+        if events.is_empty() {
+            return;
+        }
+        let listeners = self
+            .listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(_, l)| l.clone())
+            .collect_vec();
+        for listener in listeners {
+            listener.on_profile_changed(events.clone());
+        }
+    }
+}
+
+//========
+// SUBSCRIBE - Profile Changes
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Subscribes `listener` to the changes made to Profile, returning a
+    /// handle used to unsubscribe it.
+    pub fn subscribe_to_profile_changes(
+        &self,
+        listener: Arc<dyn ProfileChangeListener>,
+    ) -> ProfileChangeSubscription {
+        self.profile_change_listeners.subscribe(listener)
+    }
+
+    /// Unsubscribes the listener of `subscription`, returns `false` if it
+    /// was not subscribed.
+    pub fn unsubscribe_from_profile_changes(
+        &self,
+        subscription: ProfileChangeSubscription,
+    ) -> bool {
+        self.profile_change_listeners.unsubscribe(subscription)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingListener {
+        received: Mutex<Vec<Vec<ProfileChangeEvent>>>,
+    }
+
+    impl RecordingListener {
+        fn received(&self) -> Vec<Vec<ProfileChangeEvent>> {
+            self.received.lock().unwrap().clone()
+        }
+    }
+
+    impl ProfileChangeListener for RecordingListener {
+        fn on_profile_changed(&self, events: Vec<ProfileChangeEvent>) {
+            self.received.lock().unwrap().push(events)
+        }
+    }
+
+    #[test]
+    fn listener_is_notified_of_rename() {
+        let wallet = Wallet::placeholder();
+        let listener = Arc::new(RecordingListener::default());
+        wallet.subscribe_to_profile_changes(listener.clone());
+
+        let account = wallet.profile().unwrap().networks[0].accounts[0].clone();
+        let name = DisplayName::new("Renamed").unwrap();
+        wallet
+            .change_name_of_account(account.address.clone(), name.clone())
+            .unwrap();
+
+        assert_eq!(
+            listener.received(),
+            vec![vec![ProfileChangeEvent::AccountRenamed {
+                address: account.address,
+                display_name: name
+            }]]
+        );
+    }
+
+    #[test]
+    fn listener_is_not_notified_without_changes() {
+        let wallet = Wallet::placeholder();
+        let listener = Arc::new(RecordingListener::default());
+        wallet.subscribe_to_profile_changes(listener.clone());
+
+        let account = wallet.profile().unwrap().networks[0].accounts[0].clone();
+        wallet
+            .change_name_of_account(
+                account.address.clone(),
+                account.display_name.clone(),
+            )
+            .unwrap();
+        assert!(wallet
+            .add_factor_source(
+                wallet.profile().unwrap().factor_sources[0].clone()
+            )
+            .is_err());
+
+        assert!(listener.received().is_empty());
+    }
+
+    #[test]
+    fn unsubscribed_listener_is_not_notified() {
+        let wallet = Wallet::placeholder();
+        let listener = Arc::new(RecordingListener::default());
+        let other = Arc::new(RecordingListener::default());
+        let subscription =
+            wallet.subscribe_to_profile_changes(listener.clone());
+        wallet.subscribe_to_profile_changes(other.clone());
+
+        assert!(wallet.unsubscribe_from_profile_changes(subscription));
+        assert!(!wallet.unsubscribe_from_profile_changes(subscription));
+
+        let olympia = FactorSource::placeholder_device_olympia();
+        wallet.add_factor_source(olympia.clone()).unwrap();
+
+        assert!(listener.received().is_empty());
+        assert_eq!(
+            other.received(),
+            vec![vec![ProfileChangeEvent::FactorSourceAdded {
+                id: olympia.id()
+            }]]
+        );
+    }
+}
@@ -19,49 +19,14 @@ impl Wallet {
         self.wallet_client_storage
             .save(SecureStorageKey::ActiveProfileID, profile_id)
     }
-    pub(crate) fn save_active_profile_id_or_panic(
-        &self,
-        profile_id: &ProfileID,
-    ) {
-        match self.save_active_profile_id(profile_id) {
-            Ok(_) => log::info!(
-                "Successfully saved active ProfileID: {}",
-                profile_id
-            ),
-            Err(e) => fatal_error(format!(
-                "Failed to save active ProfileID: {}, error: {}",
-                profile_id, e
-            )),
-        }
-    }
-
-    pub(crate) fn save_existing_profile(&self) -> Result<()> {
-        self.save_profile(&self.profile())
-    }
 
-    pub(crate) fn save_profile_or_panic(&self, profile: &Profile) -> bool {
-        match self.save_profile(profile) {
-            Ok(_) => {
-                log::info!(
-                    "Successfully saved profile with ID: {}",
-                    profile.id()
-                );
-                true
-            }
-            Err(e) => {
-                fatal_error(format!(
-                    "Failed to save profile with ID: {}, error: {}",
-                    profile.id(),
-                    e
-                ));
-                false
-            }
-        }
-    }
-    pub(crate) fn save_new_profile_or_panic(&self, profile: &Profile) {
-        if self.save_profile_or_panic(profile) {
-            self.save_active_profile_id_or_panic(&profile.id());
-        }
+    /// Saves `profile` and sets it as the active Profile.
+    pub(crate) fn save_new_profile(&self, profile: &Profile) -> Result<()> {
+        self.save_profile(profile)?;
+        log::info!("Successfully saved profile with ID: {}", profile.id());
+        self.save_active_profile_id(&profile.id())?;
+        log::info!("Successfully saved active ProfileID: {}", profile.id());
+        Ok(())
     }
 }
 
@@ -69,11 +34,8 @@ impl Wallet {
 mod tests {
     use crate::prelude::*;
 
-    #[should_panic(
-        expected = "Fatal error: 'Failed to save active ProfileID: 12345678-bbbb-cccc-dddd-abcd12345678, error: Unknown Error'"
-    )]
     #[test]
-    fn save_active_profile_id_or_panic_fail() {
+    fn save_active_profile_id_fail() {
         #[derive(Debug)]
         struct FailSaveActiveProfileIDStorage {}
 
@@ -107,14 +69,19 @@ mod tests {
         }
         let storage = Arc::new(FailSaveActiveProfileIDStorage {});
 
-        _ = Wallet::by_importing_profile(Profile::placeholder(), storage);
+        assert_eq!(
+            Wallet::by_importing_profile(
+                Profile::placeholder(),
+                storage,
+                ProfileImportPolicy::Accept,
+            )
+            .map(|w| w.profile().unwrap()),
+            Err(CommonError::Unknown)
+        );
     }
 
-    #[should_panic(
-        expected = "Fatal error: 'Failed to save profile with ID: 12345678-bbbb-cccc-dddd-abcd12345678, error: Unknown Error'"
-    )]
     #[test]
-    fn save_profile_or_panic_fail() {
+    fn save_profile_fail() {
         #[derive(Debug)]
         struct FailSaveProfileStorage {}
 
@@ -148,12 +115,17 @@ mod tests {
         }
         let storage = Arc::new(FailSaveProfileStorage {});
 
-        _ = Wallet::by_importing_profile(Profile::placeholder(), storage);
+        assert_eq!(
+            Wallet::by_importing_profile(
+                Profile::placeholder(),
+                storage,
+                ProfileImportPolicy::Accept,
+            )
+            .map(|w| w.profile().unwrap()),
+            Err(CommonError::Unknown)
+        );
     }
 
-    #[should_panic(
-        expected = "Fatal error: 'Failed to save active ProfileID: ffffffff-ffff-ffff-ffff-ffffffffffff, error: Unknown Error'"
-    )]
     #[test]
     fn new_load_profile_with_id_fail() {
         #[derive(Debug)]
@@ -196,10 +168,13 @@ mod tests {
         }
         let storage = Arc::new(FailSaveActiveProfileIDStorage {});
 
-        _ = Wallet::by_loading_profile_with_id(
-            ProfileID::placeholder(),
-            storage,
-        )
-        .unwrap();
+        assert_eq!(
+            Wallet::by_loading_profile_with_id(
+                ProfileID::placeholder(),
+                storage,
+            )
+            .map(|w| w.profile().unwrap()),
+            Err(CommonError::Unknown)
+        );
     }
 }
@@ -0,0 +1,425 @@
+use crate::prelude::*;
+use std::{
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// When the Wallet saves Profile to secure storage after it has been
+/// updated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ProfilePersistencePolicy {
+    /// Profile is saved after every update.
+    #[default]
+    Immediate,
+
+    /// Profile is saved at most once every `interval_ms` milliseconds, an
+    /// update made sooner than that after the last save is only saved by a
+    /// later update, by `flush` or when the Wallet is dropped.
+    ///
+    /// No trailing save is ever scheduled after the last update, the host
+    /// **must** call `flush` for the last updates to be saved, e.g.
+    /// periodically and when the app moves to the background.
+    Throttled { interval_ms: u64 },
+
+    /// Profile is only saved by `flush` or when the Wallet is dropped.
+    Explicit,
+}
+
+#[derive(Debug, Default)]
+struct ProfilePersistenceState {
+    policy: ProfilePersistencePolicy,
+    is_dirty: bool,
+    last_saved_at: Option<Instant>,
+    batch_depth: usize,
+}
+
+/// Tracks when Profile should be saved and whether it has unsaved changes.
+#[derive(Debug, Default)]
+pub(crate) struct ProfilePersistence {
+    state: Mutex<ProfilePersistenceState>,
+}
+
+impl ProfilePersistence {
+    fn with_state<R>(
+        &self,
+        access: impl FnOnce(&mut ProfilePersistenceState) -> R,
+    ) -> R {
+        access(&mut self.state.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Whether an update should be saved right away, according to the
+    /// policy and any ongoing batch.
+    pub(crate) fn should_save_now(&self) -> bool {
+        self.with_state(|s| {
+            if s.batch_depth > 0 {
+                return false;
+            }
+            match s.policy {
+                ProfilePersistencePolicy::Immediate => true,
+                ProfilePersistencePolicy::Explicit => false,
+                ProfilePersistencePolicy::Throttled { interval_ms } => {
+                    s.last_saved_at.map_or(true, |t| {
+                        t.elapsed() >= Duration::from_millis(interval_ms)
+                    })
+                }
+            }
+        })
+    }
+
+    pub(crate) fn mark_saved(&self) {
+        self.with_state(|s| {
+            s.is_dirty = false;
+            s.last_saved_at = Some(Instant::now());
+        })
+    }
+
+    pub(crate) fn mark_dirty(&self) {
+        self.with_state(|s| s.is_dirty = true)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.with_state(|s| s.is_dirty)
+    }
+
+    fn policy(&self) -> ProfilePersistencePolicy {
+        self.with_state(|s| s.policy)
+    }
+
+    fn set_policy(&self, policy: ProfilePersistencePolicy) {
+        self.with_state(|s| s.policy = policy)
+    }
+
+    fn begin_batch(&self) -> ProfilePersistenceBatch<'_> {
+        self.with_state(|s| s.batch_depth += 1);
+        ProfilePersistenceBatch {
+            persistence: Some(self),
+        }
+    }
+
+    /// Returns `true` if the outermost batch ended.
+    fn end_batch(&self) -> bool {
+        self.with_state(|s| {
+            s.batch_depth = s.batch_depth.saturating_sub(1);
+            s.batch_depth == 0
+        })
+    }
+}
+
+/// An ongoing batch, which is ended when dropped, so that a panic during the
+/// batch does not defer saving for good.
+struct ProfilePersistenceBatch<'a> {
+    persistence: Option<&'a ProfilePersistence>,
+}
+
+impl ProfilePersistenceBatch<'_> {
+    /// Ends the batch, returns `true` if it was the outermost batch.
+    fn end(mut self) -> bool {
+        self.persistence
+            .take()
+            .map_or(false, ProfilePersistence::end_batch)
+    }
+}
+
+impl Drop for ProfilePersistenceBatch<'_> {
+    fn drop(&mut self) {
+        if let Some(persistence) = self.persistence.take() {
+            persistence.end_batch();
+        }
+    }
+}
+
+//========
+// SET - Persistence
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Changes when Profile is saved to secure storage, changing to
+    /// `Immediate` saves any unsaved changes.
+    pub fn set_persistence_policy(
+        &self,
+        policy: ProfilePersistencePolicy,
+    ) -> Result<()> {
+        self.persistence.set_policy(policy);
+        if policy == ProfilePersistencePolicy::Immediate {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn persistence_policy(&self) -> ProfilePersistencePolicy {
+        self.persistence.policy()
+    }
+
+    /// Whether Profile has been updated since it was last saved.
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.persistence.is_dirty()
+    }
+
+    /// Saves Profile to secure storage if it has unsaved changes.
+    pub fn flush(&self) -> Result<()> {
+        // The read lock is held while saving, so that no update can be
+        // marked as saved without having been saved.
+        self.access_profile_with(|p| {
+            if !self.persistence.is_dirty() {
+                return Ok(());
+            }
+            self.save_profile(p)?;
+            self.persistence.mark_saved();
+            Ok(())
+        })?
+    }
+}
+
+impl Wallet {
+    /// Performs all updates made to Profile in `updates` with a single save,
+    /// done once `updates` returns, also if it fails, unless the persistence
+    /// policy is `Explicit`. Batches can be nested, only the outermost batch
+    /// saves.
+    ///
+    /// The updates are not atomic, if `updates` fails the updates made
+    /// before the failure are kept.
+    ///
+    /// The batch applies to the whole Wallet, not only to the calling
+    /// thread, so updates made by other threads while the batch is ongoing
+    /// are also only saved when it ends.
+    ///
+    /// If saving fails its error is returned, even if `updates` succeeded,
+    /// in which case Profile in memory keeps the updates and has unsaved
+    /// changes, see [`Wallet::has_unsaved_changes`], so that saving can be
+    /// retried with [`Wallet::flush`].
+    ///
+    /// This is not exported to the hosts, since UniFFI cannot export a
+    /// function taking a closure, hosts batch updates using the `Explicit`
+    /// policy followed by [`Wallet::flush`] instead.
+    pub fn batch_update<F, R>(&self, updates: F) -> Result<R>
+    where
+        F: FnOnce(&Self) -> Result<R>,
+    {
+        let batch = self.persistence.begin_batch();
+        let res = updates(self);
+        if batch.end()
+            && self.persistence.policy() != ProfilePersistencePolicy::Explicit
+        {
+            self.flush()?;
+        }
+        res
+    }
+}
+
+impl Drop for Wallet {
+    fn drop(&mut self) {
+        if !self.persistence.is_dirty() {
+            return;
+        }
+        if let Err(e) = self.flush() {
+            error!("Failed to save unsaved changes of Profile: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts the number of times Profile is saved.
+    #[derive(Debug)]
+    struct CountingStorage {
+        storage: Arc<EphemeralSecureStorage>,
+        profile_saves: AtomicUsize,
+    }
+
+    impl CountingStorage {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                storage: EphemeralSecureStorage::new(),
+                profile_saves: AtomicUsize::new(0),
+            })
+        }
+
+        fn profile_saves(&self) -> usize {
+            self.profile_saves.load(Ordering::SeqCst)
+        }
+
+        fn saved_profile(&self, id: ProfileID) -> Profile {
+            let data = self
+                .storage
+                .load_data(SecureStorageKey::ProfileSnapshot { profile_id: id })
+                .unwrap()
+                .unwrap();
+            serde_json::from_slice(&data).unwrap()
+        }
+    }
+
+    impl SecureStorage for CountingStorage {
+        fn load_data(&self, key: SecureStorageKey) -> Result<Option<Vec<u8>>> {
+            self.storage.load_data(key)
+        }
+
+        fn save_data(
+            &self,
+            key: SecureStorageKey,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            if let SecureStorageKey::ProfileSnapshot { profile_id: _ } = key {
+                self.profile_saves.fetch_add(1, Ordering::SeqCst);
+            }
+            self.storage.save_data(key, data)
+        }
+
+        fn delete_data_for_key(&self, key: SecureStorageKey) -> Result<()> {
+            self.storage.delete_data_for_key(key)
+        }
+    }
+
+    fn wallet() -> (Wallet, Arc<CountingStorage>) {
+        let storage = CountingStorage::new();
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+            ProfileImportPolicy::Accept,
+        )
+        .unwrap();
+        (wallet, storage)
+    }
+
+    fn rename_first_account(wallet: &Wallet, name: &str) -> Result<Account> {
+        let address = wallet.profile()?.networks[0].accounts[0].address.clone();
+        wallet.change_name_of_account(address, DisplayName::new(name)?)
+    }
+
+    fn first_account_name(profile: &Profile) -> String {
+        profile.networks[0].accounts[0].display_name.value.clone()
+    }
+
+    #[test]
+    fn immediate_saves_every_update() {
+        let (wallet, storage) = wallet();
+        rename_first_account(&wallet, "One").unwrap();
+        rename_first_account(&wallet, "Two").unwrap();
+        assert_eq!(storage.profile_saves(), 3); // import + 2 updates
+        assert!(!wallet.has_unsaved_changes());
+    }
+
+    #[test]
+    fn explicit_saves_on_flush() {
+        let (wallet, storage) = wallet();
+        let id = wallet.profile().unwrap().id();
+        wallet
+            .set_persistence_policy(ProfilePersistencePolicy::Explicit)
+            .unwrap();
+        rename_first_account(&wallet, "One").unwrap();
+        rename_first_account(&wallet, "Two").unwrap();
+        assert!(wallet.has_unsaved_changes());
+        assert_eq!(
+            first_account_name(&storage.saved_profile(id.clone())),
+            "Alice"
+        );
+
+        wallet.flush().unwrap();
+        assert!(!wallet.has_unsaved_changes());
+        assert_eq!(first_account_name(&storage.saved_profile(id)), "Two");
+        assert_eq!(storage.profile_saves(), 2);
+
+        // Nothing to flush
+        wallet.flush().unwrap();
+        assert_eq!(storage.profile_saves(), 2);
+    }
+
+    #[test]
+    fn throttled_saves_at_most_once_per_interval() {
+        let (wallet, storage) = wallet();
+        let id = wallet.profile().unwrap().id();
+        wallet
+            .set_persistence_policy(ProfilePersistencePolicy::Throttled {
+                interval_ms: 60_000,
+            })
+            .unwrap();
+        rename_first_account(&wallet, "One").unwrap();
+        rename_first_account(&wallet, "Two").unwrap();
+        rename_first_account(&wallet, "Three").unwrap();
+        assert_eq!(storage.profile_saves(), 2); // import + first update
+        assert!(wallet.has_unsaved_changes());
+
+        // No trailing save, the last updates are saved by `flush`.
+        wallet.flush().unwrap();
+        assert_eq!(storage.profile_saves(), 3);
+        assert_eq!(first_account_name(&storage.saved_profile(id)), "Three");
+    }
+
+    #[test]
+    fn batch_update_saves_once() {
+        let (wallet, storage) = wallet();
+        let id = wallet.profile().unwrap().id();
+        wallet
+            .batch_update(|w| {
+                rename_first_account(w, "One")?;
+                w.batch_update(|w| rename_first_account(w, "Two"))?;
+                w.hide_account(
+                    w.profile()?.networks[0].accounts[1].address.clone(),
+                )
+            })
+            .unwrap();
+        assert_eq!(storage.profile_saves(), 2); // import + batch
+        assert!(!wallet.has_unsaved_changes());
+        assert_eq!(first_account_name(&storage.saved_profile(id)), "Two");
+    }
+
+    #[test]
+    fn failing_batch_saves_updates_made_before_failure() {
+        let (wallet, storage) = wallet();
+        let id = wallet.profile().unwrap().id();
+        assert_eq!(
+            wallet.batch_update(|w| {
+                rename_first_account(w, "One")?;
+                w.hide_account(Account::placeholder_nebunet().address)
+            }),
+            Err(CommonError::UnknownAccount)
+        );
+        assert_eq!(first_account_name(&storage.saved_profile(id)), "One");
+    }
+
+    #[test]
+    fn panicking_batch_ends_batch() {
+        let (wallet, storage) = wallet();
+        let res =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                wallet.batch_update(|w| -> Result<()> {
+                    rename_first_account(w, "One")?;
+                    panic!("Batch panicked")
+                })
+            }));
+        assert!(res.is_err());
+        assert!(wallet.has_unsaved_changes());
+
+        rename_first_account(&wallet, "Two").unwrap();
+        assert_eq!(storage.profile_saves(), 2); // import + update
+        assert!(!wallet.has_unsaved_changes());
+    }
+
+    #[test]
+    fn unsaved_changes_are_saved_on_drop() {
+        let (wallet, storage) = wallet();
+        let id = wallet.profile().unwrap().id();
+        wallet
+            .set_persistence_policy(ProfilePersistencePolicy::Explicit)
+            .unwrap();
+        rename_first_account(&wallet, "One").unwrap();
+        drop(wallet);
+        assert_eq!(first_account_name(&storage.saved_profile(id)), "One");
+    }
+
+    #[test]
+    fn changing_to_immediate_flushes() {
+        let (wallet, storage) = wallet();
+        let id = wallet.profile().unwrap().id();
+        wallet
+            .set_persistence_policy(ProfilePersistencePolicy::Explicit)
+            .unwrap();
+        rename_first_account(&wallet, "One").unwrap();
+        wallet
+            .set_persistence_policy(ProfilePersistencePolicy::Immediate)
+            .unwrap();
+        assert_eq!(first_account_name(&storage.saved_profile(id)), "One");
+    }
+}
@@ -0,0 +1,175 @@
+use crate::prelude::*;
+
+//========
+// GET - Transactions
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Statically analyzes `manifest` against the active Profile, see
+    /// [`Profile::analyze_manifest`].
+    pub fn analyze_manifest(
+        &self,
+        manifest: TransactionManifest,
+    ) -> Result<ManifestSummary> {
+        self.access_profile_with(|p| p.analyze_manifest(&manifest))?
+    }
+
+    /// Adds worktop assertions to `manifest` guaranteeing each deposit in
+    /// `deposits`, see [`Profile::manifest_adding_guarantees`].
+    pub fn manifest_adding_guarantees(
+        &self,
+        manifest: TransactionManifest,
+        deposits: Vec<EstimatedDeposit>,
+    ) -> Result<TransactionManifest> {
+        self.access_profile_with(|p| {
+            p.manifest_adding_guarantees(&manifest, deposits.clone())
+        })?
+    }
+
+    /// Selects which of the `candidates` pays the fee of `manifest` and adds
+    /// an instruction locking the fee, see [`Profile::select_fee_payer`].
+    pub fn select_fee_payer(
+        &self,
+        manifest: TransactionManifest,
+        fee_estimate: Decimal,
+        margin: Decimal,
+        candidates: Vec<FeePayerCandidate>,
+    ) -> Result<FeePayerSelectionResult> {
+        self.access_profile_with(|p| {
+            p.select_fee_payer(
+                &manifest,
+                &fee_estimate,
+                &margin,
+                candidates.clone(),
+            )
+        })?
+    }
+
+    /// Creates the manifest securifying the account at `address` in the
+    /// active Profile, see [`TransactionManifest::securify_account`].
+    pub fn manifest_securify_account(
+        &self,
+        address: AccountAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<TransactionManifest> {
+        self.access_profile_with(|p| {
+            p.networks
+                .get_account(&address)
+                .ok_or(CommonError::UnknownAccount)
+                .and_then(|a| {
+                    TransactionManifest::securify_account(
+                        &a,
+                        security_structure.clone(),
+                    )
+                })
+        })?
+    }
+
+    /// Creates the manifest securifying the persona at `address` in the
+    /// active Profile, see [`TransactionManifest::securify_persona`].
+    pub fn manifest_securify_persona(
+        &self,
+        address: IdentityAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<TransactionManifest> {
+        self.access_profile_with(|p| {
+            p.networks
+                .get_persona(&address)
+                .ok_or(CommonError::UnknownPersona)
+                .and_then(|persona| {
+                    TransactionManifest::securify_persona(
+                        &persona,
+                        security_structure.clone(),
+                    )
+                })
+        })?
+    }
+}
+
+//========
+// SET - Securified Entities
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Marks the account at `address` as securified once the transaction
+    /// of [`Wallet::manifest_securify_account`] has been committed, and saves
+    /// the Profile.
+    pub fn mark_account_securified(
+        &self,
+        address: AccountAddress,
+        access_controller_address: AccessControllerAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<Account> {
+        self.try_update_profile_with(|p| {
+            p.mark_account_securified(
+                &address,
+                access_controller_address.clone(),
+                security_structure.clone(),
+            )
+        })
+    }
+
+    /// Marks the persona at `address` as securified once the transaction
+    /// of [`Wallet::manifest_securify_persona`] has been committed, and saves
+    /// the Profile.
+    pub fn mark_persona_securified(
+        &self,
+        address: IdentityAddress,
+        access_controller_address: AccessControllerAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<Persona> {
+        self.try_update_profile_with(|p| {
+            p.mark_persona_securified(
+                &address,
+                access_controller_address.clone(),
+                security_structure.clone(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn analyze_manifest_flags_profile_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = AccountAddress::placeholder_stokenet();
+        let manifest = TransactionManifest::set_account_metadata(
+            &address,
+            "name",
+            MetadataValue::Str {
+                value: "Carol".to_owned(),
+            },
+        )
+        .unwrap();
+        let summary = wallet.analyze_manifest(manifest).unwrap();
+        assert!(summary.account(&address).unwrap().is_controlled_by_user);
+    }
+
+    #[test]
+    fn securify_persona_and_mark_securified() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let address = Persona::placeholder_stokenet().address;
+        let structure = SecurityStructureOfFactorInstances::placeholder();
+
+        let manifest = wallet
+            .manifest_securify_persona(address.clone(), structure.clone())
+            .unwrap();
+        assert!(manifest.instructions.contains("CREATE_ACCESS_CONTROLLER"));
+
+        let persona = wallet
+            .mark_persona_securified(
+                address.clone(),
+                AccessControllerAddress::placeholder_stokenet(),
+                structure.clone(),
+            )
+            .unwrap();
+        assert!(persona.security_state.is_securified());
+        assert_eq!(
+            wallet.manifest_securify_persona(address, structure),
+            Err(CommonError::EntityAlreadySecurified)
+        );
+    }
+}
@@ -304,4 +304,181 @@ pub enum CommonError {
 
     #[error("Invalid UUID (v4), got: {0}")]
     InvalidUUIDv4(String) = 10086,
+
+    #[error("Wallet interaction on wrong network, expected: {expected}, found: {found}")]
+    WalletInteractionNetworkMismatch {
+        expected: NetworkID,
+        found: NetworkID,
+    } = 10087,
+
+    #[error("Wallet interaction from unknown dApp: {0}")]
+    WalletInteractionUnknownDapp(DappDefinitionAddress) = 10088,
+
+    #[error("Wallet interaction references unknown Persona: {0}")]
+    WalletInteractionUnknownPersona(IdentityAddress) = 10089,
+
+    #[error("Wallet interaction uses Persona {0} not authorized by dApp")]
+    WalletInteractionPersonaNotAuthorizedByDapp(IdentityAddress) = 10090,
+
+    #[error("Wallet interaction contains invalid requested quantity: {0}")]
+    WalletInteractionInvalidRequestedQuantity(RequestedQuantity) = 10091,
+
+    #[error("Wallet interaction requests {requested} accounts, but only {available} available")]
+    WalletInteractionInsufficientAccounts {
+        requested: RequestedQuantity,
+        available: usize,
+    } = 10092,
+
+    #[error("Wallet interaction requests PersonaData which Persona does not have: {0}")]
+    WalletInteractionInsufficientPersonaData(String) = 10093,
+
+    #[error("Wallet interaction response is missing a Persona")]
+    WalletInteractionResponseMissingPersona = 10094,
+
+    #[error("Wallet interaction response does not fulfill request: {0}")]
+    WalletInteractionResponseDoesNotFulfillRequest(String) = 10095,
+
+    #[error("Unknown persona.")]
+    UnknownPersona = 10096,
+
+    #[error("Unknown PersonaData entry: {0}")]
+    UnknownPersonaDataEntry(PersonaDataEntryID) = 10097,
+
+    #[error("Unknown authorized dApp: {0}")]
+    UnknownAuthorizedDapp(DappDefinitionAddress) = 10098,
+
+    #[error("Persona {0} has not been used with dApp")]
+    PersonaNotAuthorizedByDapp(IdentityAddress) = 10099,
+
+    #[error("Invalid PersonaData - company name empty")]
+    PersonaDataInvalidCompanyNameEmpty = 10100,
+
+    #[error("Invalid PersonaData - invalid URL: {0}")]
+    PersonaDataInvalidUrl(String) = 10101,
+
+    #[error("Invalid PersonaData - date of birth is in the future")]
+    PersonaDataInvalidDateOfBirthInFuture = 10102,
+
+    #[error("Invalid PersonaData - postal address missing field: {0}")]
+    PersonaDataInvalidPostalAddressMissingField(PostalAddressFieldKind) = 10103,
+
+    #[error("Invalid PersonaData - postal address field {field} not used in {country}")]
+    PersonaDataInvalidPostalAddressUnsupportedField {
+        field: PostalAddressFieldKind,
+        country: PostalAddressCountry,
+    } = 10104,
+
+    #[error("Invalid transaction manifest: {0}")]
+    InvalidTransactionManifest(String) = 10105,
+
+    #[error("Address in manifest on wrong network, expected: {expected}, found: {found}")]
+    ManifestAddressOnWrongNetwork {
+        expected: NetworkID,
+        found: NetworkID,
+    } = 10106,
+
+    #[error("The faucet is not available on Mainnet")]
+    FaucetNotAvailableOnMainnet = 10107,
+
+    #[error("Invalid deposit guarantee, must be between 0 and 1, found: {0}")]
+    InvalidDepositGuarantee(Decimal) = 10108,
+
+    #[error("Instruction index {index} out of bounds, manifest has {count} instructions")]
+    ManifestInstructionIndexOutOfBounds { index: u64, count: u64 } = 10109,
+
+    #[error("Failed to decode bech32 intent hash: {0}")]
+    InvalidIntentHash(String) = 10110,
+
+    #[error("Failed to decode bech32 signed intent hash: {0}")]
+    InvalidSignedIntentHash(String) = 10111,
+
+    #[error("Network of transaction header: {header} does not match network of manifest: {manifest}")]
+    TransactionNetworkMismatch {
+        header: NetworkID,
+        manifest: NetworkID,
+    } = 10112,
+
+    #[error("Invalid epoch window, start (inclusive): {start}, end (exclusive): {end}")]
+    InvalidEpochWindow { start: u64, end: u64 } = 10113,
+
+    #[error("Invalid transaction intent: {0}")]
+    InvalidTransactionIntent(String) = 10114,
+
+    #[error("Notary public key of transaction header does not match the key of the notary")]
+    NotaryPublicKeyMismatch = 10115,
+
+    #[error("Invalid fee margin, must not be negative, found: {0}")]
+    InvalidFeeMargin(Decimal) = 10116,
+
+    #[error("No staking operations to build a manifest from")]
+    EmptyStakingOperations = 10117,
+
+    #[error("Entity is already securified by an AccessController")]
+    EntityAlreadySecurified = 10118,
+
+    #[error("Invalid security structure: {0}")]
+    InvalidSecurityStructure(String) = 10119,
+
+    #[error("Entity is not securified by an AccessController")]
+    EntityNotSecurified = 10120,
+
+    #[error("Gateway request failed with status code: {status_code}, message: {message}")]
+    GatewayRequestFailed { status_code: u16, message: String } = 10121,
+
+    #[error("Gateway is on network '{found}', expected '{expected}'")]
+    GatewayNetworkMismatch { expected: String, found: String } = 10122,
+
+    #[error("Profile failed validation with issue codes: {0}")]
+    ProfileFailedValidation(String) = 10123,
+
+    #[error("Cannot merge Profiles with different IDs, ours: '{ours}', theirs: '{theirs}'")]
+    ProfileMergeMismatchingIDs { ours: String, theirs: String } = 10124,
+
+    #[error("Unable to acquire read lock for Profile inside Wallet")]
+    UnableToAcquireReadLockForProfile = 10125,
+
+    #[error("Profile snapshot JSON does not conform to its schema at '{path}': {reason}")]
+    ProfileSnapshotSchemaViolation { path: String, reason: String } = 10126,
+
+    #[error(
+        "FactorSource with ID {0} is in use, controlling accounts or personas"
+    )]
+    FactorSourceInUse(FactorSourceID) = 10127,
+
+    #[error("FactorSource with ID {0} is the main one and cannot be removed")]
+    CannotRemoveMainFactorSource(FactorSourceID) = 10128,
+
+    #[error(
+        "Mnemonic is of FactorSource with ID {found}, expected {expected}"
+    )]
+    MnemonicDoesNotMatchFactorSource {
+        expected: FactorSourceIDFromHash,
+        found: FactorSourceIDFromHash,
+    } = 10129,
+
+    #[error("Invalid intent signature by public key {public_key}")]
+    InvalidIntentSignature { public_key: String } = 10130,
+
+    #[error("{shared} accounts shared with dApp, but {requested} requested")]
+    SharedAccountsDoNotFulfillRequest {
+        requested: RequestedQuantity,
+        shared: usize,
+    } = 10131,
+
+    #[error("Invalid divisibility {0}, must be at most 18")]
+    InvalidDivisibility(u8) = 10132,
+
+    #[error("Invalid fee estimate, must not be negative, found: {0}")]
+    InvalidFeeEstimate(Decimal) = 10133,
+
+    #[error("Gateway returned the page cursor '{0}' more than once")]
+    GatewayRepeatedPageCursor(String) = 10134,
+
+    #[error(
+        "{shared} PersonaData entries shared with dApp, but {requested} requested"
+    )]
+    SharedPersonaDataDoesNotFulfillRequest {
+        requested: RequestedQuantity,
+        shared: usize,
+    } = 10135,
 }
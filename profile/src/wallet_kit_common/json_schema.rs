@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// The schema of a JSON string, optionally of a known `format` and with a
+/// fixed length of `length` characters.
+pub(crate) fn string_json_schema(
+    format: Option<&str>,
+    length: Option<u32>,
+) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        format: format.map(str::to_owned),
+        string: Some(Box::new(StringValidation {
+            min_length: length,
+            max_length: length,
+            pattern: None,
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// The schema of a string of `byte_count` bytes hex encoded.
+pub(crate) fn hex_json_schema(byte_count: u32) -> Schema {
+    string_json_schema(None, Some(byte_count * 2))
+}
+
+/// The schema of a `Uuid`, used with `#[schemars(schema_with)]`.
+pub(crate) fn uuid_json_schema(_: &mut SchemaGenerator) -> Schema {
+    string_json_schema(Some("uuid"), None)
+}
+
+/// The schema of a `Timestamp`, used with `#[schemars(schema_with)]`.
+pub(crate) fn timestamp_json_schema(_: &mut SchemaGenerator) -> Schema {
+    string_json_schema(Some("date-time"), None)
+}
+
+/// The schema of a `Url`, used with `#[schemars(schema_with)]`.
+pub(crate) fn url_json_schema(_: &mut SchemaGenerator) -> Schema {
+    string_json_schema(Some("uri"), None)
+}
+
+/// The schema of a JSON object with exactly the `properties`, all of them
+/// required.
+pub(crate) fn object_json_schema<'a>(
+    properties: impl IntoIterator<Item = (&'a str, Schema)>,
+) -> Schema {
+    let mut object = ObjectValidation::default();
+    for (key, schema) in properties {
+        object.required.insert(key.to_owned());
+        object.properties.insert(key.to_owned(), schema);
+    }
+    SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        object: Some(Box::new(object)),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// The schema of a JSON value which MUST be equal to `value`.
+pub(crate) fn const_json_schema(value: impl Serialize) -> Schema {
+    SchemaObject {
+        const_value: Some(json!(value)),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// The schema of an enum serialized with a `"discriminator"` property next
+/// to a property holding the value of the variant, as done by the custom
+/// serde implementations of e.g. `FactorSource`. Each variant is given by
+/// its discriminant, the key of its value and the schema of its value.
+pub(crate) fn discriminated_union_json_schema<'a>(
+    variants: impl IntoIterator<Item = (&'a str, &'a str, Schema)>,
+) -> Schema {
+    one_of_json_schema(variants.into_iter().map(
+        |(discriminant, key, schema)| {
+            object_json_schema([
+                ("discriminator", const_json_schema(discriminant)),
+                (key, schema),
+            ])
+        },
+    ))
+}
+
+/// The schema of a JSON value matching exactly one of `schemas`.
+pub(crate) fn one_of_json_schema(
+    schemas: impl IntoIterator<Item = Schema>,
+) -> Schema {
+    SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            one_of: Some(schemas.into_iter().collect()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn hex_is_string_of_fixed_length() {
+        assert_eq!(
+            json!(hex_json_schema(32)),
+            json!({ "type": "string", "maxLength": 64, "minLength": 64 })
+        );
+    }
+
+    #[test]
+    fn discriminated_union() {
+        assert_eq!(
+            json!(discriminated_union_json_schema([(
+                "unsecured",
+                "unsecuredEntityControl",
+                const_json_schema(1)
+            )])),
+            json!({
+                "oneOf": [{
+                    "type": "object",
+                    "required": ["discriminator", "unsecuredEntityControl"],
+                    "properties": {
+                        "discriminator": { "const": "unsecured" },
+                        "unsecuredEntityControl": { "const": 1 }
+                    }
+                }]
+            })
+        );
+    }
+}
@@ -0,0 +1,983 @@
+use crate::prelude::*;
+use serde_json::Value;
+
+/// A place where a JSON value does not conform to a JSON Schema.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct JsonSchemaViolation {
+    /// A JSON Pointer (RFC 6901) to the offending value, e.g.
+    /// `/networks/0/accounts/1/displayName`, empty for the root value.
+    pub path: String,
+
+    /// Why the value does not conform, e.g. `expected string, found number`.
+    pub reason: String,
+}
+
+impl JsonSchemaViolation {
+    fn new(path: &str, reason: impl Into<String>) -> Self {
+        Self {
+            path: path.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Validates JSON values against a JSON Schema generated by `schemars`.
+///
+/// Supports the keywords `$ref`, `type`, `format`, `enum`, `const`, `allOf`,
+/// `anyOf`, `oneOf`, `not`, `if`/`then`/`else`, `properties`, `required`,
+/// `additionalProperties`, `propertyNames`, `minProperties`,
+/// `maxProperties`, `items`, `additionalItems`, `minItems`, `maxItems`,
+/// `uniqueItems`, `contains`, `minLength`, `maxLength`, `minimum`,
+/// `maximum`, `exclusiveMinimum`, `exclusiveMaximum` and `multipleOf`.
+///
+/// The keywords `pattern` and `patternProperties` require regular
+/// expressions and are not supported, a schema using them is reported as a
+/// violation of every value rather than being silently ignored.
+pub(crate) struct JsonSchemaValidator<'a> {
+    root: &'a RootSchema,
+}
+
+impl<'a> JsonSchemaValidator<'a> {
+    pub(crate) fn new(root: &'a RootSchema) -> Self {
+        Self { root }
+    }
+
+    /// All violations of `value`, empty if it conforms to the schema.
+    pub(crate) fn violations(&self, value: &Value) -> Vec<JsonSchemaViolation> {
+        let mut violations = Vec::new();
+        self.validate_object(&self.root.schema, value, "", &mut violations);
+        violations
+    }
+
+    fn validate(
+        &self,
+        schema: &Schema,
+        value: &Value,
+        path: &str,
+        violations: &mut Vec<JsonSchemaViolation>,
+    ) {
+        match schema {
+            Schema::Bool(true) => {}
+            Schema::Bool(false) => violations
+                .push(JsonSchemaViolation::new(path, "no value is allowed")),
+            Schema::Object(object) => {
+                self.validate_object(object, value, path, violations)
+            }
+        }
+    }
+
+    fn validate_object(
+        &self,
+        schema: &SchemaObject,
+        value: &Value,
+        path: &str,
+        violations: &mut Vec<JsonSchemaViolation>,
+    ) {
+        for keyword in unsupported_keywords(schema) {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!("unsupported keyword '{}'", keyword),
+            ));
+        }
+
+        if let Some(reference) = &schema.reference {
+            match reference
+                .strip_prefix("#/definitions/")
+                .and_then(|name| self.root.definitions.get(name))
+            {
+                Some(definition) => {
+                    self.validate(definition, value, path, violations)
+                }
+                None => violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!("unresolvable reference '{}'", reference),
+                )),
+            }
+        }
+
+        if let Some(instance_type) = &schema.instance_type {
+            let allowed = match instance_type {
+                SingleOrVec::Single(t) => vec![t.as_ref().clone()],
+                SingleOrVec::Vec(ts) => ts.clone(),
+            };
+            if !allowed.iter().any(|t| is_of_type(value, t)) {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!(
+                        "expected {}, found {}",
+                        allowed.iter().map(type_name).join(" or "),
+                        value_type_name(value)
+                    ),
+                ));
+                return;
+            }
+        }
+
+        if let Some(values) = &schema.enum_values {
+            if !values.contains(value) {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!(
+                        "expected one of {}, found {}",
+                        values.iter().join(", "),
+                        value
+                    ),
+                ));
+            }
+        }
+
+        if let Some(expected) = &schema.const_value {
+            if expected != value {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!("expected {}, found {}", expected, value),
+                ));
+            }
+        }
+
+        if let Some(format) = &schema.format {
+            if let Some(reason) = format_violation(format, value) {
+                violations.push(JsonSchemaViolation::new(path, reason));
+            }
+        }
+
+        if let Some(subschemas) = &schema.subschemas {
+            self.validate_subschemas(subschemas, value, path, violations);
+        }
+
+        match value {
+            Value::Object(map) => {
+                if let Some(object) = &schema.object {
+                    self.validate_properties(object, map, path, violations);
+                }
+            }
+            Value::Array(items) => {
+                if let Some(array) = &schema.array {
+                    self.validate_items(array, items, path, violations);
+                }
+            }
+            Value::String(string) => {
+                if let Some(validation) = &schema.string {
+                    validate_length(validation, string, path, violations);
+                }
+            }
+            Value::Number(number) => {
+                if let Some(validation) = &schema.number {
+                    validate_range(validation, number, path, violations);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_subschemas(
+        &self,
+        subschemas: &SubschemaValidation,
+        value: &Value,
+        path: &str,
+        violations: &mut Vec<JsonSchemaViolation>,
+    ) {
+        if let Some(all_of) = &subschemas.all_of {
+            for schema in all_of {
+                self.validate(schema, value, path, violations);
+            }
+        }
+        if let Some(any_of) = &subschemas.any_of {
+            let failures = self.failures_of_each(any_of, value, path);
+            if failures.len() == any_of.len() {
+                violations.extend(closest_failure(failures));
+            }
+        }
+        if let Some(one_of) = &subschemas.one_of {
+            let failures = self.failures_of_each(one_of, value, path);
+            match one_of.len() - failures.len() {
+                0 => violations.extend(closest_failure(failures)),
+                1 => {}
+                n => violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!("expected exactly one match, found {}", n),
+                )),
+            }
+        }
+        if let Some(not) = &subschemas.not {
+            if self.conforms(not, value, path) {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    "expected no match of 'not'",
+                ));
+            }
+        }
+        if let Some(if_schema) = &subschemas.if_schema {
+            let branch = if self.conforms(if_schema, value, path) {
+                &subschemas.then_schema
+            } else {
+                &subschemas.else_schema
+            };
+            if let Some(schema) = branch {
+                self.validate(schema, value, path, violations);
+            }
+        }
+    }
+
+    /// Whether `value` conforms to `schema`.
+    fn conforms(&self, schema: &Schema, value: &Value, path: &str) -> bool {
+        let mut violations = Vec::new();
+        self.validate(schema, value, path, &mut violations);
+        violations.is_empty()
+    }
+
+    /// The violations of each schema in `schemas` which `value` does not
+    /// conform to.
+    fn failures_of_each(
+        &self,
+        schemas: &[Schema],
+        value: &Value,
+        path: &str,
+    ) -> Vec<Vec<JsonSchemaViolation>> {
+        schemas
+            .iter()
+            .map(|schema| {
+                let mut violations = Vec::new();
+                self.validate(schema, value, path, &mut violations);
+                violations
+            })
+            .filter(|violations| !violations.is_empty())
+            .collect()
+    }
+
+    fn validate_properties(
+        &self,
+        object: &ObjectValidation,
+        map: &serde_json::Map<String, Value>,
+        path: &str,
+        violations: &mut Vec<JsonSchemaViolation>,
+    ) {
+        if let Some(min) = object.min_properties {
+            if map.len() < min as usize {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!("expected at least {} properties", min),
+                ));
+            }
+        }
+        if let Some(max) = object.max_properties {
+            if map.len() > max as usize {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!("expected at most {} properties", max),
+                ));
+            }
+        }
+        for key in object.required.iter().filter(|k| !map.contains_key(*k)) {
+            violations.push(JsonSchemaViolation::new(
+                &child_path(path, key),
+                "missing required property",
+            ));
+        }
+        for (key, value) in map {
+            let path = child_path(path, key);
+            if let Some(schema) = &object.property_names {
+                let name = Value::String(key.clone());
+                self.validate(schema, &name, &path, violations);
+            }
+            match object.properties.get(key) {
+                Some(schema) => self.validate(schema, value, &path, violations),
+                None => {
+                    if let Some(schema) = &object.additional_properties {
+                        self.validate(schema, value, &path, violations)
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_items(
+        &self,
+        array: &ArrayValidation,
+        items: &[Value],
+        path: &str,
+        violations: &mut Vec<JsonSchemaViolation>,
+    ) {
+        if let Some(min) = array.min_items {
+            if items.len() < min as usize {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!("expected at least {} items", min),
+                ));
+            }
+        }
+        if let Some(max) = array.max_items {
+            if items.len() > max as usize {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    format!("expected at most {} items", max),
+                ));
+            }
+        }
+        if array.unique_items == Some(true) {
+            for (index, item) in items.iter().enumerate().skip(1) {
+                if items[..index].contains(item) {
+                    violations.push(JsonSchemaViolation::new(
+                        &child_path(path, &index.to_string()),
+                        format!(
+                            "expected unique items, found duplicate {}",
+                            item
+                        ),
+                    ));
+                }
+            }
+        }
+        if let Some(contains) = &array.contains {
+            if !items.iter().enumerate().any(|(index, item)| {
+                self.conforms(
+                    contains,
+                    item,
+                    &child_path(path, &index.to_string()),
+                )
+            }) {
+                violations.push(JsonSchemaViolation::new(
+                    path,
+                    "expected an item matching 'contains'",
+                ));
+            }
+        }
+        for (index, item) in items.iter().enumerate() {
+            let item_path = child_path(path, &index.to_string());
+            let schema = match &array.items {
+                Some(SingleOrVec::Single(schema)) => Some(schema.as_ref()),
+                Some(SingleOrVec::Vec(schemas)) => {
+                    schemas.get(index).or(array.additional_items.as_deref())
+                }
+                None => None,
+            };
+            if let Some(schema) = schema {
+                self.validate(schema, item, &item_path, violations);
+            }
+        }
+    }
+}
+
+/// The violations of the schema `value` got furthest into before failing,
+/// used to report precise violations when no schema of an `anyOf` or
+/// `oneOf` matches.
+fn closest_failure(
+    failures: Vec<Vec<JsonSchemaViolation>>,
+) -> Vec<JsonSchemaViolation> {
+    let depth = |violations: &Vec<JsonSchemaViolation>| {
+        violations
+            .iter()
+            .map(|v| v.path.matches('/').count())
+            .max()
+            .unwrap_or_default()
+    };
+    failures
+        .into_iter()
+        .max_by(|lhs, rhs| {
+            depth(lhs)
+                .cmp(&depth(rhs))
+                .then_with(|| rhs.len().cmp(&lhs.len()))
+        })
+        .unwrap_or_default()
+}
+
+/// The keywords used by `schema` which this validator does not support.
+fn unsupported_keywords(schema: &SchemaObject) -> Vec<&'static str> {
+    let mut keywords = Vec::new();
+    if schema.string.as_ref().is_some_and(|s| s.pattern.is_some()) {
+        keywords.push("pattern");
+    }
+    if schema
+        .object
+        .as_ref()
+        .is_some_and(|o| !o.pattern_properties.is_empty())
+    {
+        keywords.push("patternProperties");
+    }
+    keywords
+}
+
+/// `path` extended with `key`, escaped as per RFC 6901.
+fn child_path(path: &str, key: &str) -> String {
+    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn is_of_type(value: &Value, instance_type: &InstanceType) -> bool {
+    match instance_type {
+        InstanceType::Null => value.is_null(),
+        InstanceType::Boolean => value.is_boolean(),
+        InstanceType::Object => value.is_object(),
+        InstanceType::Array => value.is_array(),
+        InstanceType::Number => value.is_number(),
+        InstanceType::String => value.is_string(),
+        InstanceType::Integer => value.is_i64() || value.is_u64(),
+    }
+}
+
+fn type_name(instance_type: &InstanceType) -> &'static str {
+    match instance_type {
+        InstanceType::Null => "null",
+        InstanceType::Boolean => "boolean",
+        InstanceType::Object => "object",
+        InstanceType::Array => "array",
+        InstanceType::Number => "number",
+        InstanceType::String => "string",
+        InstanceType::Integer => "integer",
+    }
+}
+
+fn value_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+    }
+}
+
+/// Why `value` is not of `format`, if it is not. Unknown formats are
+/// accepted.
+fn format_violation(format: &str, value: &Value) -> Option<String> {
+    let is_valid = match (format, value) {
+        ("uuid", Value::String(s)) => Uuid::parse_str(s).is_ok(),
+        ("date-time", Value::String(s)) => Timestamp::parse(s).is_some(),
+        ("uri", Value::String(s)) => Url::parse(s).is_ok(),
+        ("uint8", Value::Number(n)) => fits(n, u8::MAX as u64),
+        ("uint16", Value::Number(n)) => fits(n, u16::MAX as u64),
+        ("uint32", Value::Number(n)) => fits(n, u32::MAX as u64),
+        _ => true,
+    };
+    (!is_valid).then(|| format!("expected {}, found {}", format, value))
+}
+
+fn fits(number: &serde_json::Number, max: u64) -> bool {
+    number.as_u64().map_or(false, |n| n <= max)
+}
+
+fn validate_length(
+    validation: &StringValidation,
+    string: &str,
+    path: &str,
+    violations: &mut Vec<JsonSchemaViolation>,
+) {
+    let length = string.chars().count();
+    if let Some(min) = validation.min_length {
+        if length < min as usize {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!(
+                    "expected at least {} characters, found {}",
+                    min, length
+                ),
+            ));
+        }
+    }
+    if let Some(max) = validation.max_length {
+        if length > max as usize {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!(
+                    "expected at most {} characters, found {}",
+                    max, length
+                ),
+            ));
+        }
+    }
+}
+
+fn validate_range(
+    validation: &NumberValidation,
+    number: &serde_json::Number,
+    path: &str,
+    violations: &mut Vec<JsonSchemaViolation>,
+) {
+    let Some(number) = number.as_f64() else {
+        return;
+    };
+    if let Some(min) = validation.minimum {
+        if number < min {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!("expected at least {}, found {}", min, number),
+            ));
+        }
+    }
+    if let Some(max) = validation.maximum {
+        if number > max {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!("expected at most {}, found {}", max, number),
+            ));
+        }
+    }
+    if let Some(min) = validation.exclusive_minimum {
+        if number <= min {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!("expected more than {}, found {}", min, number),
+            ));
+        }
+    }
+    if let Some(max) = validation.exclusive_maximum {
+        if number >= max {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!("expected less than {}, found {}", max, number),
+            ));
+        }
+    }
+    if let Some(divisor) = validation.multiple_of {
+        if (number / divisor).fract() != 0.0 {
+            violations.push(JsonSchemaViolation::new(
+                path,
+                format!("expected a multiple of {}, found {}", divisor, number),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serde_json::Value;
+
+    #[derive(Serialize, JsonSchema)]
+    #[serde(rename_all = "camelCase")]
+    struct Pet {
+        name: String,
+        age_in_years: u8,
+        nick_names: Vec<String>,
+        owner: Option<Owner>,
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    struct Owner {
+        #[schemars(schema_with = "uuid_json_schema")]
+        id: Uuid,
+    }
+
+    fn violations(json: Value) -> Vec<JsonSchemaViolation> {
+        let schema = schemars::schema_for!(Pet);
+        JsonSchemaValidator::new(&schema).violations(&json)
+    }
+
+    /// The violations of `json` against the schema `schema`.
+    fn violations_of(schema: Value, json: Value) -> Vec<JsonSchemaViolation> {
+        let schema: RootSchema = serde_json::from_value(schema).unwrap();
+        JsonSchemaValidator::new(&schema).violations(&json)
+    }
+
+    /// The reasons of the violations of `json` against `schema`, panics if
+    /// any violation is not of the root value.
+    fn reasons(schema: Value, json: Value) -> Vec<String> {
+        violations_of(schema, json)
+            .into_iter()
+            .map(|v| {
+                assert_eq!(v.path, "");
+                v.reason
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            violations(json!({
+                "name": "Tuna",
+                "ageInYears": 3,
+                "nickNames": ["Tunis"],
+                "owner": { "id": "66f07ca2-a9d9-49e5-8152-77aca3d1dd74" }
+            })),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn missing_property() {
+        assert_eq!(
+            violations(json!({ "name": "Tuna", "nickNames": [] })),
+            vec![JsonSchemaViolation::new(
+                "/ageInYears",
+                "missing required property"
+            )]
+        );
+    }
+
+    #[test]
+    fn wrong_type_of_array_item() {
+        assert_eq!(
+            violations(json!({
+                "name": "Tuna",
+                "ageInYears": 3,
+                "nickNames": ["Tunis", 1]
+            })),
+            vec![JsonSchemaViolation::new(
+                "/nickNames/1",
+                "expected string, found integer"
+            )]
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(
+            violations(json!({
+                "name": "Tuna",
+                "ageInYears": 256,
+                "nickNames": []
+            })),
+            vec![JsonSchemaViolation::new(
+                "/ageInYears",
+                "expected uint8, found 256"
+            )]
+        );
+    }
+
+    #[test]
+    fn invalid_nested_format() {
+        assert_eq!(
+            violations(json!({
+                "name": "Tuna",
+                "ageInYears": 3,
+                "nickNames": [],
+                "owner": { "id": "not a uuid" }
+            })),
+            vec![JsonSchemaViolation::new(
+                "/owner/id",
+                "expected uuid, found \"not a uuid\""
+            )]
+        );
+    }
+
+    #[test]
+    fn keyword_ref() {
+        let schema = json!({
+            "$ref": "#/definitions/Name",
+            "definitions": { "Name": { "type": "string" } }
+        });
+        assert_eq!(
+            reasons(schema.clone(), json!("Tuna")),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            reasons(schema, json!(1)),
+            vec!["expected string, found integer"]
+        );
+        assert_eq!(
+            reasons(json!({ "$ref": "#/definitions/Unknown" }), json!(1)),
+            vec!["unresolvable reference '#/definitions/Unknown'"]
+        );
+    }
+
+    #[test]
+    fn keyword_type() {
+        let schema = json!({ "type": ["string", "null"] });
+        assert!(reasons(schema.clone(), json!(null)).is_empty());
+        assert_eq!(
+            reasons(schema, json!(1.5)),
+            vec!["expected string or null, found number"]
+        );
+    }
+
+    #[test]
+    fn keyword_format() {
+        let schema = json!({ "format": "date-time" });
+        assert!(
+            reasons(schema.clone(), json!("2023-09-11T16:05:56Z")).is_empty()
+        );
+        assert_eq!(
+            reasons(schema, json!("yesterday")),
+            vec!["expected date-time, found \"yesterday\""]
+        );
+    }
+
+    #[test]
+    fn keyword_enum() {
+        let schema = json!({ "enum": ["a", "b"] });
+        assert!(reasons(schema.clone(), json!("a")).is_empty());
+        assert_eq!(
+            reasons(schema, json!("c")),
+            vec!["expected one of \"a\", \"b\", found \"c\""]
+        );
+    }
+
+    #[test]
+    fn keyword_const() {
+        let schema = json!({ "const": 1 });
+        assert!(reasons(schema.clone(), json!(1)).is_empty());
+        assert_eq!(reasons(schema, json!(2)), vec!["expected 1, found 2"]);
+    }
+
+    #[test]
+    fn keyword_all_of() {
+        let schema = json!({ "allOf": [{ "minimum": 1 }, { "maximum": 2 }] });
+        assert!(reasons(schema.clone(), json!(2)).is_empty());
+        assert_eq!(
+            reasons(schema, json!(3)),
+            vec!["expected at most 2, found 3"]
+        );
+    }
+
+    #[test]
+    fn keyword_any_of() {
+        let schema =
+            json!({ "anyOf": [{ "type": "string" }, { "minimum": 1 }] });
+        assert!(reasons(schema.clone(), json!(1)).is_empty());
+        assert!(reasons(schema.clone(), json!("a")).is_empty());
+        assert_eq!(reasons(schema, json!(0)).len(), 1);
+    }
+
+    #[test]
+    fn keyword_one_of() {
+        let schema = json!({ "oneOf": [{ "minimum": 1 }, { "maximum": 2 }] });
+        assert!(reasons(schema.clone(), json!(3)).is_empty());
+        assert_eq!(
+            reasons(schema, json!(2)),
+            vec!["expected exactly one match, found 2"]
+        );
+    }
+
+    #[test]
+    fn keyword_not() {
+        let schema = json!({ "not": { "type": "null" } });
+        assert!(reasons(schema.clone(), json!(1)).is_empty());
+        assert_eq!(
+            reasons(schema, json!(null)),
+            vec!["expected no match of 'not'"]
+        );
+    }
+
+    #[test]
+    fn keyword_if_then_else() {
+        let schema = json!({
+            "if": { "type": "string" },
+            "then": { "minLength": 2 },
+            "else": { "minimum": 2 }
+        });
+        assert!(reasons(schema.clone(), json!("ab")).is_empty());
+        assert!(reasons(schema.clone(), json!(2)).is_empty());
+        assert_eq!(
+            reasons(schema.clone(), json!("a")),
+            vec!["expected at least 2 characters, found 1"]
+        );
+        assert_eq!(
+            reasons(schema, json!(1)),
+            vec!["expected at least 2, found 1"]
+        );
+    }
+
+    #[test]
+    fn keywords_properties_required_and_additional_properties() {
+        let schema = json!({
+            "properties": { "name": { "type": "string" } },
+            "required": ["name"],
+            "additionalProperties": false
+        });
+        assert!(
+            violations_of(schema.clone(), json!({ "name": "a" })).is_empty()
+        );
+        assert_eq!(
+            violations_of(schema, json!({ "name": 1, "age": 2 })),
+            vec![
+                JsonSchemaViolation::new(
+                    "/name",
+                    "expected string, found integer"
+                ),
+                JsonSchemaViolation::new("/age", "no value is allowed"),
+            ]
+        );
+        assert_eq!(
+            violations_of(json!({ "required": ["name"] }), json!({})),
+            vec![JsonSchemaViolation::new(
+                "/name",
+                "missing required property"
+            )]
+        );
+    }
+
+    #[test]
+    fn keyword_property_names() {
+        let schema = json!({ "propertyNames": { "maxLength": 2 } });
+        assert!(violations_of(schema.clone(), json!({ "ab": 1 })).is_empty());
+        assert_eq!(
+            violations_of(schema, json!({ "abc": 1 })),
+            vec![JsonSchemaViolation::new(
+                "/abc",
+                "expected at most 2 characters, found 3"
+            )]
+        );
+    }
+
+    #[test]
+    fn keywords_min_and_max_properties() {
+        let schema = json!({ "minProperties": 1, "maxProperties": 1 });
+        assert!(reasons(schema.clone(), json!({ "a": 1 })).is_empty());
+        assert_eq!(
+            reasons(schema.clone(), json!({})),
+            vec!["expected at least 1 properties"]
+        );
+        assert_eq!(
+            reasons(schema, json!({ "a": 1, "b": 2 })),
+            vec!["expected at most 1 properties"]
+        );
+    }
+
+    #[test]
+    fn keywords_items_and_additional_items() {
+        let schema = json!({
+            "items": [{ "type": "string" }],
+            "additionalItems": { "type": "integer" }
+        });
+        assert!(violations_of(schema.clone(), json!(["a", 1, 2])).is_empty());
+        assert_eq!(
+            violations_of(schema, json!([1, "a"])),
+            vec![
+                JsonSchemaViolation::new(
+                    "/0",
+                    "expected string, found integer"
+                ),
+                JsonSchemaViolation::new(
+                    "/1",
+                    "expected integer, found string"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn keywords_min_and_max_items() {
+        let schema = json!({ "minItems": 1, "maxItems": 1 });
+        assert!(reasons(schema.clone(), json!([1])).is_empty());
+        assert_eq!(
+            reasons(schema.clone(), json!([])),
+            vec!["expected at least 1 items"]
+        );
+        assert_eq!(
+            reasons(schema, json!([1, 2])),
+            vec!["expected at most 1 items"]
+        );
+    }
+
+    #[test]
+    fn keyword_unique_items() {
+        let schema = json!({ "uniqueItems": true });
+        assert!(violations_of(schema.clone(), json!([1, 2])).is_empty());
+        assert_eq!(
+            violations_of(schema, json!([1, 2, 1])),
+            vec![JsonSchemaViolation::new(
+                "/2",
+                "expected unique items, found duplicate 1"
+            )]
+        );
+    }
+
+    #[test]
+    fn keyword_contains() {
+        let schema = json!({ "contains": { "type": "string" } });
+        assert!(reasons(schema.clone(), json!([1, "a"])).is_empty());
+        assert_eq!(
+            reasons(schema, json!([1, 2])),
+            vec!["expected an item matching 'contains'"]
+        );
+    }
+
+    #[test]
+    fn keywords_min_and_max_length() {
+        let schema = json!({ "minLength": 1, "maxLength": 2 });
+        assert!(reasons(schema.clone(), json!("ab")).is_empty());
+        assert_eq!(
+            reasons(schema.clone(), json!("")),
+            vec!["expected at least 1 characters, found 0"]
+        );
+        assert_eq!(
+            reasons(schema, json!("abc")),
+            vec!["expected at most 2 characters, found 3"]
+        );
+    }
+
+    #[test]
+    fn keywords_minimum_and_maximum() {
+        let schema = json!({ "minimum": 1, "maximum": 2 });
+        assert!(reasons(schema.clone(), json!(1)).is_empty());
+        assert!(reasons(schema.clone(), json!(2)).is_empty());
+        assert_eq!(
+            reasons(schema.clone(), json!(0)),
+            vec!["expected at least 1, found 0"]
+        );
+        assert_eq!(
+            reasons(schema, json!(3)),
+            vec!["expected at most 2, found 3"]
+        );
+    }
+
+    #[test]
+    fn keywords_exclusive_minimum_and_maximum() {
+        let schema = json!({ "exclusiveMinimum": 1, "exclusiveMaximum": 3 });
+        assert!(reasons(schema.clone(), json!(2)).is_empty());
+        assert_eq!(
+            reasons(schema.clone(), json!(1)),
+            vec!["expected more than 1, found 1"]
+        );
+        assert_eq!(
+            reasons(schema, json!(3)),
+            vec!["expected less than 3, found 3"]
+        );
+    }
+
+    #[test]
+    fn keyword_multiple_of() {
+        let schema = json!({ "multipleOf": 0.5 });
+        assert!(reasons(schema.clone(), json!(1.5)).is_empty());
+        assert_eq!(
+            reasons(schema, json!(1.25)),
+            vec!["expected a multiple of 0.5, found 1.25"]
+        );
+    }
+
+    #[test]
+    fn unsupported_keyword_pattern_is_a_violation() {
+        assert_eq!(
+            reasons(json!({ "pattern": "^a" }), json!("abc")),
+            vec!["unsupported keyword 'pattern'"]
+        );
+    }
+
+    #[test]
+    fn unsupported_keyword_pattern_properties_is_a_violation() {
+        assert_eq!(
+            reasons(
+                json!({ "patternProperties": { "^a": { "type": "string" } } }),
+                json!({})
+            ),
+            vec!["unsupported keyword 'patternProperties'"]
+        );
+    }
+
+    #[test]
+    fn profile_schema_uses_only_supported_keywords() {
+        let schema = serde_json::to_value(profile_json_schema()).unwrap();
+        let mut keywords = Vec::new();
+        collect_keys(&schema, &mut keywords);
+        for keyword in ["pattern", "patternProperties"] {
+            assert!(!keywords.contains(&keyword.to_owned()), "{}", keyword);
+        }
+    }
+
+    /// All object keys anywhere in `value`.
+    fn collect_keys(value: &Value, keys: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    keys.push(key.clone());
+                    collect_keys(value, keys);
+                }
+            }
+            Value::Array(values) => {
+                values.iter().for_each(|v| collect_keys(v, keys))
+            }
+            _ => {}
+        }
+    }
+}
@@ -2,6 +2,8 @@ mod assert_json;
 mod error;
 mod has_placeholder;
 mod hash;
+mod json_schema;
+mod json_schema_validator;
 mod secure_random_bytes;
 mod types;
 mod unsafe_id_stepper;
@@ -11,6 +13,8 @@ pub use assert_json::*;
 pub use error::*;
 pub use has_placeholder::*;
 pub use hash::*;
+pub use json_schema::*;
+pub use json_schema_validator::*;
 pub use secure_random_bytes::*;
 pub use types::*;
 pub use unsafe_id_stepper::*;
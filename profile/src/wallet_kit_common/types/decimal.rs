@@ -38,10 +38,10 @@ impl Ord for Decimal {
     }
 }
 impl Decimal {
-    fn native(&self) -> NativeDecimal {
+    pub(crate) fn native(&self) -> NativeDecimal {
         NativeDecimal::from_str(&self.base10_string).unwrap()
     }
-    fn from_native(decimal: NativeDecimal) -> Self {
+    pub(crate) fn from_native(decimal: NativeDecimal) -> Self {
         Self {
             base10_string: decimal.to_string(),
         }
@@ -72,6 +72,16 @@ impl<'de> Deserialize<'de> for Decimal {
     }
 }
 
+impl JsonSchema for Decimal {
+    fn schema_name() -> String {
+        "Decimal".to_owned()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_json_schema(Some("decimal"), None)
+    }
+}
+
 impl Decimal {
     pub fn try_from_str(s: &str) -> Result<Self> {
         Self::new(s.to_string())
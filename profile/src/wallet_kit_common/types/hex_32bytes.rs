@@ -88,6 +88,16 @@ impl FromStr for Hex32Bytes {
     }
 }
 
+impl JsonSchema for Hex32Bytes {
+    fn schema_name() -> String {
+        "Hex32Bytes".to_owned()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        hex_json_schema(32)
+    }
+}
+
 impl From<Hash> for Hex32Bytes {
     /// Instantiates a new `Hex32Bytes` from the `Hash` (32 bytes).
     fn from(value: Hash) -> Self {
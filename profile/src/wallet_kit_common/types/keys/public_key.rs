@@ -187,6 +187,25 @@ impl Serialize for PublicKey {
     }
 }
 
+impl JsonSchema for PublicKey {
+    fn schema_name() -> String {
+        "PublicKey".to_owned()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let key = |curve: SLIP10Curve, byte_count: u32| {
+            object_json_schema([
+                ("curve", const_json_schema(curve)),
+                ("compressedData", hex_json_schema(byte_count)),
+            ])
+        };
+        one_of_json_schema([
+            key(SLIP10Curve::Curve25519, 32),
+            key(SLIP10Curve::Secp256k1, 33),
+        ])
+    }
+}
+
 impl From<PublicKey> for EnginePublicKey {
     fn from(value: PublicKey) -> Self {
         match value {
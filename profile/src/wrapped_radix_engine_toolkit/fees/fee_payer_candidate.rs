@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+/// An account which could pay the fee of a transaction, along with its XRD
+/// balance, which the host fetches from the Gateway.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct FeePayerCandidate {
+    pub account_address: AccountAddress,
+    pub xrd_balance: Decimal,
+}
+
+impl FeePayerCandidate {
+    pub fn new(account_address: AccountAddress, xrd_balance: Decimal) -> Self {
+        Self {
+            account_address,
+            xrd_balance,
+        }
+    }
+}
+
+impl HasPlaceholder for FeePayerCandidate {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            AccountAddress::placeholder_mainnet(),
+            Decimal::try_from_str("100").unwrap(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(AccountAddress::placeholder_mainnet_other(), Decimal::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = FeePayerCandidate;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+}
@@ -0,0 +1,499 @@
+use crate::prelude::*;
+
+use radix_engine_common::math::{
+    CheckedAdd, CheckedMul, CheckedSub, Decimal as ScryptoDecimal,
+};
+use transaction::prelude::XRD as SCRYPTO_XRD;
+
+/// The account selected to pay the fee and the manifest locking the fee.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct SelectedFeePayer {
+    pub fee_payer: AccountAddress,
+
+    /// The amount locked, i.e. the fee estimate plus the margin.
+    pub locked_fee: Decimal,
+
+    /// The manifest with a `lock_fee` instruction added.
+    pub manifest: TransactionManifest,
+
+    /// Set to the fee payer if it did not already sign the transaction, i.e.
+    /// one more signature than before is required.
+    pub additional_signer: Option<AccountAddress>,
+}
+
+/// The outcome of selecting a fee payer for a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum FeePayerSelectionResult {
+    Selected {
+        selected: SelectedFeePayer,
+    },
+
+    /// None of the `candidates` has enough XRD to lock `fee_to_lock`, taking
+    /// into account the XRD withdrawn from them in the manifest.
+    InsufficientBalance {
+        fee_to_lock: Decimal,
+        candidates: Vec<FeePayerCandidate>,
+    },
+
+    /// There were no candidates to select from.
+    NoCandidates,
+}
+
+/// Selects which of the `candidates` pays the fee of `manifest` and adds an
+/// instruction locking the fee, see [`TransactionManifest::select_fee_payer`].
+#[uniffi::export]
+pub fn manifest_select_fee_payer(
+    manifest: &TransactionManifest,
+    fee_estimate: Decimal,
+    margin: Decimal,
+    candidates: Vec<FeePayerCandidate>,
+) -> Result<FeePayerSelectionResult> {
+    manifest.select_fee_payer(&fee_estimate, &margin, candidates)
+}
+
+impl TransactionManifest {
+    /// Selects which of the `candidates` pays the fee of this manifest and
+    /// adds an instruction locking `fee_estimate * (1 + margin)` from it,
+    /// e.g. a `margin` of `0.1` locks 10% more than the estimate.
+    ///
+    /// Accounts already signing the transaction are preferred, so that no
+    /// additional signature is needed, otherwise the first candidate with
+    /// enough XRD is selected. The XRD withdrawn from a candidate by the
+    /// manifest is not available for paying the fee.
+    ///
+    /// Throws an error if `fee_estimate` or `margin` is negative or if the
+    /// amounts overflow. Since the fee is locked by a new first instruction,
+    /// see [`TransactionManifest::adding_lock_fee`], guarantees must be
+    /// added to this manifest before selecting the fee payer.
+    pub fn select_fee_payer(
+        &self,
+        fee_estimate: &Decimal,
+        margin: &Decimal,
+        candidates: impl IntoIterator<Item = FeePayerCandidate>,
+    ) -> Result<FeePayerSelectionResult> {
+        if fee_estimate.is_negative() {
+            return Err(CommonError::InvalidFeeEstimate(fee_estimate.clone()));
+        }
+        if margin.is_negative() {
+            return Err(CommonError::InvalidFeeMargin(margin.clone()));
+        }
+        let candidates = candidates.into_iter().collect_vec();
+        if let Some(c) = candidates
+            .iter()
+            .find(|c| c.account_address.network_id != self.network_id)
+        {
+            return Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: self.network_id,
+                found: c.account_address.network_id,
+            });
+        }
+        if candidates.is_empty() {
+            return Ok(FeePayerSelectionResult::NoCandidates);
+        }
+
+        let fee_to_lock = ScryptoDecimal::one()
+            .checked_add(margin.native())
+            .and_then(|factor| fee_estimate.native().checked_mul(factor))
+            .map(Decimal::from_native)
+            .ok_or(CommonError::DecimalError)?;
+
+        let summary = self.summary()?;
+        let xrd = ResourceAddress::try_from_node_id(
+            *SCRYPTO_XRD.as_node_id(),
+            self.network_id,
+        )
+        .expect("XRD to be a valid resource address on every network.");
+
+        let is_signing = |address: &AccountAddress| {
+            summary
+                .account(address)
+                .map(|a| a.requires_signature)
+                .unwrap_or(false)
+        };
+
+        let mut able_to_pay = Vec::new();
+        for candidate in candidates.iter() {
+            let withdrawn = summary
+                .account(&candidate.account_address)
+                .map(|a| {
+                    a.withdrawals
+                        .iter()
+                        .filter(|w| w.resource_address == xrd)
+                        .filter_map(|w| match &w.amount {
+                            ResourceAmount::Fungible { amount } => {
+                                Some(amount.native())
+                            }
+                            ResourceAmount::NonFungible { .. } => None,
+                        })
+                        .try_fold(ScryptoDecimal::zero(), |acc, x| {
+                            acc.checked_add(x)
+                        })
+                })
+                .unwrap_or(Some(ScryptoDecimal::zero()))
+                .ok_or(CommonError::DecimalError)?;
+            let available = candidate
+                .xrd_balance
+                .native()
+                .checked_sub(withdrawn)
+                .ok_or(CommonError::DecimalError)?;
+            if available >= fee_to_lock.native() {
+                able_to_pay.push(candidate);
+            }
+        }
+
+        let fee_payer = able_to_pay
+            .iter()
+            .find(|c| is_signing(&c.account_address))
+            .or_else(|| able_to_pay.first())
+            .map(|c| c.account_address.clone());
+
+        let Some(fee_payer) = fee_payer else {
+            return Ok(FeePayerSelectionResult::InsufficientBalance {
+                fee_to_lock,
+                candidates,
+            });
+        };
+
+        let additional_signer =
+            (!is_signing(&fee_payer)).then(|| fee_payer.clone());
+        let manifest = self.adding_lock_fee(&fee_payer, fee_to_lock.clone())?;
+
+        Ok(FeePayerSelectionResult::Selected {
+            selected: SelectedFeePayer {
+                fee_payer,
+                locked_fee: fee_to_lock,
+                manifest,
+                additional_signer,
+            },
+        })
+    }
+}
+
+impl Profile {
+    /// Selects which of the `candidates` pays the fee of `manifest`, see
+    /// [`TransactionManifest::select_fee_payer`], candidates which are not
+    /// accounts in this Profile are ignored since the user cannot sign with
+    /// them, neither are accounts hidden by the user.
+    pub fn select_fee_payer(
+        &self,
+        manifest: &TransactionManifest,
+        fee_estimate: &Decimal,
+        margin: &Decimal,
+        candidates: impl IntoIterator<Item = FeePayerCandidate>,
+    ) -> Result<FeePayerSelectionResult> {
+        manifest.select_fee_payer(
+            fee_estimate,
+            margin,
+            candidates.into_iter().filter(|c| {
+                self.networks
+                    .get_account(&c.account_address)
+                    .is_some_and(|a| {
+                        !a.flags.contains(&EntityFlag::DeletedByUser)
+                    })
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use radix_engine_common::math::Decimal as ScryptoDecimal;
+    use transaction::prelude::{
+        ComponentAddress as ScryptoComponentAddress,
+        ManifestBuilder as ScryptoManifestBuilder,
+    };
+
+    fn amount(s: &str) -> Decimal {
+        Decimal::try_from_str(s).unwrap()
+    }
+
+    fn sender() -> AccountAddress {
+        AccountAddress::placeholder_mainnet()
+    }
+
+    fn other() -> AccountAddress {
+        AccountAddress::placeholder_mainnet_other()
+    }
+
+    /// Transfers 10 XRD from `sender` to `other`.
+    fn manifest() -> TransactionManifest {
+        TransactionManifest::single_fungible_transfer(
+            &sender(),
+            other(),
+            ResourceAddress::placeholder_mainnet_xrd(),
+            amount("10"),
+        )
+        .unwrap()
+    }
+
+    fn select(
+        candidates: impl IntoIterator<Item = FeePayerCandidate>,
+    ) -> FeePayerSelectionResult {
+        manifest_select_fee_payer(
+            &manifest(),
+            amount("1"),
+            amount("0.5"),
+            candidates.into_iter().collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn prefers_signing_account() {
+        let result = select([
+            FeePayerCandidate::new(other(), amount("100")),
+            FeePayerCandidate::new(sender(), amount("100")),
+        ]);
+        let FeePayerSelectionResult::Selected { selected } = result else {
+            panic!("Expected selection");
+        };
+        assert_eq!(selected.fee_payer, sender());
+        assert_eq!(selected.locked_fee, amount("1.5"));
+        assert_eq!(selected.additional_signer, None);
+        assert!(selected.manifest.instructions.contains("\"lock_fee\""));
+        assert!(selected.manifest.instructions.contains("Decimal(\"1.5\")"));
+    }
+
+    #[test]
+    fn xrd_withdrawn_is_not_available_for_fee() {
+        let result = select([
+            FeePayerCandidate::new(sender(), amount("11")),
+            FeePayerCandidate::new(other(), amount("2")),
+        ]);
+        let FeePayerSelectionResult::Selected { selected } = result else {
+            panic!("Expected selection");
+        };
+        assert_eq!(selected.fee_payer, other());
+        assert_eq!(selected.additional_signer, Some(other()));
+        assert!(
+            selected
+                .manifest
+                .summary()
+                .unwrap()
+                .account(&other())
+                .unwrap()
+                .requires_signature
+        );
+    }
+
+    #[test]
+    fn insufficient_balance() {
+        let candidates = vec![
+            FeePayerCandidate::new(sender(), amount("11.4")),
+            FeePayerCandidate::new(other(), amount("1.4")),
+        ];
+        assert_eq!(
+            select(candidates.clone()),
+            FeePayerSelectionResult::InsufficientBalance {
+                fee_to_lock: amount("1.5"),
+                candidates
+            }
+        );
+    }
+
+    #[test]
+    fn no_candidates() {
+        assert_eq!(select([]), FeePayerSelectionResult::NoCandidates);
+    }
+
+    #[test]
+    fn negative_margin() {
+        assert_eq!(
+            manifest().select_fee_payer(&amount("1"), &amount("-0.1"), []),
+            Err(CommonError::InvalidFeeMargin(amount("-0.1")))
+        );
+    }
+
+    #[test]
+    fn negative_fee_estimate() {
+        assert_eq!(
+            manifest().select_fee_payer(&amount("-1"), &Decimal::zero(), []),
+            Err(CommonError::InvalidFeeEstimate(amount("-1")))
+        );
+    }
+
+    #[test]
+    fn fee_to_lock_overflow() {
+        assert_eq!(
+            manifest().select_fee_payer(
+                &Decimal::from_native(ScryptoDecimal::MAX),
+                &amount("1"),
+                [FeePayerCandidate::new(
+                    sender(),
+                    Decimal::from_native(ScryptoDecimal::MAX)
+                )]
+            ),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn available_balance_overflow() {
+        assert_eq!(
+            manifest().select_fee_payer(
+                &amount("1"),
+                &Decimal::zero(),
+                [FeePayerCandidate::new(
+                    sender(),
+                    Decimal::from_native(ScryptoDecimal::MIN)
+                )]
+            ),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn guarantees_added_before_fee_payer_keep_their_position() {
+        let carol = AccountAddress::placeholder_stokenet();
+        let xrd = ResourceAddress::placeholder_stokenet_xrd();
+        let receiver: ScryptoComponentAddress = carol.clone().into();
+        let manifest = TransactionManifest::from_scrypto(
+            ScryptoManifestBuilder::new()
+                .get_free_xrd_from_faucet()
+                .try_deposit_entire_worktop_or_abort(receiver, None)
+                .build(),
+            NetworkID::Stokenet,
+        )
+        .unwrap()
+        .adding_guarantees(
+            [EstimatedDeposit::new(
+                carol.clone(),
+                xrd.clone(),
+                1,
+                amount("10000"),
+                None,
+            )
+            .unwrap()],
+            &amount("0.9"),
+        )
+        .unwrap();
+
+        let FeePayerSelectionResult::Selected { selected } = manifest
+            .select_fee_payer(
+                &amount("1"),
+                &amount("0.1"),
+                [FeePayerCandidate::new(carol.clone(), amount("100"))],
+            )
+            .unwrap()
+        else {
+            panic!("Expected selection");
+        };
+
+        let instructions = &selected.manifest.instructions;
+        let position = |s: &str| instructions.find(s).unwrap();
+        assert!(position("\"lock_fee\"") < position("\"free\""));
+        assert!(position("\"free\"") < position("ASSERT_WORKTOP_CONTAINS"));
+        assert!(
+            position("ASSERT_WORKTOP_CONTAINS")
+                < position("\"try_deposit_batch_or_abort\"")
+        );
+        assert_eq!(
+            selected
+                .manifest
+                .summary()
+                .unwrap()
+                .account(&carol)
+                .unwrap()
+                .deposits,
+            vec![AccountDeposit {
+                resource_address: xrd,
+                amount: DepositAmount::Estimated {
+                    guaranteed_minimum: Some(amount("9000")),
+                    instruction_index: 3
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn candidate_on_wrong_network() {
+        assert_eq!(
+            manifest().select_fee_payer(
+                &amount("1"),
+                &Decimal::zero(),
+                [FeePayerCandidate::new(
+                    AccountAddress::placeholder_stokenet(),
+                    amount("100")
+                )]
+            ),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+
+    #[test]
+    fn profile_ignores_accounts_not_in_profile() {
+        let profile = Profile::placeholder();
+        let carol = AccountAddress::placeholder_stokenet();
+        let manifest = TransactionManifest::set_account_metadata(
+            &carol,
+            "name",
+            MetadataValue::Str {
+                value: "Carol".to_owned(),
+            },
+        )
+        .unwrap();
+        let unknown = AccountAddress::try_from_bech32(
+            "account_tdx_2_12yf9gd53yfep7a669fv2t3wm7nz9zeezwd04n02a433ker8vza6rhe",
+        )
+        .unwrap();
+
+        let sut = profile
+            .select_fee_payer(
+                &manifest,
+                &amount("1"),
+                &Decimal::zero(),
+                [FeePayerCandidate::new(unknown, amount("100"))],
+            )
+            .unwrap();
+        assert_eq!(sut, FeePayerSelectionResult::NoCandidates);
+
+        let sut = profile
+            .select_fee_payer(
+                &manifest,
+                &amount("1"),
+                &Decimal::zero(),
+                [FeePayerCandidate::new(carol.clone(), amount("100"))],
+            )
+            .unwrap();
+        assert!(matches!(
+            sut,
+            FeePayerSelectionResult::Selected { selected } if selected.fee_payer == carol
+        ));
+    }
+
+    #[test]
+    fn profile_ignores_hidden_accounts() {
+        let profile = Profile::placeholder();
+        let carol = AccountAddress::placeholder_stokenet();
+        let diana = Account::placeholder_stokenet_diana().address;
+        assert!(profile
+            .networks
+            .get_account(&diana)
+            .unwrap()
+            .flags
+            .contains(&EntityFlag::DeletedByUser));
+        let manifest = TransactionManifest::set_account_metadata(
+            &carol,
+            "name",
+            MetadataValue::Str {
+                value: "Carol".to_owned(),
+            },
+        )
+        .unwrap();
+
+        let sut = profile
+            .select_fee_payer(
+                &manifest,
+                &amount("1"),
+                &Decimal::zero(),
+                [FeePayerCandidate::new(diana, amount("100"))],
+            )
+            .unwrap();
+        assert_eq!(sut, FeePayerSelectionResult::NoCandidates);
+    }
+}
@@ -0,0 +1,5 @@
+mod fee_payer_candidate;
+mod fee_payer_selection;
+
+pub use fee_payer_candidate::*;
+pub use fee_payer_selection::*;
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+
+use transaction::prelude::{
+    Ed25519Signature as ScryptoEd25519Signature,
+    IntentSignatureV1 as ScryptoIntentSignature,
+    Secp256k1Signature as ScryptoSecp256k1Signature,
+    SignatureWithPublicKeyV1 as ScryptoSignatureWithPublicKey,
+};
+
+/// A signature of the [`IntentHash`] of a transaction by the key of an
+/// entity whose auth the manifest requires, e.g. an account withdrawing
+/// resources, see [`ManifestSummary`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct IntentSignature {
+    /// The key which signed, e.g. the transaction signing key of an account.
+    pub public_key: PublicKey,
+
+    /// The 64 bytes of an Ed25519 signature or the 65 bytes of a recoverable
+    /// Secp256k1 signature.
+    pub signature: BagOfBytes,
+}
+
+impl IntentSignature {
+    pub fn new(public_key: PublicKey, signature: BagOfBytes) -> Self {
+        Self {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Signs `intent_hash` with `private_key`.
+    pub fn sign(private_key: &PrivateKey, intent_hash: &IntentHash) -> Self {
+        let hash = intent_hash.scrypto_hash();
+        let signature = match private_key {
+            PrivateKey::Ed25519(key) => key.sign(&hash).0.to_vec(),
+            PrivateKey::Secp256k1(key) => key.sign(&hash).0.to_vec(),
+        };
+        Self::new(private_key.public_key(), signature.into())
+    }
+
+    /// Checks that this is a valid signature of `intent_hash` by
+    /// `public_key` and converts it into the Scrypto intent signature.
+    pub(crate) fn scrypto_signature(
+        &self,
+        intent_hash: &IntentHash,
+    ) -> Result<ScryptoIntentSignature> {
+        let invalid = || CommonError::InvalidIntentSignature {
+            public_key: self.public_key.to_hex(),
+        };
+        let hash = intent_hash.scrypto_hash();
+        let bytes = self.signature.to_vec();
+        let signature = match &self.public_key {
+            PublicKey::Ed25519 { value } => {
+                let signature = ScryptoEd25519Signature(
+                    bytes.try_into().map_err(|_| invalid())?,
+                );
+                if !value.is_valid(&signature, &hash) {
+                    return Err(invalid());
+                }
+                ScryptoSignatureWithPublicKey::Ed25519 {
+                    public_key: value.to_engine(),
+                    signature,
+                }
+            }
+            PublicKey::Secp256k1 { value } => {
+                let signature = ScryptoSecp256k1Signature(
+                    bytes.try_into().map_err(|_| invalid())?,
+                );
+                if !value.is_valid(&signature, &hash) {
+                    return Err(invalid());
+                }
+                ScryptoSignatureWithPublicKey::Secp256k1 { signature }
+            }
+        };
+        Ok(ScryptoIntentSignature(signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn intent_hash() -> IntentHash {
+        TransactionIntent::new(
+            TransactionHeader::placeholder(),
+            TransactionManifest::set_account_metadata(
+                &AccountAddress::placeholder_mainnet(),
+                "name",
+                MetadataValue::Str {
+                    value: "Savings".to_owned(),
+                },
+            )
+            .unwrap(),
+            Message::None,
+        )
+        .unwrap()
+        .intent_hash()
+        .unwrap()
+    }
+
+    #[test]
+    fn sign_and_convert_ed25519() {
+        let key: PrivateKey = Ed25519PrivateKey::placeholder().into();
+        let sut = IntentSignature::sign(&key, &intent_hash());
+        assert_eq!(sut.public_key, key.public_key());
+        assert_eq!(sut.signature.len(), 64);
+        assert!(sut.scrypto_signature(&intent_hash()).is_ok());
+    }
+
+    #[test]
+    fn sign_and_convert_secp256k1() {
+        let key: PrivateKey = Secp256k1PrivateKey::placeholder().into();
+        let sut = IntentSignature::sign(&key, &intent_hash());
+        assert_eq!(sut.signature.len(), 65);
+        assert!(sut.scrypto_signature(&intent_hash()).is_ok());
+    }
+
+    #[test]
+    fn signature_by_other_key_is_invalid() {
+        let key: PrivateKey = Ed25519PrivateKey::placeholder().into();
+        let mut sut = IntentSignature::sign(&key, &intent_hash());
+        sut.public_key =
+            Ed25519PrivateKey::placeholder_other().public_key().into();
+        assert_eq!(
+            sut.scrypto_signature(&intent_hash()),
+            Err(CommonError::InvalidIntentSignature {
+                public_key: sut.public_key.to_hex()
+            })
+        );
+    }
+
+    #[test]
+    fn signature_of_wrong_length_is_invalid() {
+        let key: PrivateKey = Secp256k1PrivateKey::placeholder().into();
+        let sut = IntentSignature::new(key.public_key(), vec![0; 64].into());
+        assert!(matches!(
+            sut.scrypto_signature(&intent_hash()),
+            Err(CommonError::InvalidIntentSignature { .. })
+        ));
+    }
+}
@@ -0,0 +1,930 @@
+use crate::prelude::*;
+
+use radix_engine_common::data::scrypto::model::NonFungibleLocalId as ScryptoNonFungibleLocalId;
+use radix_engine_common::math::{
+    CheckedAdd, CheckedSub, Decimal as ScryptoDecimal,
+};
+use radix_engine_common::prelude::{
+    manifest_decode, manifest_encode, ManifestBucket as ScryptoManifestBucket,
+    ManifestDecode as ScryptoManifestDecode,
+    ManifestExpression as ScryptoManifestExpression,
+    ManifestValue as ScryptoManifestValue,
+};
+use radix_engine_common::types::ResourceAddress as ScryptoResourceAddress;
+use radix_engine_toolkit::functions::manifest::summary as ret_summary;
+use transaction::manifest::decompile as scrypto_decompile;
+use transaction::prelude::{
+    DynamicGlobalAddress as ScryptoDynamicGlobalAddress,
+    InstructionV1 as ScryptoInstruction,
+};
+
+/// Statically analyzes `manifest`, summarizing what it does to each account.
+#[uniffi::export]
+pub fn transaction_manifest_summary(
+    manifest: &TransactionManifest,
+) -> Result<ManifestSummary> {
+    manifest.summary()
+}
+
+impl TransactionManifest {
+    /// Statically analyzes this manifest, summarizing what it does to each
+    /// account, none of the entities are flagged as controlled by the user,
+    /// use [`Profile::analyze_manifest`] for that. Fails if the
+    /// instructions are invalid, see [`TransactionManifest::new`], or if
+    /// the amounts put on the worktop overflow.
+    pub fn summary(&self) -> Result<ManifestSummary> {
+        let scrypto_manifest = self.scrypto_manifest()?;
+        let ret = ret_summary(&scrypto_manifest);
+
+        let mut analyzer = InstructionsAnalyzer::new(self.network_id);
+        for (index, instruction) in
+            scrypto_manifest.instructions.iter().enumerate()
+        {
+            analyzer.process(index, instruction)?;
+        }
+
+        for address in ret.accounts_requiring_auth.iter() {
+            if let Ok(address) = AccountAddress::try_from_node_id(
+                *address.as_node_id(),
+                self.network_id,
+            ) {
+                analyzer.account_summary(address).requires_signature = true;
+            }
+        }
+
+        let presented_proofs = ret
+            .presented_proofs
+            .iter()
+            .map(|r| analyzer.resource_address(r))
+            .collect_vec();
+
+        let personas_requiring_auth = ret
+            .identities_requiring_auth
+            .iter()
+            .filter_map(|a| {
+                IdentityAddress::try_from_node_id(
+                    *a.as_node_id(),
+                    self.network_id,
+                )
+                .ok()
+            })
+            .map(|address| PersonaRequiringAuth {
+                address,
+                is_controlled_by_user: false,
+            })
+            .collect_vec();
+
+        Ok(ManifestSummary {
+            accounts: analyzer.accounts,
+            presented_proofs,
+            personas_requiring_auth,
+            unrecognized_instructions: analyzer.unrecognized_instructions,
+        })
+    }
+}
+
+impl Profile {
+    /// Statically analyzes `manifest`, flagging the accounts and personas
+    /// which are in this Profile as controlled by the user.
+    pub fn analyze_manifest(
+        &self,
+        manifest: &TransactionManifest,
+    ) -> Result<ManifestSummary> {
+        let mut summary = manifest.summary()?;
+        for account in summary.accounts.iter_mut() {
+            account.is_controlled_by_user =
+                self.networks.get_account(&account.address).is_some();
+        }
+        for persona in summary.personas_requiring_auth.iter_mut() {
+            persona.is_controlled_by_user =
+                self.networks.get_persona(&persona.address).is_some();
+        }
+        Ok(summary)
+    }
+}
+
+/// A known amount of a resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Holding {
+    Fungible(ScryptoDecimal),
+    NonFungible(Vec<ScryptoNonFungibleLocalId>),
+}
+
+/// The content of a bucket, either known statically or decided during
+/// execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum BucketContent {
+    Known(Holding),
+    Unknown {
+        guaranteed_minimum: Option<ScryptoDecimal>,
+        instruction_index: usize,
+    },
+}
+
+/// Tracks the resources on the worktop and in buckets while walking the
+/// instructions of a manifest.
+///
+/// The worktop is "exact" as long as everything put on it was withdrawn
+/// from accounts, once an unrecognized instruction - e.g. a call to a dApp
+/// component - has been executed the worktop might hold resources of
+/// unknown amounts, which then can only be bounded by assertions.
+struct InstructionsAnalyzer {
+    network_id: NetworkID,
+    accounts: Vec<AccountManifestSummary>,
+    unrecognized_instructions: Vec<String>,
+    worktop: Vec<(ScryptoResourceAddress, Holding)>,
+    worktop_is_exact: bool,
+    asserted_minimums: Vec<(ScryptoResourceAddress, ScryptoDecimal)>,
+    buckets: HashMap<u32, (ScryptoResourceAddress, BucketContent)>,
+    next_bucket_id: u32,
+}
+
+const ACCOUNT_METHODS_WITHOUT_TRANSFERS: &[&str] = &[
+    "lock_fee",
+    "lock_contingent_fee",
+    "create_proof_of_amount",
+    "create_proof_of_non_fungibles",
+    "set_default_deposit_rule",
+    "set_resource_preference",
+    "remove_resource_preference",
+    "add_authorized_depositor",
+    "remove_authorized_depositor",
+];
+
+impl InstructionsAnalyzer {
+    fn new(network_id: NetworkID) -> Self {
+        Self {
+            network_id,
+            accounts: Vec::new(),
+            unrecognized_instructions: Vec::new(),
+            worktop: Vec::new(),
+            worktop_is_exact: true,
+            asserted_minimums: Vec::new(),
+            buckets: HashMap::new(),
+            next_bucket_id: 0,
+        }
+    }
+
+    fn account_summary(
+        &mut self,
+        address: AccountAddress,
+    ) -> &mut AccountManifestSummary {
+        let index =
+            match self.accounts.iter().position(|a| a.address == address) {
+                Some(index) => index,
+                None => {
+                    self.accounts.push(AccountManifestSummary::new(address));
+                    self.accounts.len() - 1
+                }
+            };
+        &mut self.accounts[index]
+    }
+
+    fn account_address(
+        &self,
+        address: &ScryptoDynamicGlobalAddress,
+    ) -> Option<AccountAddress> {
+        match address {
+            ScryptoDynamicGlobalAddress::Static(address) => {
+                AccountAddress::try_from_node_id(
+                    *address.as_node_id(),
+                    self.network_id,
+                )
+                .ok()
+            }
+            _ => None,
+        }
+    }
+
+    fn resource_address(
+        &self,
+        resource_address: &ScryptoResourceAddress,
+    ) -> ResourceAddress {
+        ResourceAddress::try_from_node_id(
+            *resource_address.as_node_id(),
+            self.network_id,
+        )
+        .expect("Engine resource addresses to always be valid.")
+    }
+
+    fn resource_amount(holding: Holding) -> ResourceAmount {
+        match holding {
+            Holding::Fungible(amount) => ResourceAmount::Fungible {
+                amount: Decimal::from_native(amount),
+            },
+            Holding::NonFungible(ids) => ResourceAmount::NonFungible {
+                ids: ids.into_iter().map(NonFungibleLocalId::from).collect(),
+            },
+        }
+    }
+
+    /// Fails with [`CommonError::DecimalError`] if the amounts on the
+    /// worktop overflow, which only a malicious manifest can cause.
+    fn process(
+        &mut self,
+        index: usize,
+        instruction: &ScryptoInstruction,
+    ) -> Result<()> {
+        match instruction {
+            ScryptoInstruction::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let recognized = match self.account_address(address) {
+                    Some(account) => self.process_account_method(
+                        index,
+                        account,
+                        method_name,
+                        args,
+                    )?,
+                    None => false,
+                };
+                if !recognized {
+                    self.unrecognized(instruction);
+                }
+            }
+            ScryptoInstruction::CallMetadataMethod { address, .. }
+            | ScryptoInstruction::CallRoyaltyMethod { address, .. }
+            | ScryptoInstruction::CallRoleAssignmentMethod {
+                address, ..
+            } => match self.account_address(address) {
+                Some(account) => _ = self.account_summary(account),
+                None => self.unrecognized(instruction),
+            },
+            ScryptoInstruction::CallFunction { .. }
+            | ScryptoInstruction::CallDirectVaultMethod { .. }
+            | ScryptoInstruction::BurnResource { .. }
+            | ScryptoInstruction::AllocateGlobalAddress { .. } => {
+                self.unrecognized(instruction)
+            }
+            ScryptoInstruction::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                let holding = Holding::Fungible(*amount);
+                self.remove_from_worktop(resource_address, &holding)?;
+                self.new_bucket(
+                    *resource_address,
+                    BucketContent::Known(holding),
+                );
+            }
+            ScryptoInstruction::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => {
+                let holding = Holding::NonFungible(ids.to_vec());
+                self.remove_from_worktop(resource_address, &holding)?;
+                self.new_bucket(
+                    *resource_address,
+                    BucketContent::Known(holding),
+                );
+            }
+            ScryptoInstruction::TakeAllFromWorktop { resource_address } => {
+                let content =
+                    self.take_all_from_worktop(index, resource_address);
+                self.new_bucket(*resource_address, content);
+            }
+            ScryptoInstruction::ReturnToWorktop { bucket_id } => {
+                match self.buckets.remove(&bucket_id.0) {
+                    Some((resource_address, BucketContent::Known(holding))) => {
+                        self.add_to_worktop(resource_address, holding)?
+                    }
+                    _ => self.worktop_is_exact = false,
+                }
+            }
+            ScryptoInstruction::AssertWorktopContains {
+                resource_address,
+                amount,
+            } => {
+                self.asserted_minimums.push((*resource_address, *amount));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Returns `false` if the call is not recognized.
+    fn process_account_method(
+        &mut self,
+        index: usize,
+        account: AccountAddress,
+        method_name: &str,
+        args: &ScryptoManifestValue,
+    ) -> Result<bool> {
+        let recognized = match method_name {
+            m if ACCOUNT_METHODS_WITHOUT_TRANSFERS.contains(&m) => {
+                _ = self.account_summary(account);
+                true
+            }
+            "withdraw" => {
+                decode_args::<(ScryptoResourceAddress, ScryptoDecimal)>(args)
+                    .map(|(r, amount)| {
+                        self.withdraw(account, r, Holding::Fungible(amount))
+                    })
+                    .transpose()?
+                    .is_some()
+            }
+            "lock_fee_and_withdraw" => decode_args::<(
+                ScryptoDecimal,
+                ScryptoResourceAddress,
+                ScryptoDecimal,
+            )>(args)
+            .map(|(_, r, amount)| {
+                self.withdraw(account, r, Holding::Fungible(amount))
+            })
+            .transpose()?
+            .is_some(),
+            "withdraw_non_fungibles" => decode_args::<(
+                ScryptoResourceAddress,
+                Vec<ScryptoNonFungibleLocalId>,
+            )>(args)
+            .map(|(r, ids)| {
+                self.withdraw(account, r, Holding::NonFungible(ids))
+            })
+            .transpose()?
+            .is_some(),
+            "lock_fee_and_withdraw_non_fungibles" => decode_args::<(
+                ScryptoDecimal,
+                ScryptoResourceAddress,
+                Vec<ScryptoNonFungibleLocalId>,
+            )>(args)
+            .map(|(_, r, ids)| {
+                self.withdraw(account, r, Holding::NonFungible(ids))
+            })
+            .transpose()?
+            .is_some(),
+            "deposit" | "try_deposit_or_abort" | "try_deposit_or_refund" => {
+                decode_first_field::<ScryptoManifestBucket>(args)
+                    .map(|bucket| self.deposit_bucket(&account, bucket))
+                    .unwrap_or(false)
+            }
+            "deposit_batch"
+            | "try_deposit_batch_or_abort"
+            | "try_deposit_batch_or_refund" => {
+                if let Some(ScryptoManifestExpression::EntireWorktop) =
+                    decode_first_field::<ScryptoManifestExpression>(args)
+                {
+                    self.deposit_entire_worktop(index, &account);
+                    true
+                } else if let Some(buckets) =
+                    decode_first_field::<Vec<ScryptoManifestBucket>>(args)
+                {
+                    buckets
+                        .into_iter()
+                        .all(|bucket| self.deposit_bucket(&account, bucket))
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        };
+        Ok(recognized)
+    }
+
+    fn unrecognized(&mut self, instruction: &ScryptoInstruction) {
+        // Any resources returned by the call end up on the worktop.
+        self.worktop_is_exact = false;
+        let instruction = scrypto_decompile(
+            &[instruction.clone()],
+            &self.network_id.network_definition(),
+        )
+        .map(|s| s.trim().to_owned())
+        .unwrap_or_else(|_| format!("{:?}", instruction));
+        self.unrecognized_instructions.push(instruction);
+    }
+
+    fn withdraw(
+        &mut self,
+        account: AccountAddress,
+        resource_address: ScryptoResourceAddress,
+        holding: Holding,
+    ) -> Result<()> {
+        let transfer = ResourceTransfer {
+            resource_address: self.resource_address(&resource_address),
+            amount: Self::resource_amount(holding.clone()),
+        };
+        self.account_summary(account).withdrawals.push(transfer);
+        self.add_to_worktop(resource_address, holding)
+    }
+
+    fn deposit_bucket(
+        &mut self,
+        account: &AccountAddress,
+        bucket: ScryptoManifestBucket,
+    ) -> bool {
+        let Some((resource_address, content)) = self.buckets.remove(&bucket.0)
+        else {
+            return false;
+        };
+        let deposit = self.deposit(&resource_address, content);
+        self.account_summary(account.clone()).deposits.push(deposit);
+        true
+    }
+
+    fn deposit_entire_worktop(
+        &mut self,
+        index: usize,
+        account: &AccountAddress,
+    ) {
+        let mut contents = self
+            .worktop
+            .drain(..)
+            .map(|(r, holding)| (r, BucketContent::Known(holding)))
+            .collect_vec();
+
+        if !self.worktop_is_exact {
+            // Known amounts are only lower bounds now.
+            contents = contents
+                .into_iter()
+                .map(|(r, content)| {
+                    let known = match content {
+                        BucketContent::Known(Holding::Fungible(amount)) => {
+                            Some(amount)
+                        }
+                        _ => None,
+                    };
+                    (r, self.unknown_content(index, &r, known))
+                })
+                .collect_vec();
+
+            let asserted = self
+                .asserted_minimums
+                .iter()
+                .map(|(r, _)| *r)
+                .unique()
+                .filter(|r| !contents.iter().any(|(c, _)| c == r))
+                .collect_vec();
+            for r in asserted {
+                let content = self.unknown_content(index, &r, None);
+                contents.push((r, content));
+            }
+        }
+
+        for (resource_address, content) in contents {
+            let deposit = self.deposit(&resource_address, content);
+            self.account_summary(account.clone()).deposits.push(deposit);
+        }
+
+        self.asserted_minimums.clear();
+        self.worktop_is_exact = true;
+    }
+
+    fn deposit(
+        &self,
+        resource_address: &ScryptoResourceAddress,
+        content: BucketContent,
+    ) -> AccountDeposit {
+        let amount = match content {
+            BucketContent::Known(holding) => DepositAmount::Guaranteed {
+                amount: Self::resource_amount(holding),
+            },
+            BucketContent::Unknown {
+                guaranteed_minimum,
+                instruction_index,
+            } => DepositAmount::Estimated {
+                guaranteed_minimum: guaranteed_minimum
+                    .map(Decimal::from_native),
+                instruction_index: instruction_index as u64,
+            },
+        };
+        AccountDeposit {
+            resource_address: self.resource_address(resource_address),
+            amount,
+        }
+    }
+
+    /// The content of an unknown amount of `resource_address` leaving the
+    /// worktop at instruction `index`, at least `known` or any asserted
+    /// amount.
+    fn unknown_content(
+        &self,
+        index: usize,
+        resource_address: &ScryptoResourceAddress,
+        known: Option<ScryptoDecimal>,
+    ) -> BucketContent {
+        let asserted = self
+            .asserted_minimums
+            .iter()
+            .filter(|(r, _)| r == resource_address)
+            .map(|(_, amount)| *amount)
+            .max();
+        BucketContent::Unknown {
+            guaranteed_minimum: asserted.into_iter().chain(known).max(),
+            instruction_index: index,
+        }
+    }
+
+    fn new_bucket(
+        &mut self,
+        resource_address: ScryptoResourceAddress,
+        content: BucketContent,
+    ) {
+        self.buckets
+            .insert(self.next_bucket_id, (resource_address, content));
+        self.next_bucket_id += 1;
+    }
+
+    fn take_all_from_worktop(
+        &mut self,
+        index: usize,
+        resource_address: &ScryptoResourceAddress,
+    ) -> BucketContent {
+        let position =
+            self.worktop.iter().position(|(r, _)| r == resource_address);
+        let holding = position.map(|p| self.worktop.remove(p).1);
+
+        if self.worktop_is_exact {
+            return BucketContent::Known(
+                holding.unwrap_or(Holding::Fungible(ScryptoDecimal::zero())),
+            );
+        }
+        let known = match holding {
+            Some(Holding::Fungible(amount)) => Some(amount),
+            _ => None,
+        };
+        self.unknown_content(index, resource_address, known)
+    }
+
+    fn add_to_worktop(
+        &mut self,
+        resource_address: ScryptoResourceAddress,
+        holding: Holding,
+    ) -> Result<()> {
+        let existing = self
+            .worktop
+            .iter_mut()
+            .find(|(r, _)| *r == resource_address)
+            .map(|(_, h)| h);
+        match (existing, holding) {
+            (Some(Holding::Fungible(existing)), Holding::Fungible(amount)) => {
+                *existing = existing
+                    .checked_add(amount)
+                    .ok_or(CommonError::DecimalError)?;
+            }
+            (
+                Some(Holding::NonFungible(existing)),
+                Holding::NonFungible(ids),
+            ) => {
+                existing.extend(ids);
+            }
+            (_, holding) => self.worktop.push((resource_address, holding)),
+        }
+        Ok(())
+    }
+
+    fn remove_from_worktop(
+        &mut self,
+        resource_address: &ScryptoResourceAddress,
+        holding: &Holding,
+    ) -> Result<()> {
+        let Some(position) =
+            self.worktop.iter().position(|(r, _)| r == resource_address)
+        else {
+            return Ok(());
+        };
+        let is_empty = match (&mut self.worktop[position].1, holding) {
+            (Holding::Fungible(existing), Holding::Fungible(amount)) => {
+                *existing = existing
+                    .checked_sub(*amount)
+                    .ok_or(CommonError::DecimalError)?;
+                !existing.is_positive()
+            }
+            (Holding::NonFungible(existing), Holding::NonFungible(ids)) => {
+                existing.retain(|id| !ids.contains(id));
+                existing.is_empty()
+            }
+            _ => false,
+        };
+        if is_empty {
+            self.worktop.remove(position);
+        }
+        Ok(())
+    }
+}
+
+fn decode_args<T: ScryptoManifestDecode>(
+    args: &ScryptoManifestValue,
+) -> Option<T> {
+    manifest_decode(&manifest_encode(args).ok()?).ok()
+}
+
+fn decode_first_field<T: ScryptoManifestDecode>(
+    args: &ScryptoManifestValue,
+) -> Option<T> {
+    let ScryptoManifestValue::Tuple { fields } = args else {
+        return None;
+    };
+    manifest_decode(&manifest_encode(fields.first()?).ok()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use radix_engine_common::math::Decimal as ScryptoDecimal;
+    use transaction::prelude::{
+        ComponentAddress as ScryptoComponentAddress,
+        ManifestBuilder as ScryptoManifestBuilder, XRD,
+    };
+
+    fn carol() -> AccountAddress {
+        AccountAddress::placeholder_stokenet()
+    }
+
+    fn external() -> AccountAddress {
+        AccountAddress::try_from_bech32(
+            "account_tdx_2_12yf9gd53yfep7a669fv2t3wm7nz9zeezwd04n02a433ker8vza6rhe",
+        )
+        .unwrap()
+    }
+
+    fn amount(s: &str) -> Decimal {
+        Decimal::try_from_str(s).unwrap()
+    }
+
+    fn xrd() -> ResourceAddress {
+        ResourceAddress::placeholder_stokenet_xrd()
+    }
+
+    /// A stokenet manifest of `instructions`, where `{carol}`, `{external}`
+    /// and `{xrd}` are replaced with their addresses.
+    fn manifest(instructions: &str) -> TransactionManifest {
+        let instructions = instructions
+            .replace("{carol}", &carol().address)
+            .replace("{external}", &external().address)
+            .replace("{xrd}", &xrd().address);
+        TransactionManifest::new(instructions, NetworkID::Stokenet, Vec::new())
+            .unwrap()
+    }
+
+    fn fungible(amount: Decimal) -> ResourceAmount {
+        ResourceAmount::Fungible { amount }
+    }
+
+    fn guaranteed_xrd(s: &str) -> AccountDeposit {
+        AccountDeposit {
+            resource_address: xrd(),
+            amount: DepositAmount::Guaranteed {
+                amount: fungible(amount(s)),
+            },
+        }
+    }
+
+    #[test]
+    fn fungible_transfer_from_profile_account() {
+        let manifest = TransactionManifest::single_fungible_transfer(
+            &carol(),
+            external(),
+            ResourceAddress::placeholder_stokenet_xrd(),
+            amount("1337"),
+        )
+        .unwrap();
+        let sut = Profile::placeholder().analyze_manifest(&manifest).unwrap();
+
+        let sender = sut.account(&carol()).unwrap();
+        assert!(sender.is_controlled_by_user);
+        assert!(sender.requires_signature);
+        assert_eq!(
+            sender.withdrawals,
+            vec![ResourceTransfer {
+                resource_address: ResourceAddress::placeholder_stokenet_xrd(),
+                amount: ResourceAmount::Fungible {
+                    amount: amount("1337")
+                }
+            }]
+        );
+        assert!(sender.deposits.is_empty());
+
+        let recipient = sut.account(&external()).unwrap();
+        assert!(!recipient.is_controlled_by_user);
+        assert!(!recipient.requires_signature);
+        assert_eq!(
+            recipient.deposits,
+            vec![AccountDeposit {
+                resource_address: ResourceAddress::placeholder_stokenet_xrd(),
+                amount: DepositAmount::Guaranteed {
+                    amount: ResourceAmount::Fungible {
+                        amount: amount("1337")
+                    }
+                }
+            }]
+        );
+        assert!(sut.unrecognized_instructions.is_empty());
+    }
+
+    #[test]
+    fn non_fungibles_transfer() {
+        let manifest = TransactionManifest::non_fungibles_transfer(
+            &AccountAddress::placeholder_mainnet(),
+            AccountAddress::placeholder_mainnet_other(),
+            [
+                NonFungibleGlobalId::placeholder(),
+                NonFungibleGlobalId::placeholder_other(),
+            ],
+        )
+        .unwrap();
+        let sut = manifest.summary().unwrap();
+        let ids = ResourceAmount::NonFungible {
+            ids: vec![
+                NonFungibleGlobalId::placeholder().non_fungible_local_id,
+                NonFungibleGlobalId::placeholder_other().non_fungible_local_id,
+            ],
+        };
+        assert_eq!(
+            sut.account(&AccountAddress::placeholder_mainnet())
+                .unwrap()
+                .withdrawals[0]
+                .amount,
+            ids
+        );
+        assert_eq!(
+            sut.account(&AccountAddress::placeholder_mainnet_other())
+                .unwrap()
+                .deposits[0]
+                .amount,
+            DepositAmount::Guaranteed { amount: ids }
+        );
+    }
+
+    #[test]
+    fn faucet_deposit_is_estimated_after_assertion() {
+        let receiver: ScryptoComponentAddress = carol().into();
+        let scrypto_manifest = ScryptoManifestBuilder::new()
+            .get_free_xrd_from_faucet()
+            .assert_worktop_contains(XRD, amount("10").native())
+            .try_deposit_entire_worktop_or_abort(receiver, None)
+            .build();
+        let manifest = TransactionManifest::from_scrypto(
+            scrypto_manifest,
+            NetworkID::Stokenet,
+        )
+        .unwrap();
+
+        let sut = transaction_manifest_summary(&manifest).unwrap();
+        assert_eq!(sut.unrecognized_instructions.len(), 1);
+        assert!(sut.unrecognized_instructions[0].contains("\"free\""));
+        assert_eq!(
+            sut.account(&carol()).unwrap().deposits,
+            vec![AccountDeposit {
+                resource_address: ResourceAddress::placeholder_stokenet_xrd(),
+                amount: DepositAmount::Estimated {
+                    guaranteed_minimum: Some(amount("10")),
+                    instruction_index: 2
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn unknown_persona_is_not_controlled_by_user() {
+        let profile = Profile::placeholder();
+        let sut = profile
+            .analyze_manifest(
+                &TransactionManifest::set_account_metadata(
+                    &external(),
+                    "name",
+                    MetadataValue::Str {
+                        value: "Other".to_owned(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        let account = sut.account(&external()).unwrap();
+        assert!(!account.is_controlled_by_user);
+        assert!(sut.personas_requiring_auth.is_empty());
+        assert!(sut.unrecognized_instructions.is_empty());
+    }
+
+    #[test]
+    fn summary_of_unvalidated_manifest_fails() {
+        let manifest = TransactionManifest {
+            instructions: "CALL_METHOD;".to_owned(),
+            network_id: NetworkID::Mainnet,
+            blobs: Vec::new(),
+        };
+        assert!(matches!(
+            transaction_manifest_summary(&manifest),
+            Err(CommonError::InvalidTransactionManifest(_))
+        ));
+    }
+
+    #[test]
+    fn deposit_batch_of_buckets() {
+        let sut = manifest(
+            r#"
+            CALL_METHOD Address("{carol}") "withdraw" Address("{xrd}") Decimal("10");
+            TAKE_FROM_WORKTOP Address("{xrd}") Decimal("4") Bucket("first");
+            TAKE_FROM_WORKTOP Address("{xrd}") Decimal("6") Bucket("second");
+            CALL_METHOD Address("{external}") "deposit_batch" Array<Bucket>(Bucket("first"), Bucket("second"));
+            "#,
+        )
+        .summary()
+        .unwrap();
+        assert_eq!(
+            sut.account(&external()).unwrap().deposits,
+            vec![guaranteed_xrd("4"), guaranteed_xrd("6")]
+        );
+        assert!(sut.unrecognized_instructions.is_empty());
+    }
+
+    #[test]
+    fn take_all_from_worktop() {
+        let sut = manifest(
+            r#"
+            CALL_METHOD Address("{carol}") "withdraw" Address("{xrd}") Decimal("10");
+            CALL_METHOD Address("{carol}") "withdraw" Address("{xrd}") Decimal("5");
+            TAKE_ALL_FROM_WORKTOP Address("{xrd}") Bucket("all");
+            CALL_METHOD Address("{external}") "deposit" Bucket("all");
+            "#,
+        )
+        .summary()
+        .unwrap();
+        assert_eq!(
+            sut.account(&external()).unwrap().deposits,
+            vec![guaranteed_xrd("15")]
+        );
+    }
+
+    #[test]
+    fn return_to_worktop() {
+        let sut = manifest(
+            r#"
+            CALL_METHOD Address("{carol}") "withdraw" Address("{xrd}") Decimal("10");
+            TAKE_FROM_WORKTOP Address("{xrd}") Decimal("3") Bucket("returned");
+            RETURN_TO_WORKTOP Bucket("returned");
+            CALL_METHOD Address("{external}") "deposit_batch" Expression("ENTIRE_WORKTOP");
+            "#,
+        )
+        .summary()
+        .unwrap();
+        assert_eq!(
+            sut.account(&external()).unwrap().deposits,
+            vec![guaranteed_xrd("10")]
+        );
+    }
+
+    #[test]
+    fn lock_fee_and_withdraw() {
+        let sut = manifest(
+            r#"
+            CALL_METHOD Address("{carol}") "lock_fee_and_withdraw" Decimal("1") Address("{xrd}") Decimal("10");
+            CALL_METHOD Address("{external}") "deposit_batch" Expression("ENTIRE_WORKTOP");
+            "#,
+        )
+        .summary()
+        .unwrap();
+        assert_eq!(
+            sut.account(&carol()).unwrap().withdrawals,
+            vec![ResourceTransfer {
+                resource_address: xrd(),
+                amount: fungible(amount("10")),
+            }]
+        );
+        assert_eq!(
+            sut.account(&external()).unwrap().deposits,
+            vec![guaranteed_xrd("10")]
+        );
+        assert!(sut.unrecognized_instructions.is_empty());
+    }
+
+    #[test]
+    fn worktop_overflow_is_an_error() {
+        let max = ScryptoDecimal::MAX.to_string();
+        let sut = manifest(&format!(
+            r#"
+            CALL_METHOD Address("{{carol}}") "withdraw" Address("{{xrd}}") Decimal("{max}");
+            CALL_METHOD Address("{{carol}}") "withdraw" Address("{{xrd}}") Decimal("{max}");
+            CALL_METHOD Address("{{external}}") "deposit_batch" Expression("ENTIRE_WORKTOP");
+            "#
+        ));
+        assert_eq!(sut.summary(), Err(CommonError::DecimalError));
+        assert_eq!(
+            Profile::placeholder().analyze_manifest(&sut),
+            Err(CommonError::DecimalError)
+        );
+        assert_eq!(
+            sut.select_fee_payer(
+                &amount("1"),
+                &Decimal::zero(),
+                [FeePayerCandidate::new(carol(), amount("100"))]
+            ),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn worktop_underflow_is_an_error() {
+        let sut = manifest(&format!(
+            r#"
+            CALL_METHOD Address("{{carol}}") "withdraw" Address("{{xrd}}") Decimal("{max}");
+            TAKE_FROM_WORKTOP Address("{{xrd}}") Decimal("{min}") Bucket("bucket");
+            CALL_METHOD Address("{{external}}") "deposit" Bucket("bucket");
+            "#,
+            max = ScryptoDecimal::MAX,
+            min = ScryptoDecimal::MIN,
+        ));
+        assert_eq!(sut.summary(), Err(CommonError::DecimalError));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+/// A summary of what a manifest does to the accounts it touches, built
+/// using static analysis of the manifest, i.e. without executing it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ManifestSummary {
+    /// The withdrawals from, deposits into and required signatures of each
+    /// account touched by the manifest, in order of first appearance.
+    pub accounts: Vec<AccountManifestSummary>,
+
+    /// The resources of the proofs presented in the manifest, e.g. badges.
+    pub presented_proofs: Vec<ResourceAddress>,
+
+    /// Personas which must sign the transaction.
+    pub personas_requiring_auth: Vec<PersonaRequiringAuth>,
+
+    /// Instructions which the wallet cannot summarize, e.g. calls to dApp
+    /// components, in the textual manifest format.
+    pub unrecognized_instructions: Vec<String>,
+}
+
+impl ManifestSummary {
+    /// The summary of the account with `address`, if it is touched by the
+    /// manifest.
+    pub fn account(
+        &self,
+        address: &AccountAddress,
+    ) -> Option<&AccountManifestSummary> {
+        self.accounts.iter().find(|a| &a.address == address)
+    }
+}
+
+/// What a manifest does to one account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AccountManifestSummary {
+    pub address: AccountAddress,
+
+    /// `true` if the account is in Profile, i.e. controlled by the user.
+    pub is_controlled_by_user: bool,
+
+    /// `true` if the account must sign the transaction.
+    pub requires_signature: bool,
+
+    pub withdrawals: Vec<ResourceTransfer>,
+
+    pub deposits: Vec<AccountDeposit>,
+}
+
+impl AccountManifestSummary {
+    pub(crate) fn new(address: AccountAddress) -> Self {
+        Self {
+            address,
+            is_controlled_by_user: false,
+            requires_signature: false,
+            withdrawals: Vec::new(),
+            deposits: Vec::new(),
+        }
+    }
+}
+
+/// A Persona which must sign the transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct PersonaRequiringAuth {
+    pub address: IdentityAddress,
+
+    /// `true` if the persona is in Profile, i.e. controlled by the user.
+    pub is_controlled_by_user: bool,
+}
+
+/// An amount of a fungible resource or a set of non-fungibles.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum ResourceAmount {
+    Fungible { amount: Decimal },
+    NonFungible { ids: Vec<NonFungibleLocalId> },
+}
+
+/// A known amount of the resource `resource_address`, e.g. withdrawn from
+/// an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ResourceTransfer {
+    pub resource_address: ResourceAddress,
+    pub amount: ResourceAmount,
+}
+
+/// A deposit of the resource `resource_address` into an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AccountDeposit {
+    pub resource_address: ResourceAddress,
+    pub amount: DepositAmount,
+}
+
+/// The amount deposited, either guaranteed by the manifest itself, or only
+/// known once the transaction is executed, e.g. the output of a swap.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum DepositAmount {
+    /// The manifest deposits exactly `amount`, or fails.
+    Guaranteed { amount: ResourceAmount },
+
+    /// The amount is decided by some component during execution,
+    /// `guaranteed_minimum` is set if the manifest asserts a minimum amount.
+    /// The resource leaves the worktop at instruction `instruction_index`,
+    /// which is where a guarantee can be asserted.
+    Estimated {
+        guaranteed_minimum: Option<Decimal>,
+        instruction_index: u64,
+    },
+}
@@ -0,0 +1,5 @@
+mod manifest_analyzer;
+mod manifest_summary;
+
+pub use manifest_analyzer::*;
+pub use manifest_summary::*;
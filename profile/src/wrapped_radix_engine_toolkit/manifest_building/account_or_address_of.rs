@@ -0,0 +1,54 @@
+use crate::prelude::*;
+
+/// The recipient of a transfer, either one of the accounts in Profile or the
+/// address of some other account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum AccountOrAddressOf {
+    ProfileAccount { value: Account },
+    AddressOfExternalAccount { value: AccountAddress },
+}
+
+impl AccountOrAddressOf {
+    /// The address of the recipient account.
+    pub fn account_address(&self) -> &AccountAddress {
+        match self {
+            Self::ProfileAccount { value } => &value.address,
+            Self::AddressOfExternalAccount { value } => value,
+        }
+    }
+}
+
+impl From<Account> for AccountOrAddressOf {
+    fn from(value: Account) -> Self {
+        Self::ProfileAccount { value }
+    }
+}
+
+impl From<AccountAddress> for AccountOrAddressOf {
+    fn from(value: AccountAddress) -> Self {
+        Self::AddressOfExternalAccount { value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountOrAddressOf;
+
+    #[test]
+    fn account_address_of_profile_account() {
+        let account = Account::placeholder_stokenet_carol();
+        assert_eq!(
+            SUT::from(account.clone()).account_address(),
+            &account.address
+        );
+    }
+
+    #[test]
+    fn account_address_of_external_account() {
+        let address = AccountAddress::placeholder_mainnet();
+        assert_eq!(SUT::from(address.clone()).account_address(), &address);
+    }
+}
@@ -0,0 +1,380 @@
+use crate::prelude::*;
+
+use radix_engine_interface::blueprints::access_controller::{
+    RuleSet as ScryptoRuleSet,
+    ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT as SCRYPTO_CANCEL_RECOVERY_IDENT,
+    ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT as SCRYPTO_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+    ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT as SCRYPTO_LOCK_PRIMARY_ROLE_IDENT,
+    ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT as SCRYPTO_QUICK_CONFIRM_RECOVERY_IDENT,
+    ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT as SCRYPTO_TIMED_CONFIRM_RECOVERY_IDENT,
+    ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT as SCRYPTO_UNLOCK_PRIMARY_ROLE_IDENT,
+};
+use transaction::prelude::{
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+    ResolvableArguments as ScryptoResolvableArguments,
+};
+
+/// A role of an AccessController.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum AccessControllerRole {
+    /// The role used for signing transactions of the securified entity.
+    Primary,
+
+    /// The role used to initiate recovery, cancel it and to lock and unlock
+    /// the primary role.
+    Recovery,
+
+    /// The role used to confirm a recovery initiated by the recovery role.
+    Confirmation,
+}
+
+/// A manifest calling an AccessController and the factors of the role which
+/// must sign it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AccessControllerManifest {
+    pub manifest: TransactionManifest,
+
+    /// The role of the AccessController which must sign the manifest.
+    pub signing_role: AccessControllerRole,
+
+    /// The factor instances of `signing_role` as recorded in the security
+    /// state of the entity, `threshold` many of the threshold factors or any
+    /// of the override factors must sign.
+    pub signers: RoleOfFactorInstances,
+
+    /// The factor sources of `signers`, without duplicates.
+    pub signing_factor_sources: Vec<FactorSourceIDFromHash>,
+}
+
+/// Creates the manifest initiating recovery of the securified entity with
+/// `security_state` using its recovery role, proposing `proposed`, see
+/// [`AccessControllerManifest::initiate_recovery_as_recovery`].
+#[uniffi::export]
+pub fn manifest_initiate_recovery_as_recovery(
+    security_state: &EntitySecurityState,
+    proposed: SecurityStructureOfFactorInstances,
+) -> Result<AccessControllerManifest> {
+    AccessControllerManifest::initiate_recovery_as_recovery(
+        security_state,
+        proposed,
+    )
+}
+
+/// Creates the manifest confirming the recovery proposal `proposed` using the
+/// confirmation role, see [`AccessControllerManifest::confirm_recovery`].
+#[uniffi::export]
+pub fn manifest_confirm_recovery(
+    security_state: &EntitySecurityState,
+    proposed: SecurityStructureOfFactorInstances,
+) -> Result<AccessControllerManifest> {
+    AccessControllerManifest::confirm_recovery(security_state, proposed)
+}
+
+/// Creates the manifest confirming the recovery proposal `proposed` once the
+/// delay has passed, see [`AccessControllerManifest::timed_confirm_recovery`].
+#[uniffi::export]
+pub fn manifest_timed_confirm_recovery(
+    security_state: &EntitySecurityState,
+    proposed: SecurityStructureOfFactorInstances,
+) -> Result<AccessControllerManifest> {
+    AccessControllerManifest::timed_confirm_recovery(security_state, proposed)
+}
+
+/// Creates the manifest cancelling the recovery proposal of the recovery
+/// role, see [`AccessControllerManifest::cancel_recovery`].
+#[uniffi::export]
+pub fn manifest_cancel_recovery(
+    security_state: &EntitySecurityState,
+) -> Result<AccessControllerManifest> {
+    AccessControllerManifest::cancel_recovery(security_state)
+}
+
+/// Creates the manifest locking the primary role, see
+/// [`AccessControllerManifest::lock_primary_role`].
+#[uniffi::export]
+pub fn manifest_lock_primary_role(
+    security_state: &EntitySecurityState,
+) -> Result<AccessControllerManifest> {
+    AccessControllerManifest::lock_primary_role(security_state)
+}
+
+/// Creates the manifest unlocking the primary role, see
+/// [`AccessControllerManifest::unlock_primary_role`].
+#[uniffi::export]
+pub fn manifest_unlock_primary_role(
+    security_state: &EntitySecurityState,
+) -> Result<AccessControllerManifest> {
+    AccessControllerManifest::unlock_primary_role(security_state)
+}
+
+impl SecurityStructureOfFactorInstances {
+    /// The rules of the three roles of this structure.
+    pub(crate) fn scrypto_rule_set(&self) -> ScryptoRuleSet {
+        ScryptoRuleSet {
+            primary_role: self.primary_role.scrypto_access_rule(),
+            recovery_role: self.recovery_role.scrypto_access_rule(),
+            confirmation_role: self.confirmation_role.scrypto_access_rule(),
+        }
+    }
+
+    fn role(&self, role: AccessControllerRole) -> &RoleOfFactorInstances {
+        match role {
+            AccessControllerRole::Primary => &self.primary_role,
+            AccessControllerRole::Recovery => &self.recovery_role,
+            AccessControllerRole::Confirmation => &self.confirmation_role,
+        }
+    }
+}
+
+impl AccessControllerManifest {
+    /// Creates the manifest proposing to replace the security structure of
+    /// the securified entity with `proposed`, signed by the recovery role.
+    ///
+    /// The proposal must then be confirmed by the confirmation role, see
+    /// [`Self::confirm_recovery`], or after the delay of the current security
+    /// structure, see [`Self::timed_confirm_recovery`].
+    pub fn initiate_recovery_as_recovery(
+        security_state: &EntitySecurityState,
+        proposed: SecurityStructureOfFactorInstances,
+    ) -> Result<Self> {
+        proposed.validate()?;
+        Self::call(
+            security_state,
+            AccessControllerRole::Recovery,
+            SCRYPTO_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            transaction::manifest_args!(
+                proposed.scrypto_rule_set(),
+                Some(proposed.timed_recovery_delay_in_minutes())
+            ),
+        )
+    }
+
+    /// Creates the manifest confirming the recovery proposal `proposed` of
+    /// the recovery role, signed by the confirmation role. `proposed` must be
+    /// the same as the one passed to [`Self::initiate_recovery_as_recovery`].
+    pub fn confirm_recovery(
+        security_state: &EntitySecurityState,
+        proposed: SecurityStructureOfFactorInstances,
+    ) -> Result<Self> {
+        proposed.validate()?;
+        Self::call(
+            security_state,
+            AccessControllerRole::Confirmation,
+            SCRYPTO_QUICK_CONFIRM_RECOVERY_IDENT,
+            transaction::manifest_args!(
+                proposed.scrypto_rule_set(),
+                Some(proposed.timed_recovery_delay_in_minutes())
+            ),
+        )
+    }
+
+    /// Creates the manifest confirming the recovery proposal `proposed` of
+    /// the recovery role without the confirmation role, which the
+    /// AccessController only accepts once the number of days until auto
+    /// confirmation of the current security structure has passed. Signed by
+    /// the recovery role.
+    pub fn timed_confirm_recovery(
+        security_state: &EntitySecurityState,
+        proposed: SecurityStructureOfFactorInstances,
+    ) -> Result<Self> {
+        proposed.validate()?;
+        Self::call(
+            security_state,
+            AccessControllerRole::Recovery,
+            SCRYPTO_TIMED_CONFIRM_RECOVERY_IDENT,
+            transaction::manifest_args!(
+                proposed.scrypto_rule_set(),
+                Some(proposed.timed_recovery_delay_in_minutes())
+            ),
+        )
+    }
+
+    /// Creates the manifest cancelling the recovery proposal of the recovery
+    /// role, signed by the recovery role.
+    pub fn cancel_recovery(
+        security_state: &EntitySecurityState,
+    ) -> Result<Self> {
+        Self::call(
+            security_state,
+            AccessControllerRole::Recovery,
+            SCRYPTO_CANCEL_RECOVERY_IDENT,
+            transaction::manifest_args!(),
+        )
+    }
+
+    /// Creates the manifest locking the primary role, e.g. when a primary
+    /// factor is lost, so that it can no longer sign on behalf of the entity.
+    /// Signed by the recovery role.
+    pub fn lock_primary_role(
+        security_state: &EntitySecurityState,
+    ) -> Result<Self> {
+        Self::call(
+            security_state,
+            AccessControllerRole::Recovery,
+            SCRYPTO_LOCK_PRIMARY_ROLE_IDENT,
+            transaction::manifest_args!(),
+        )
+    }
+
+    /// Creates the manifest unlocking the primary role locked by
+    /// [`Self::lock_primary_role`]. Signed by the recovery role.
+    pub fn unlock_primary_role(
+        security_state: &EntitySecurityState,
+    ) -> Result<Self> {
+        Self::call(
+            security_state,
+            AccessControllerRole::Recovery,
+            SCRYPTO_UNLOCK_PRIMARY_ROLE_IDENT,
+            transaction::manifest_args!(),
+        )
+    }
+
+    fn call(
+        security_state: &EntitySecurityState,
+        signing_role: AccessControllerRole,
+        method_name: &str,
+        arguments: impl ScryptoResolvableArguments,
+    ) -> Result<Self> {
+        let secured = security_state.secured_entity_control()?;
+        let access_controller = &secured.access_controller_address;
+        let signers = secured.security_structure.role(signing_role).clone();
+
+        let builder = ScryptoManifestBuilder::new().call_method(
+            ScryptoComponentAddress::from(access_controller.clone()),
+            method_name,
+            arguments,
+        );
+        let manifest = TransactionManifest::from_scrypto(
+            builder.build(),
+            access_controller.network_id,
+        )?;
+
+        Ok(Self {
+            manifest,
+            signing_role,
+            signing_factor_sources: signers.factor_source_ids(),
+            signers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn securified() -> EntitySecurityState {
+        SecuredEntityControl::placeholder().into()
+    }
+
+    fn current() -> SecurityStructureOfFactorInstances {
+        SecurityStructureOfFactorInstances::placeholder()
+    }
+
+    fn proposed() -> SecurityStructureOfFactorInstances {
+        SecurityStructureOfFactorInstances::placeholder_other()
+    }
+
+    fn assert_calls(
+        sut: &AccessControllerManifest,
+        method_name: &str,
+        signing_role: AccessControllerRole,
+        signers: RoleOfFactorInstances,
+    ) {
+        let instructions = &sut.manifest.instructions;
+        assert!(instructions.contains(&format!("\"{}\"", method_name)));
+        assert!(instructions
+            .contains(&AccessControllerAddress::placeholder().address));
+        assert_eq!(sut.signing_role, signing_role);
+        assert_eq!(sut.signing_factor_sources, signers.factor_source_ids());
+        assert_eq!(sut.signers, signers);
+    }
+
+    #[test]
+    fn initiate_recovery_as_recovery() {
+        let sut =
+            manifest_initiate_recovery_as_recovery(&securified(), proposed())
+                .unwrap();
+        assert_calls(
+            &sut,
+            "initiate_recovery_as_recovery",
+            AccessControllerRole::Recovery,
+            current().recovery_role,
+        );
+        assert!(sut.manifest.instructions.contains("10080u32"));
+    }
+
+    #[test]
+    fn confirm_recovery() {
+        let sut = manifest_confirm_recovery(&securified(), proposed()).unwrap();
+        assert_calls(
+            &sut,
+            "quick_confirm_recovery_role_recovery_proposal",
+            AccessControllerRole::Confirmation,
+            current().confirmation_role,
+        );
+    }
+
+    #[test]
+    fn timed_confirm_recovery() {
+        let sut =
+            manifest_timed_confirm_recovery(&securified(), proposed()).unwrap();
+        assert_calls(
+            &sut,
+            "timed_confirm_recovery",
+            AccessControllerRole::Recovery,
+            current().recovery_role,
+        );
+    }
+
+    #[test]
+    fn cancel_recovery() {
+        assert_calls(
+            &manifest_cancel_recovery(&securified()).unwrap(),
+            "cancel_recovery_role_recovery_proposal",
+            AccessControllerRole::Recovery,
+            current().recovery_role,
+        );
+    }
+
+    #[test]
+    fn lock_and_unlock_primary_role() {
+        assert_calls(
+            &manifest_lock_primary_role(&securified()).unwrap(),
+            "lock_primary_role",
+            AccessControllerRole::Recovery,
+            current().recovery_role,
+        );
+        assert_calls(
+            &manifest_unlock_primary_role(&securified()).unwrap(),
+            "unlock_primary_role",
+            AccessControllerRole::Recovery,
+            current().recovery_role,
+        );
+    }
+
+    #[test]
+    fn unsecured_entity_fails() {
+        assert_eq!(
+            manifest_lock_primary_role(&EntitySecurityState::placeholder()),
+            Err(CommonError::EntityNotSecurified)
+        );
+        assert_eq!(
+            manifest_initiate_recovery_as_recovery(
+                &EntitySecurityState::placeholder(),
+                proposed()
+            ),
+            Err(CommonError::EntityNotSecurified)
+        );
+    }
+
+    #[test]
+    fn invalid_proposal_fails() {
+        let mut proposed = proposed();
+        proposed.number_of_days_until_auto_confirmation = 0;
+        assert!(matches!(
+            manifest_confirm_recovery(&securified(), proposed),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+}
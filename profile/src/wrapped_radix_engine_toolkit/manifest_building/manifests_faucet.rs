@@ -0,0 +1,89 @@
+use crate::prelude::*;
+
+use transaction::prelude::{
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+};
+
+/// Creates the manifest requesting free XRD from the faucet, e.g. on
+/// Stokenet, depositing it into `address_of_receiving_account`.
+#[uniffi::export]
+pub fn manifest_for_faucet(
+    include_lock_fee_instruction: bool,
+    address_of_receiving_account: AccountAddress,
+) -> Result<TransactionManifest> {
+    TransactionManifest::faucet(
+        include_lock_fee_instruction,
+        &address_of_receiving_account,
+    )
+}
+
+impl TransactionManifest {
+    /// Creates the manifest requesting free XRD from the faucet of the
+    /// network of `address_of_receiving_account`, optionally locking the fee
+    /// using the faucet, so that an account without XRD can use it. Fails for
+    /// Mainnet, which does not have a faucet.
+    pub fn faucet(
+        include_lock_fee_instruction: bool,
+        address_of_receiving_account: &AccountAddress,
+    ) -> Result<Self> {
+        let network_id = address_of_receiving_account.network_id;
+        if network_id == NetworkID::Mainnet {
+            return Err(CommonError::FaucetNotAvailableOnMainnet);
+        }
+
+        let mut builder = ScryptoManifestBuilder::new();
+        if include_lock_fee_instruction {
+            builder = builder.lock_fee_from_faucet();
+        }
+        let receiver: ScryptoComponentAddress =
+            address_of_receiving_account.clone().into();
+        let manifest = builder
+            .get_free_xrd_from_faucet()
+            .try_deposit_entire_worktop_or_abort(receiver, None)
+            .build();
+
+        Self::from_scrypto(manifest, network_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn faucet_with_lock_fee() {
+        let sut =
+            manifest_for_faucet(true, AccountAddress::placeholder_stokenet())
+                .unwrap();
+        assert_eq!(sut.network_id, NetworkID::Stokenet);
+        assert!(sut.instructions.contains("\"lock_fee\""));
+        assert!(sut.instructions.contains("\"free\""));
+        assert!(sut.instructions.contains("\"try_deposit_batch_or_abort\""));
+        assert!(sut
+            .instructions
+            .contains(&AccountAddress::placeholder_stokenet().address));
+    }
+
+    #[test]
+    fn faucet_without_lock_fee() {
+        let sut = TransactionManifest::faucet(
+            false,
+            &AccountAddress::placeholder_stokenet(),
+        )
+        .unwrap();
+        assert!(!sut.instructions.contains("\"lock_fee\""));
+        assert!(sut.instructions.contains("\"free\""));
+    }
+
+    #[test]
+    fn faucet_on_mainnet_fails() {
+        assert_eq!(
+            TransactionManifest::faucet(
+                true,
+                &AccountAddress::placeholder_mainnet()
+            ),
+            Err(CommonError::FaucetNotAvailableOnMainnet)
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::prelude::*;
+
+use radix_engine_interface::prelude::{
+    MetadataValue as ScryptoMetadataValue, UncheckedUrl as ScryptoUncheckedUrl,
+};
+use transaction::prelude::{
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+};
+
+/// The kinds of metadata values the wallet sets on accounts, e.g.
+/// `name`, `description`, `tags` or `icon_url`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum MetadataValue {
+    Str { value: String },
+    StrArray { value: Vec<String> },
+    UncheckedUrl { value: Url },
+}
+
+impl From<MetadataValue> for ScryptoMetadataValue {
+    fn from(value: MetadataValue) -> Self {
+        match value {
+            MetadataValue::Str { value } => Self::String(value),
+            MetadataValue::StrArray { value } => Self::StringArray(value),
+            MetadataValue::UncheckedUrl { value } => {
+                Self::Url(ScryptoUncheckedUrl::of(value.as_str()))
+            }
+        }
+    }
+}
+
+/// The metadata key and value marking an account as a dApp definition.
+const ACCOUNT_TYPE_KEY: &str = "account_type";
+const ACCOUNT_TYPE_DAPP_DEFINITION: &str = "dapp definition";
+
+/// Creates the manifest setting the metadata `key` of the account with
+/// `address` to `value`.
+#[uniffi::export]
+pub fn manifest_set_account_metadata(
+    address: AccountAddress,
+    key: String,
+    value: MetadataValue,
+) -> Result<TransactionManifest> {
+    TransactionManifest::set_account_metadata(&address, key, value)
+}
+
+/// Creates the manifest marking the account with `address` as a dApp
+/// definition.
+#[uniffi::export]
+pub fn manifest_marking_account_as_dapp_definition_type(
+    address: AccountAddress,
+) -> Result<TransactionManifest> {
+    TransactionManifest::marking_account_as_dapp_definition_type(&address)
+}
+
+impl TransactionManifest {
+    /// Creates the manifest setting the metadata `key` of the account with
+    /// `address` to `value`.
+    pub fn set_account_metadata(
+        address: &AccountAddress,
+        key: impl Into<String>,
+        value: MetadataValue,
+    ) -> Result<Self> {
+        let account: ScryptoComponentAddress = address.clone().into();
+        let manifest = ScryptoManifestBuilder::new()
+            .set_metadata(
+                account,
+                key.into(),
+                ScryptoMetadataValue::from(value),
+            )
+            .build();
+        Self::from_scrypto(manifest, address.network_id)
+    }
+
+    /// Creates the manifest setting the metadata `account_type` of the
+    /// account with `address` to `"dapp definition"`.
+    pub fn marking_account_as_dapp_definition_type(
+        address: &AccountAddress,
+    ) -> Result<Self> {
+        Self::set_account_metadata(
+            address,
+            ACCOUNT_TYPE_KEY,
+            MetadataValue::Str {
+                value: ACCOUNT_TYPE_DAPP_DEFINITION.to_owned(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn set_account_metadata_string() {
+        let sut = manifest_set_account_metadata(
+            AccountAddress::placeholder_mainnet(),
+            "name".to_owned(),
+            MetadataValue::Str {
+                value: "Savings".to_owned(),
+            },
+        )
+        .unwrap()
+        .instructions;
+        assert!(sut.starts_with("SET_METADATA"));
+        assert!(sut.contains("\"name\""));
+        assert!(sut.contains("\"Savings\""));
+        assert!(sut.contains(&AccountAddress::placeholder_mainnet().address));
+    }
+
+    #[test]
+    fn set_account_metadata_url() {
+        let sut = TransactionManifest::set_account_metadata(
+            &AccountAddress::placeholder_stokenet(),
+            "icon_url",
+            MetadataValue::UncheckedUrl {
+                value: Url::parse("https://radixdlt.com/icon.png").unwrap(),
+            },
+        )
+        .unwrap();
+        assert_eq!(sut.network_id, NetworkID::Stokenet);
+        assert!(sut.instructions.contains("https://radixdlt.com/icon.png"));
+    }
+
+    #[test]
+    fn marking_account_as_dapp_definition_type() {
+        let sut = manifest_marking_account_as_dapp_definition_type(
+            AccountAddress::placeholder_mainnet(),
+        )
+        .unwrap()
+        .instructions;
+        assert!(sut.contains("\"account_type\""));
+        assert!(sut.contains("\"dapp definition\""));
+    }
+}
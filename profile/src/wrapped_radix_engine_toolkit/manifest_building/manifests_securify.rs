@@ -0,0 +1,393 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::PublicKey as ScryptoPublicKey;
+use radix_engine_interface::blueprints::account::ACCOUNT_SECURIFY_IDENT as SCRYPTO_ACCOUNT_SECURIFY_IDENT;
+use radix_engine_interface::blueprints::identity::IDENTITY_SECURIFY_IDENT as SCRYPTO_IDENTITY_SECURIFY_IDENT;
+use transaction::prelude::{
+    AccessRule as ScryptoAccessRule, AccessRuleNode as ScryptoAccessRuleNode,
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+    NonFungibleGlobalId as ScryptoNonFungibleGlobalId,
+    ProofRule as ScryptoProofRule, ResourceAddress as ScryptoResourceAddress,
+    ResourceOrNonFungible as ScryptoResourceOrNonFungible,
+    ACCOUNT_OWNER_BADGE as SCRYPTO_ACCOUNT_OWNER_BADGE,
+    IDENTITY_OWNER_BADGE as SCRYPTO_IDENTITY_OWNER_BADGE,
+};
+
+/// Creates the manifest securifying `account` with an AccessController using
+/// the roles of `security_structure`, see
+/// [`TransactionManifest::securify_account`].
+#[uniffi::export]
+pub fn manifest_securify_account(
+    account: &Account,
+    security_structure: SecurityStructureOfFactorInstances,
+) -> Result<TransactionManifest> {
+    TransactionManifest::securify_account(account, security_structure)
+}
+
+/// Creates the manifest securifying `persona` with an AccessController using
+/// the roles of `security_structure`, see
+/// [`TransactionManifest::securify_persona`].
+#[uniffi::export]
+pub fn manifest_securify_persona(
+    persona: &Persona,
+    security_structure: SecurityStructureOfFactorInstances,
+) -> Result<TransactionManifest> {
+    TransactionManifest::securify_persona(persona, security_structure)
+}
+
+impl RoleOfFactorInstances {
+    /// The rule of this role, requiring the virtual signature badges of
+    /// `threshold` many of the threshold factors or any of the override
+    /// factors.
+    pub(crate) fn scrypto_access_rule(&self) -> ScryptoAccessRule {
+        let badges = |factors: &[HierarchicalDeterministicFactorInstance]| {
+            factors
+                .iter()
+                .map(|f| {
+                    ScryptoResourceOrNonFungible::NonFungible(
+                        ScryptoNonFungibleGlobalId::from_public_key(
+                            &ScryptoPublicKey::from(
+                                f.public_key.public_key.clone(),
+                            ),
+                        ),
+                    )
+                })
+                .collect_vec()
+        };
+
+        // An empty `CountOf` with a threshold of zero is always fulfilled,
+        // so only non empty rules are included.
+        let mut rules = Vec::new();
+        if !self.threshold_factors.is_empty() {
+            rules.push(ScryptoAccessRuleNode::ProofRule(
+                ScryptoProofRule::CountOf(
+                    self.threshold,
+                    badges(&self.threshold_factors),
+                ),
+            ));
+        }
+        if !self.override_factors.is_empty() {
+            rules.push(ScryptoAccessRuleNode::ProofRule(
+                ScryptoProofRule::AnyOf(badges(&self.override_factors)),
+            ));
+        }
+        ScryptoAccessRule::Protected(ScryptoAccessRuleNode::AnyOf(rules))
+    }
+}
+
+impl TransactionManifest {
+    /// Creates the manifest securifying the unsecured `account`, i.e. its
+    /// owner badge is moved into a new AccessController with the primary,
+    /// recovery and confirmation roles of `security_structure`.
+    ///
+    /// Once the transaction is committed the account should be marked as
+    /// securified, see [`Profile::mark_account_securified`].
+    pub fn securify_account(
+        account: &Account,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<Self> {
+        Self::securify_entity(
+            account.address.clone().into(),
+            account.network_id,
+            &account.security_state,
+            SCRYPTO_ACCOUNT_SECURIFY_IDENT,
+            SCRYPTO_ACCOUNT_OWNER_BADGE,
+            security_structure,
+        )
+    }
+
+    /// Creates the manifest securifying the unsecured `persona`, i.e. its
+    /// owner badge is moved into a new AccessController with the primary,
+    /// recovery and confirmation roles of `security_structure`.
+    ///
+    /// Once the transaction is committed the persona should be marked as
+    /// securified, see [`Profile::mark_persona_securified`].
+    pub fn securify_persona(
+        persona: &Persona,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<Self> {
+        Self::securify_entity(
+            persona.address.clone().into(),
+            persona.address.network_id,
+            &persona.security_state,
+            SCRYPTO_IDENTITY_SECURIFY_IDENT,
+            SCRYPTO_IDENTITY_OWNER_BADGE,
+            security_structure,
+        )
+    }
+
+    fn securify_entity(
+        entity_address: ScryptoComponentAddress,
+        network_id: NetworkID,
+        security_state: &EntitySecurityState,
+        securify_method: &str,
+        owner_badge: ScryptoResourceAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<Self> {
+        if security_state.is_securified() {
+            return Err(CommonError::EntityAlreadySecurified);
+        }
+        security_structure.validate()?;
+
+        let bucket = "owner_badge";
+        let builder = ScryptoManifestBuilder::new()
+            .call_method(
+                entity_address,
+                securify_method,
+                transaction::manifest_args!(),
+            )
+            .take_all_from_worktop(owner_badge, bucket)
+            .create_access_controller(
+                bucket,
+                security_structure.primary_role.scrypto_access_rule(),
+                security_structure.recovery_role.scrypto_access_rule(),
+                security_structure.confirmation_role.scrypto_access_rule(),
+                Some(security_structure.timed_recovery_delay_in_minutes()),
+            );
+
+        Self::from_scrypto(builder.build(), network_id)
+    }
+}
+
+impl Profile {
+    /// Marks the account at `address` as securified by the AccessController
+    /// at `access_controller_address`, to be called once the transaction of
+    /// [`TransactionManifest::securify_account`] has been committed.
+    pub fn mark_account_securified(
+        &mut self,
+        address: &AccountAddress,
+        access_controller_address: AccessControllerAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<Account> {
+        let account = self
+            .networks
+            .get_account(address)
+            .ok_or(CommonError::UnknownAccount)?;
+        let security_state = Self::securified_security_state(
+            &account.security_state,
+            address.network_id,
+            access_controller_address,
+            security_structure,
+        )?;
+        self.networks
+            .update_account(address, |a| {
+                a.security_state = security_state.clone()
+            })
+            .ok_or(CommonError::UnknownAccount)
+    }
+
+    /// Marks the persona at `address` as securified by the AccessController
+    /// at `access_controller_address`, to be called once the transaction of
+    /// [`TransactionManifest::securify_persona`] has been committed.
+    pub fn mark_persona_securified(
+        &mut self,
+        address: &IdentityAddress,
+        access_controller_address: AccessControllerAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<Persona> {
+        let persona = self
+            .networks
+            .get_persona(address)
+            .ok_or(CommonError::UnknownPersona)?;
+        let security_state = Self::securified_security_state(
+            &persona.security_state,
+            address.network_id,
+            access_controller_address,
+            security_structure,
+        )?;
+        self.networks
+            .update_persona(address, |p| {
+                p.security_state = security_state.clone()
+            })
+            .ok_or(CommonError::UnknownPersona)
+    }
+
+    fn securified_security_state(
+        current: &EntitySecurityState,
+        network_id: NetworkID,
+        access_controller_address: AccessControllerAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Result<EntitySecurityState> {
+        let EntitySecurityState::Unsecured { value: unsecured } = current
+        else {
+            return Err(CommonError::EntityAlreadySecurified);
+        };
+        if access_controller_address.network_id != network_id {
+            return Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: network_id,
+                found: access_controller_address.network_id,
+            });
+        }
+        security_structure.validate()?;
+        Ok(SecuredEntityControl::new(
+            unsecured.transaction_signing.clone(),
+            access_controller_address,
+            security_structure,
+        )
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn securified(account: &Account) -> Account {
+        let mut account = account.clone();
+        account.security_state = SecuredEntityControl::placeholder().into();
+        account
+    }
+
+    #[test]
+    fn securify_account() {
+        let account = Account::placeholder_mainnet();
+        let sut = manifest_securify_account(
+            &account,
+            SecurityStructureOfFactorInstances::placeholder(),
+        )
+        .unwrap()
+        .instructions;
+
+        assert!(sut.contains(&account.address.address));
+        assert!(sut.contains("\"securify\""));
+        assert!(sut.contains("TAKE_ALL_FROM_WORKTOP"));
+        assert!(sut.contains("CREATE_ACCESS_CONTROLLER"));
+        assert!(sut.contains("CountOf"));
+        assert!(sut.contains("AnyOf"));
+        assert!(sut.contains("20160u32"));
+    }
+
+    #[test]
+    fn securify_persona() {
+        let persona = Persona::placeholder_mainnet();
+        let sut = manifest_securify_persona(
+            &persona,
+            SecurityStructureOfFactorInstances::placeholder_other(),
+        )
+        .unwrap()
+        .instructions;
+
+        assert!(sut.contains(&persona.address.address));
+        assert!(sut.contains("\"securify\""));
+        assert!(sut.contains("CREATE_ACCESS_CONTROLLER"));
+        assert!(sut.contains("10080u32"));
+    }
+
+    #[test]
+    fn securify_account_requires_signature_of_account() {
+        let account = Account::placeholder_mainnet();
+        let sut = TransactionManifest::securify_account(
+            &account,
+            SecurityStructureOfFactorInstances::placeholder(),
+        )
+        .unwrap()
+        .summary()
+        .unwrap();
+        assert!(sut.account(&account.address).unwrap().requires_signature);
+    }
+
+    #[test]
+    fn securify_securified_account_fails() {
+        assert_eq!(
+            TransactionManifest::securify_account(
+                &securified(&Account::placeholder_mainnet()),
+                SecurityStructureOfFactorInstances::placeholder(),
+            ),
+            Err(CommonError::EntityAlreadySecurified)
+        );
+    }
+
+    #[test]
+    fn securify_with_invalid_structure_fails() {
+        let mut structure = SecurityStructureOfFactorInstances::placeholder();
+        structure.number_of_days_until_auto_confirmation = 0;
+        assert!(matches!(
+            TransactionManifest::securify_account(
+                &Account::placeholder_mainnet(),
+                structure
+            ),
+            Err(CommonError::InvalidSecurityStructure(_))
+        ));
+    }
+
+    #[test]
+    fn mark_account_securified() {
+        let mut profile = Profile::placeholder();
+        let address = AccountAddress::placeholder_stokenet();
+        let before = profile.networks.get_account(&address).unwrap();
+        let EntitySecurityState::Unsecured { value: unsecured } =
+            before.security_state.clone()
+        else {
+            panic!("Expected unsecured placeholder");
+        };
+
+        let account = profile
+            .mark_account_securified(
+                &address,
+                AccessControllerAddress::placeholder_stokenet(),
+                SecurityStructureOfFactorInstances::placeholder(),
+            )
+            .unwrap();
+
+        let EntitySecurityState::Securified { value: secured } =
+            account.security_state.clone()
+        else {
+            panic!("Expected securified account");
+        };
+        assert_eq!(
+            secured.access_controller_address,
+            AccessControllerAddress::placeholder_stokenet()
+        );
+        assert_eq!(
+            secured.virtual_entity_creating_instance,
+            unsecured.transaction_signing
+        );
+        assert_eq!(profile.networks.get_account(&address), Some(account));
+    }
+
+    #[test]
+    fn mark_account_securified_twice_fails() {
+        let mut profile = Profile::placeholder();
+        let address = AccountAddress::placeholder_stokenet();
+        let mark = |p: &mut Profile| {
+            p.mark_account_securified(
+                &address,
+                AccessControllerAddress::placeholder_stokenet(),
+                SecurityStructureOfFactorInstances::placeholder(),
+            )
+        };
+        assert!(mark(&mut profile).is_ok());
+        assert_eq!(
+            mark(&mut profile),
+            Err(CommonError::EntityAlreadySecurified)
+        );
+    }
+
+    #[test]
+    fn mark_account_securified_on_wrong_network_fails() {
+        assert_eq!(
+            Profile::placeholder().mark_account_securified(
+                &AccountAddress::placeholder_stokenet(),
+                AccessControllerAddress::placeholder_mainnet(),
+                SecurityStructureOfFactorInstances::placeholder(),
+            ),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn mark_unknown_account_securified_fails() {
+        assert_eq!(
+            Profile::placeholder().mark_account_securified(
+                &AccountAddress::placeholder_mainnet_other(),
+                AccessControllerAddress::placeholder_mainnet(),
+                SecurityStructureOfFactorInstances::placeholder(),
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use crate::prelude::*;
+
+use radix_engine_interface::blueprints::consensus_manager::{
+    VALIDATOR_CLAIM_XRD_IDENT as SCRYPTO_VALIDATOR_CLAIM_XRD_IDENT,
+    VALIDATOR_STAKE_IDENT as SCRYPTO_VALIDATOR_STAKE_IDENT,
+    VALIDATOR_UNSTAKE_IDENT as SCRYPTO_VALIDATOR_UNSTAKE_IDENT,
+};
+use transaction::prelude::{
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+    ResourceAddress as ScryptoResourceAddress, XRD as SCRYPTO_XRD,
+};
+
+/// Creates the manifest staking XRD to validators, see
+/// [`TransactionManifest::stake`].
+#[uniffi::export]
+pub fn manifest_stake(stakes: Vec<StakeXrd>) -> Result<TransactionManifest> {
+    TransactionManifest::stake(stakes)
+}
+
+/// Creates the manifest unstaking liquid stake units, see
+/// [`TransactionManifest::unstake`].
+#[uniffi::export]
+pub fn manifest_unstake(
+    unstakes: Vec<UnstakeLiquidStakeUnits>,
+) -> Result<TransactionManifest> {
+    TransactionManifest::unstake(unstakes)
+}
+
+/// Creates the manifest claiming XRD of matured stake claim NFTs, see
+/// [`TransactionManifest::claim_stakes`].
+#[uniffi::export]
+pub fn manifest_claim_stakes(
+    claims: Vec<ClaimStake>,
+) -> Result<TransactionManifest> {
+    TransactionManifest::claim_stakes(claims)
+}
+
+/// Names buckets `bucket_0`, `bucket_1` and so on.
+#[derive(Default)]
+struct BucketNames(usize);
+
+impl BucketNames {
+    fn next(&mut self) -> String {
+        let bucket = format!("bucket_{}", self.0);
+        self.0 += 1;
+        bucket
+    }
+}
+
+impl TransactionManifest {
+    /// Creates the manifest staking XRD, possibly from many accounts to many
+    /// validators. For each stake the XRD is withdrawn from the account and
+    /// passed to the validator, and the liquid stake units returned are
+    /// deposited back into the same account.
+    pub fn stake(stakes: impl IntoIterator<Item = StakeXrd>) -> Result<Self> {
+        let stakes = stakes.into_iter().collect_vec();
+        let network_id =
+            network_of_staking_operations(stakes.iter().flat_map(|s| {
+                [s.account_address.network_id, s.validator_address.network_id]
+            }))?;
+
+        let mut buckets = BucketNames::default();
+        let mut builder = ScryptoManifestBuilder::new();
+        for stake in stakes {
+            let account: ScryptoComponentAddress = stake.account_address.into();
+            let validator: ScryptoComponentAddress =
+                stake.validator_address.into();
+            let amount = stake.amount.native();
+            let bucket = buckets.next();
+            builder = builder
+                .withdraw_from_account(account, SCRYPTO_XRD, amount)
+                .take_from_worktop(SCRYPTO_XRD, amount, bucket.as_str())
+                .call_method_with_name_lookup(
+                    validator,
+                    SCRYPTO_VALIDATOR_STAKE_IDENT,
+                    |lookup| (lookup.bucket(bucket.as_str()),),
+                )
+                .try_deposit_entire_worktop_or_abort(account, None);
+        }
+
+        Self::from_scrypto(builder.build(), network_id)
+    }
+
+    /// Creates the manifest unstaking liquid stake units, possibly from many
+    /// accounts and of many validators. For each unstake the liquid stake
+    /// units are withdrawn from the account and passed to the validator, and
+    /// the stake claim NFT returned is deposited back into the same account.
+    pub fn unstake(
+        unstakes: impl IntoIterator<Item = UnstakeLiquidStakeUnits>,
+    ) -> Result<Self> {
+        let unstakes = unstakes.into_iter().collect_vec();
+        let network_id =
+            network_of_staking_operations(unstakes.iter().flat_map(|u| {
+                [
+                    u.account_address.network_id,
+                    u.validator_address.network_id,
+                    u.liquid_stake_unit_address.network_id,
+                ]
+            }))?;
+
+        let mut buckets = BucketNames::default();
+        let mut builder = ScryptoManifestBuilder::new();
+        for unstake in unstakes {
+            let account: ScryptoComponentAddress =
+                unstake.account_address.into();
+            let validator: ScryptoComponentAddress =
+                unstake.validator_address.into();
+            let liquid_stake_units: ScryptoResourceAddress =
+                unstake.liquid_stake_unit_address.into();
+            let amount = unstake.amount.native();
+            let bucket = buckets.next();
+            builder = builder
+                .withdraw_from_account(account, liquid_stake_units, amount)
+                .take_from_worktop(liquid_stake_units, amount, bucket.as_str())
+                .call_method_with_name_lookup(
+                    validator,
+                    SCRYPTO_VALIDATOR_UNSTAKE_IDENT,
+                    |lookup| (lookup.bucket(bucket.as_str()),),
+                )
+                .try_deposit_entire_worktop_or_abort(account, None);
+        }
+
+        Self::from_scrypto(builder.build(), network_id)
+    }
+
+    /// Creates the manifest claiming the XRD of matured stake claim NFTs,
+    /// possibly from many accounts and of many validators. For each claim
+    /// the NFTs are withdrawn from the account, one bucket per resource, and
+    /// passed to the validator, and the XRD returned is deposited back into
+    /// the same account.
+    pub fn claim_stakes(
+        claims: impl IntoIterator<Item = ClaimStake>,
+    ) -> Result<Self> {
+        let claims = claims.into_iter().collect_vec();
+        let network_id =
+            network_of_staking_operations(claims.iter().flat_map(|c| {
+                [c.account_address.network_id, c.validator_address.network_id]
+                    .into_iter()
+                    .chain(
+                        c.claim_nfts
+                            .iter()
+                            .map(|n| n.resource_address.network_id),
+                    )
+            }))?;
+
+        let mut buckets = BucketNames::default();
+        let mut builder = ScryptoManifestBuilder::new();
+        for claim in claims {
+            let account: ScryptoComponentAddress =
+                claim.account_address.clone().into();
+            let validator: ScryptoComponentAddress =
+                claim.validator_address.clone().into();
+
+            let claim_nft_resources = claim
+                .claim_nfts
+                .iter()
+                .map(|n| n.resource_address.clone())
+                .unique()
+                .collect_vec();
+
+            for resource in claim_nft_resources {
+                let local_ids = claim
+                    .claim_nfts
+                    .iter()
+                    .filter(|n| n.resource_address == resource)
+                    .map(|n| n.engine_global_id().local_id().clone())
+                    .collect_vec();
+                let resource_address: ScryptoResourceAddress = resource.into();
+                let bucket = buckets.next();
+                builder = builder
+                    .withdraw_non_fungibles_from_account(
+                        account,
+                        resource_address,
+                        local_ids.clone(),
+                    )
+                    .take_non_fungibles_from_worktop(
+                        resource_address,
+                        local_ids,
+                        bucket.as_str(),
+                    )
+                    .call_method_with_name_lookup(
+                        validator,
+                        SCRYPTO_VALIDATOR_CLAIM_XRD_IDENT,
+                        |lookup| (lookup.bucket(bucket.as_str()),),
+                    );
+            }
+            builder =
+                builder.try_deposit_entire_worktop_or_abort(account, None);
+        }
+
+        Self::from_scrypto(builder.build(), network_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn amount(s: &str) -> Decimal {
+        Decimal::try_from_str(s).unwrap()
+    }
+
+    fn account() -> AccountAddress {
+        AccountAddress::placeholder_mainnet()
+    }
+
+    fn account_other() -> AccountAddress {
+        AccountAddress::placeholder_mainnet_other()
+    }
+
+    #[test]
+    fn stake_to_many_validators_from_many_accounts() {
+        let sut = manifest_stake(vec![
+            StakeXrd::new(
+                account(),
+                ValidatorAddress::placeholder(),
+                amount("100"),
+            ),
+            StakeXrd::new(
+                account_other(),
+                ValidatorAddress::placeholder_other(),
+                amount("200"),
+            ),
+        ])
+        .unwrap();
+        let instructions = &sut.instructions;
+
+        assert_eq!(instructions.matches("\"stake\"").count(), 2);
+        assert_eq!(instructions.matches("\"withdraw\"").count(), 2);
+        assert_eq!(
+            instructions
+                .matches("\"try_deposit_batch_or_abort\"")
+                .count(),
+            2
+        );
+        assert!(instructions.contains(&ValidatorAddress::placeholder().address));
+        assert!(instructions
+            .contains(&ValidatorAddress::placeholder_other().address));
+        assert!(instructions
+            .contains(&ResourceAddress::placeholder_mainnet_xrd().address));
+
+        let summary = sut.summary().unwrap();
+        assert!(summary.account(&account()).unwrap().requires_signature);
+        assert!(
+            summary
+                .account(&account_other())
+                .unwrap()
+                .requires_signature
+        );
+        assert_eq!(summary.unrecognized_instructions.len(), 2);
+    }
+
+    #[test]
+    fn unstake() {
+        let sut = manifest_unstake(vec![UnstakeLiquidStakeUnits::new(
+            account(),
+            ValidatorAddress::placeholder(),
+            ResourceAddress::placeholder_other(),
+            amount("42"),
+        )])
+        .unwrap()
+        .instructions;
+
+        assert_eq!(sut.matches("\"unstake\"").count(), 1);
+        assert!(sut.contains(&ResourceAddress::placeholder_other().address));
+        assert!(sut.contains("Decimal(\"42\")"));
+    }
+
+    #[test]
+    fn claim_stakes() {
+        let sut = manifest_claim_stakes(vec![ClaimStake::new(
+            account(),
+            ValidatorAddress::placeholder(),
+            [
+                NonFungibleGlobalId::placeholder(),
+                NonFungibleGlobalId::placeholder_other(),
+            ],
+        )])
+        .unwrap()
+        .instructions;
+
+        assert_eq!(sut.matches("\"claim_xrd\"").count(), 1);
+        assert_eq!(sut.matches("\"withdraw_non_fungibles\"").count(), 1);
+        assert!(sut.contains("NonFungibleLocalId(\"#2244#\")"));
+        assert!(sut.contains("NonFungibleLocalId(\"#9999#\")"));
+        assert_eq!(sut.matches("\"try_deposit_batch_or_abort\"").count(), 1);
+    }
+
+    #[test]
+    fn empty_operations() {
+        assert_eq!(
+            TransactionManifest::stake([]),
+            Err(CommonError::EmptyStakingOperations)
+        );
+        assert_eq!(
+            TransactionManifest::unstake([]),
+            Err(CommonError::EmptyStakingOperations)
+        );
+        assert_eq!(
+            TransactionManifest::claim_stakes([]),
+            Err(CommonError::EmptyStakingOperations)
+        );
+    }
+
+    #[test]
+    fn validator_on_other_network() {
+        assert_eq!(
+            TransactionManifest::stake([StakeXrd::new(
+                account(),
+                ValidatorAddress::placeholder_stokenet(),
+                Decimal::one(),
+            )]),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::prelude::*;
+
+use radix_engine_interface::blueprints::account::{
+    AccountAddAuthorizedDepositorInput as ScryptoAccountAddAuthorizedDepositorInput,
+    AccountRemoveAuthorizedDepositorInput as ScryptoAccountRemoveAuthorizedDepositorInput,
+    AccountRemoveResourcePreferenceInput as ScryptoAccountRemoveResourcePreferenceInput,
+    AccountSetDefaultDepositRuleInput as ScryptoAccountSetDefaultDepositRuleInput,
+    AccountSetResourcePreferenceInput as ScryptoAccountSetResourcePreferenceInput,
+    ACCOUNT_ADD_AUTHORIZED_DEPOSITOR as SCRYPTO_ACCOUNT_ADD_AUTHORIZED_DEPOSITOR,
+    ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR as SCRYPTO_ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR,
+    ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT as SCRYPTO_ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT,
+    ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT as SCRYPTO_ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+    ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT as SCRYPTO_ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT,
+};
+use transaction::prelude::{
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+};
+
+/// Creates the manifest moving the third-party deposit settings of the
+/// account `owner` on-ledger from `from` to `to`.
+#[uniffi::export]
+pub fn manifest_third_party_deposit_update(
+    owner: AccountAddress,
+    from: ThirdPartyDeposits,
+    to: ThirdPartyDeposits,
+) -> Result<TransactionManifest> {
+    TransactionManifest::third_party_deposit_update(&owner, &from, &to)
+}
+
+impl TransactionManifest {
+    /// Creates the manifest moving the third-party deposit settings of the
+    /// account `owner` on-ledger from `from` to `to`, calling the account
+    /// component once per change in the [`ThirdPartyDepositsDelta`].
+    ///
+    /// Once the transaction has been submitted the new settings should be
+    /// saved to the `OnLedgerSettings` of the account.
+    ///
+    /// Fails if any resource or depositor in the changes is not on the
+    /// network of `owner`.
+    pub fn third_party_deposit_update(
+        owner: &AccountAddress,
+        from: &ThirdPartyDeposits,
+        to: &ThirdPartyDeposits,
+    ) -> Result<Self> {
+        let delta = ThirdPartyDepositsDelta::new(from, to);
+        delta.validate_network(owner.network_id)?;
+        let address: ScryptoComponentAddress = owner.clone().into();
+        let mut builder = ScryptoManifestBuilder::new();
+
+        if let Some(deposit_rule) = delta.deposit_rule {
+            builder = builder.call_method(
+                address,
+                SCRYPTO_ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+                ScryptoAccountSetDefaultDepositRuleInput {
+                    default: deposit_rule.into(),
+                },
+            );
+        }
+
+        for exception in delta.asset_exceptions_to_add_or_update {
+            builder = builder.call_method(
+                address,
+                SCRYPTO_ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT,
+                ScryptoAccountSetResourcePreferenceInput {
+                    resource_address: exception.address.into(),
+                    resource_preference: exception.exception_rule.into(),
+                },
+            );
+        }
+
+        for resource_address in delta.asset_exceptions_to_be_removed {
+            builder = builder.call_method(
+                address,
+                SCRYPTO_ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT,
+                ScryptoAccountRemoveResourcePreferenceInput {
+                    resource_address: resource_address.into(),
+                },
+            );
+        }
+
+        for depositor in delta.depositors_to_add {
+            builder = builder.call_method(
+                address,
+                SCRYPTO_ACCOUNT_ADD_AUTHORIZED_DEPOSITOR,
+                ScryptoAccountAddAuthorizedDepositorInput {
+                    badge: depositor.into(),
+                },
+            );
+        }
+
+        for depositor in delta.depositors_to_remove {
+            builder = builder.call_method(
+                address,
+                SCRYPTO_ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR,
+                ScryptoAccountRemoveAuthorizedDepositorInput {
+                    badge: depositor.into(),
+                },
+            );
+        }
+
+        Self::from_scrypto(builder.build(), owner.network_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn owner() -> AccountAddress {
+        AccountAddress::placeholder_mainnet()
+    }
+
+    fn xrd() -> ResourceAddress {
+        "resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd"
+            .parse()
+            .unwrap()
+    }
+
+    fn nft() -> ResourceOrNonFungible {
+        ResourceOrNonFungible::NonFungible {
+            value: "resource_rdx1n2ekdd2m0jsxjt9wasmu3p49twy2yfalpaa6wf08md46sk8dfmldnd:#2244#"
+                .parse()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn no_changes_yields_empty_manifest() {
+        let settings = ThirdPartyDeposits::new(DepositRule::DenyAll);
+        let sut = TransactionManifest::third_party_deposit_update(
+            &owner(),
+            &settings,
+            &settings,
+        )
+        .unwrap();
+        assert_eq!(sut.instructions, "");
+        assert_eq!(sut.network_id, NetworkID::Mainnet);
+    }
+
+    #[test]
+    fn all_kinds_of_changes() {
+        let from = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [],
+            [nft()],
+        );
+        let to = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::DenyAll,
+            [AssetException::new(
+                xrd(),
+                DepositAddressExceptionRule::Allow,
+            )],
+            [ResourceOrNonFungible::Resource { value: xrd() }],
+        );
+        let sut = manifest_third_party_deposit_update(owner(), from, to)
+            .unwrap()
+            .instructions;
+
+        let methods = [
+            "set_default_deposit_rule",
+            "set_resource_preference",
+            "add_authorized_depositor",
+            "remove_authorized_depositor",
+        ];
+        for method in methods {
+            assert_eq!(sut.matches(&format!("\"{method}\"")).count(), 1);
+        }
+        assert!(!sut.contains("remove_resource_preference"));
+        assert_eq!(sut.matches(&owner().address).count(), methods.len());
+        assert!(sut.contains(&xrd().address));
+    }
+
+    #[test]
+    fn asset_exception_on_other_network() {
+        let to = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [AssetException::new(
+                ResourceAddress::placeholder_stokenet_xrd(),
+                DepositAddressExceptionRule::Deny,
+            )],
+            [],
+        );
+        assert_eq!(
+            TransactionManifest::third_party_deposit_update(
+                &owner(),
+                &ThirdPartyDeposits::new(DepositRule::AcceptAll),
+                &to,
+            ),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet,
+            })
+        );
+    }
+
+    #[test]
+    fn depositor_on_other_network() {
+        let from = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [],
+            [ResourceOrNonFungible::Resource {
+                value: ResourceAddress::placeholder_stokenet_xrd(),
+            }],
+        );
+        assert_eq!(
+            TransactionManifest::third_party_deposit_update(
+                &owner(),
+                &from,
+                &ThirdPartyDeposits::new(DepositRule::AcceptAll),
+            ),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet,
+            })
+        );
+    }
+
+    #[test]
+    fn removed_asset_exception() {
+        let from = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptAll,
+            [AssetException::new(
+                xrd(),
+                DepositAddressExceptionRule::Deny,
+            )],
+            [],
+        );
+        let sut = TransactionManifest::third_party_deposit_update(
+            &owner(),
+            &from,
+            &ThirdPartyDeposits::new(DepositRule::AcceptAll),
+        )
+        .unwrap();
+        assert!(sut.instructions.contains("\"remove_resource_preference\""));
+        assert!(sut.instructions.contains(&xrd().address));
+    }
+}
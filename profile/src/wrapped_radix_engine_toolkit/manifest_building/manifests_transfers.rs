@@ -0,0 +1,267 @@
+use crate::prelude::*;
+
+use transaction::prelude::{
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+    ResourceAddress as ScryptoResourceAddress,
+};
+
+/// Creates the manifest transferring `amount` of the fungible resource
+/// `resource_address` from the account `from` to the account `to`.
+#[uniffi::export]
+pub fn manifest_single_fungible_transfer(
+    from: AccountAddress,
+    to: AccountOrAddressOf,
+    resource_address: ResourceAddress,
+    amount: Decimal,
+) -> Result<TransactionManifest> {
+    TransactionManifest::single_fungible_transfer(
+        &from,
+        to,
+        resource_address,
+        amount,
+    )
+}
+
+/// Creates the manifest transferring the non-fungibles `non_fungibles` from
+/// the account `from` to the account `to`.
+#[uniffi::export]
+pub fn manifest_non_fungibles_transfer(
+    from: AccountAddress,
+    to: AccountOrAddressOf,
+    non_fungibles: Vec<NonFungibleGlobalId>,
+) -> Result<TransactionManifest> {
+    TransactionManifest::non_fungibles_transfer(&from, to, non_fungibles)
+}
+
+/// Creates the manifest transferring assets from one account to many
+/// recipients.
+#[uniffi::export]
+pub fn manifest_per_recipient_transfers(
+    transfers: PerRecipientAssetTransfers,
+) -> Result<TransactionManifest> {
+    TransactionManifest::per_recipient_transfers(transfers)
+}
+
+impl TransactionManifest {
+    /// Creates the manifest transferring `amount` of the fungible resource
+    /// `resource_address` from the account `from` to the account `to`.
+    pub fn single_fungible_transfer(
+        from: &AccountAddress,
+        to: impl Into<AccountOrAddressOf>,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    ) -> Result<Self> {
+        Self::per_recipient_transfers(PerRecipientAssetTransfers::new(
+            from.clone(),
+            [PerRecipientAssetTransfer::new(
+                to,
+                [PerRecipientFungibleTransfer::new(resource_address, amount)],
+                [],
+            )],
+        ))
+    }
+
+    /// Creates the manifest transferring the non-fungibles `non_fungibles`,
+    /// possibly of different resources, from the account `from` to the
+    /// account `to`.
+    pub fn non_fungibles_transfer(
+        from: &AccountAddress,
+        to: impl Into<AccountOrAddressOf>,
+        non_fungibles: impl IntoIterator<Item = NonFungibleGlobalId>,
+    ) -> Result<Self> {
+        Self::per_recipient_transfers(PerRecipientAssetTransfers::new(
+            from.clone(),
+            [PerRecipientAssetTransfer::new(to, [], non_fungibles)],
+        ))
+    }
+
+    /// Creates the manifest transferring assets from one account to many
+    /// recipients, each fungible and each non-fungible resource is
+    /// withdrawn from the sender, taken from the worktop and deposited into
+    /// the recipient using `try_deposit_or_abort`, meaning the transaction
+    /// fails if the recipient does not accept the deposit.
+    pub fn per_recipient_transfers(
+        transfers: PerRecipientAssetTransfers,
+    ) -> Result<Self> {
+        transfers.validate_network()?;
+
+        let network_id = transfers.address_of_sender.network_id;
+        let sender: ScryptoComponentAddress =
+            transfers.address_of_sender.into();
+        let mut builder = ScryptoManifestBuilder::new();
+        let mut bucket_index = 0;
+        let mut next_bucket = || {
+            let bucket = format!("bucket_{bucket_index}");
+            bucket_index += 1;
+            bucket
+        };
+
+        for transfer in transfers.transfers {
+            let recipient: ScryptoComponentAddress =
+                transfer.recipient.account_address().clone().into();
+
+            for fungible in transfer.fungibles {
+                let resource_address: ScryptoResourceAddress =
+                    fungible.resource_address.into();
+                let amount = fungible.amount.native();
+                let bucket = next_bucket();
+                builder = builder
+                    .withdraw_from_account(sender, resource_address, amount)
+                    .take_from_worktop(
+                        resource_address,
+                        amount,
+                        bucket.as_str(),
+                    )
+                    .try_deposit_or_abort(recipient, None, bucket.as_str());
+            }
+
+            let non_fungible_resources = transfer
+                .non_fungibles
+                .iter()
+                .map(|n| n.resource_address.clone())
+                .unique()
+                .collect_vec();
+
+            for resource in non_fungible_resources {
+                let local_ids = transfer
+                    .non_fungibles
+                    .iter()
+                    .filter(|n| n.resource_address == resource)
+                    .map(|n| n.engine_global_id().local_id().clone())
+                    .collect_vec();
+                let resource_address: ScryptoResourceAddress = resource.into();
+                let bucket = next_bucket();
+                builder = builder
+                    .withdraw_non_fungibles_from_account(
+                        sender,
+                        resource_address,
+                        local_ids.clone(),
+                    )
+                    .take_non_fungibles_from_worktop(
+                        resource_address,
+                        local_ids,
+                        bucket.as_str(),
+                    )
+                    .try_deposit_or_abort(recipient, None, bucket.as_str());
+            }
+        }
+
+        Self::from_scrypto(builder.build(), network_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn from() -> AccountAddress {
+        AccountAddress::placeholder_mainnet()
+    }
+
+    fn to() -> AccountAddress {
+        AccountAddress::placeholder_mainnet_other()
+    }
+
+    #[test]
+    fn single_fungible_transfer() {
+        let sut = manifest_single_fungible_transfer(
+            from(),
+            to().into(),
+            ResourceAddress::placeholder(),
+            Decimal::try_from_str("1337").unwrap(),
+        )
+        .unwrap()
+        .instructions;
+
+        assert_eq!(sut.matches("\"withdraw\"").count(), 1);
+        assert_eq!(sut.matches("TAKE_FROM_WORKTOP").count(), 1);
+        assert_eq!(sut.matches("\"try_deposit_or_abort\"").count(), 1);
+        assert_eq!(sut.matches("Decimal(\"1337\")").count(), 2);
+        assert!(sut.contains(&from().address));
+        assert!(sut.contains(&to().address));
+        assert!(sut.contains(&ResourceAddress::placeholder().address));
+    }
+
+    #[test]
+    fn transfer_to_profile_account() {
+        let account = Account::placeholder_stokenet_carol();
+        let sut = TransactionManifest::single_fungible_transfer(
+            &AccountAddress::placeholder_stokenet(),
+            account.clone(),
+            ResourceAddress::placeholder_stokenet_xrd(),
+            Decimal::one(),
+        )
+        .unwrap();
+        assert_eq!(sut.network_id, NetworkID::Stokenet);
+        assert!(sut.instructions.contains(&account.address.address));
+    }
+
+    #[test]
+    fn non_fungibles_of_same_resource_use_one_bucket() {
+        let sut = manifest_non_fungibles_transfer(
+            from(),
+            to().into(),
+            vec![
+                NonFungibleGlobalId::placeholder(),
+                NonFungibleGlobalId::placeholder_other(),
+            ],
+        )
+        .unwrap()
+        .instructions;
+
+        assert_eq!(sut.matches("\"withdraw_non_fungibles\"").count(), 1);
+        assert_eq!(sut.matches("TAKE_NON_FUNGIBLES_FROM_WORKTOP").count(), 1);
+        assert_eq!(sut.matches("\"try_deposit_or_abort\"").count(), 1);
+        assert!(sut.contains("NonFungibleLocalId(\"#2244#\")"));
+        assert!(sut.contains("NonFungibleLocalId(\"#9999#\")"));
+    }
+
+    #[test]
+    fn multi_recipient_transfers() {
+        let xrd = |amount: &str| {
+            PerRecipientFungibleTransfer::new(
+                ResourceAddress::placeholder(),
+                Decimal::try_from_str(amount).unwrap(),
+            )
+        };
+        let transfers = PerRecipientAssetTransfers::new(
+            from(),
+            [
+                PerRecipientAssetTransfer::new(
+                    to(),
+                    [xrd("1"), xrd("2")],
+                    [NonFungibleGlobalId::placeholder()],
+                ),
+                PerRecipientAssetTransfer::new(
+                    AccountAddress::placeholder_mainnet_other(),
+                    [xrd("3")],
+                    [],
+                ),
+            ],
+        );
+        let sut = manifest_per_recipient_transfers(transfers)
+            .unwrap()
+            .instructions;
+
+        assert_eq!(sut.matches("\"try_deposit_or_abort\"").count(), 4);
+        assert_eq!(sut.matches("\"withdraw\"").count(), 3);
+        assert_eq!(sut.matches("\"withdraw_non_fungibles\"").count(), 1);
+    }
+
+    #[test]
+    fn transfer_of_resource_on_other_network_fails() {
+        assert_eq!(
+            TransactionManifest::single_fungible_transfer(
+                &from(),
+                to(),
+                ResourceAddress::placeholder_stokenet_xrd(),
+                Decimal::one(),
+            ),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+}
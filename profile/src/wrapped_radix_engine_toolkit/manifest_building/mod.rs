@@ -0,0 +1,21 @@
+mod account_or_address_of;
+mod manifests_access_controller;
+mod manifests_faucet;
+mod manifests_metadata;
+mod manifests_securify;
+mod manifests_staking;
+mod manifests_third_party_deposits;
+mod manifests_transfers;
+mod per_recipient_asset_transfers;
+mod staking_operations;
+
+pub use account_or_address_of::*;
+pub use manifests_access_controller::*;
+pub use manifests_faucet::*;
+pub use manifests_metadata::*;
+pub use manifests_securify::*;
+pub use manifests_staking::*;
+pub use manifests_third_party_deposits::*;
+pub use manifests_transfers::*;
+pub use per_recipient_asset_transfers::*;
+pub use staking_operations::*;
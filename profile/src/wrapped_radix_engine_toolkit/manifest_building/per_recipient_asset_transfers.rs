@@ -0,0 +1,128 @@
+use crate::prelude::*;
+
+/// Transfers of assets from one account to one or many recipients.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct PerRecipientAssetTransfers {
+    /// The account the assets are withdrawn from.
+    pub address_of_sender: AccountAddress,
+
+    /// The assets to deposit, per recipient.
+    pub transfers: Vec<PerRecipientAssetTransfer>,
+}
+
+impl PerRecipientAssetTransfers {
+    pub fn new(
+        address_of_sender: AccountAddress,
+        transfers: impl IntoIterator<Item = PerRecipientAssetTransfer>,
+    ) -> Self {
+        Self {
+            address_of_sender,
+            transfers: transfers.into_iter().collect(),
+        }
+    }
+}
+
+/// The fungible and non-fungible assets to deposit into one recipient.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct PerRecipientAssetTransfer {
+    pub recipient: AccountOrAddressOf,
+    pub fungibles: Vec<PerRecipientFungibleTransfer>,
+    pub non_fungibles: Vec<NonFungibleGlobalId>,
+}
+
+impl PerRecipientAssetTransfer {
+    pub fn new(
+        recipient: impl Into<AccountOrAddressOf>,
+        fungibles: impl IntoIterator<Item = PerRecipientFungibleTransfer>,
+        non_fungibles: impl IntoIterator<Item = NonFungibleGlobalId>,
+    ) -> Self {
+        Self {
+            recipient: recipient.into(),
+            fungibles: fungibles.into_iter().collect(),
+            non_fungibles: non_fungibles.into_iter().collect(),
+        }
+    }
+}
+
+/// An `amount` of the fungible resource `resource_address`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct PerRecipientFungibleTransfer {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+impl PerRecipientFungibleTransfer {
+    pub fn new(resource_address: ResourceAddress, amount: Decimal) -> Self {
+        Self {
+            resource_address,
+            amount,
+        }
+    }
+}
+
+impl PerRecipientAssetTransfers {
+    /// Checks that the recipients and resources are all on the network of
+    /// the sender.
+    pub(crate) fn validate_network(&self) -> Result<()> {
+        let expected = self.address_of_sender.network_id;
+        let mut found = self.transfers.iter().flat_map(|t| {
+            [t.recipient.account_address().network_id]
+                .into_iter()
+                .chain(
+                    t.fungibles.iter().map(|f| f.resource_address.network_id),
+                )
+                .chain(
+                    t.non_fungibles
+                        .iter()
+                        .map(|n| n.resource_address.network_id),
+                )
+        });
+        match found.find(|n| *n != expected) {
+            Some(found) => Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected,
+                found,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PerRecipientAssetTransfers;
+
+    #[test]
+    fn validate_network_ok() {
+        let sut = SUT::new(
+            AccountAddress::placeholder_mainnet(),
+            [PerRecipientAssetTransfer::new(
+                AccountAddress::placeholder_mainnet_other(),
+                [],
+                [],
+            )],
+        );
+        assert_eq!(sut.validate_network(), Ok(()));
+    }
+
+    #[test]
+    fn validate_network_recipient_on_other_network() {
+        let sut = SUT::new(
+            AccountAddress::placeholder_mainnet(),
+            [PerRecipientAssetTransfer::new(
+                AccountAddress::placeholder_stokenet(),
+                [],
+                [],
+            )],
+        );
+        assert_eq!(
+            sut.validate_network(),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+}
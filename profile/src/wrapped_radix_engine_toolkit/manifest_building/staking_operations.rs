@@ -0,0 +1,133 @@
+use crate::prelude::*;
+
+/// Stakes `amount` of XRD from the account `account_address` to the
+/// validator `validator_address`, the liquid stake units received are
+/// deposited into the account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct StakeXrd {
+    pub account_address: AccountAddress,
+    pub validator_address: ValidatorAddress,
+    pub amount: Decimal,
+}
+
+impl StakeXrd {
+    pub fn new(
+        account_address: AccountAddress,
+        validator_address: ValidatorAddress,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            account_address,
+            validator_address,
+            amount,
+        }
+    }
+}
+
+/// Unstakes `amount` of the liquid stake units `liquid_stake_unit_address`
+/// of the validator `validator_address` held by the account
+/// `account_address`, the stake claim NFT received is deposited into the
+/// account and can be claimed once the unstaking delay has passed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct UnstakeLiquidStakeUnits {
+    pub account_address: AccountAddress,
+    pub validator_address: ValidatorAddress,
+    pub liquid_stake_unit_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+impl UnstakeLiquidStakeUnits {
+    pub fn new(
+        account_address: AccountAddress,
+        validator_address: ValidatorAddress,
+        liquid_stake_unit_address: ResourceAddress,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            account_address,
+            validator_address,
+            liquid_stake_unit_address,
+            amount,
+        }
+    }
+}
+
+/// Claims the XRD of the matured stake claim NFTs `claim_nfts` of the
+/// validator `validator_address` held by the account `account_address`, the
+/// XRD is deposited into the account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct ClaimStake {
+    pub account_address: AccountAddress,
+    pub validator_address: ValidatorAddress,
+    pub claim_nfts: Vec<NonFungibleGlobalId>,
+}
+
+impl ClaimStake {
+    pub fn new(
+        account_address: AccountAddress,
+        validator_address: ValidatorAddress,
+        claim_nfts: impl IntoIterator<Item = NonFungibleGlobalId>,
+    ) -> Self {
+        Self {
+            account_address,
+            validator_address,
+            claim_nfts: claim_nfts.into_iter().collect(),
+        }
+    }
+}
+
+/// Returns the network of the first of `network_ids`, failing if any of the
+/// others is on another network or if there are none.
+pub(crate) fn network_of_staking_operations(
+    network_ids: impl IntoIterator<Item = NetworkID>,
+) -> Result<NetworkID> {
+    let mut network_ids = network_ids.into_iter();
+    let expected = network_ids
+        .next()
+        .ok_or(CommonError::EmptyStakingOperations)?;
+    match network_ids.find(|n| *n != expected) {
+        Some(found) => {
+            Err(CommonError::ManifestAddressOnWrongNetwork { expected, found })
+        }
+        None => Ok(expected),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn network_of_staking_operations_empty() {
+        assert_eq!(
+            network_of_staking_operations([]),
+            Err(CommonError::EmptyStakingOperations)
+        );
+    }
+
+    #[test]
+    fn network_of_staking_operations_mixed() {
+        assert_eq!(
+            network_of_staking_operations([
+                NetworkID::Mainnet,
+                NetworkID::Mainnet,
+                NetworkID::Stokenet
+            ]),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+
+    #[test]
+    fn network_of_staking_operations_same() {
+        assert_eq!(
+            network_of_staking_operations([
+                NetworkID::Stokenet,
+                NetworkID::Stokenet
+            ]),
+            Ok(NetworkID::Stokenet)
+        );
+    }
+}
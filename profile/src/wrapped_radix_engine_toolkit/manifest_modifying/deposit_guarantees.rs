@@ -0,0 +1,374 @@
+use crate::prelude::*;
+
+use radix_engine_common::math::RoundingMode as ScryptoRoundingMode;
+use transaction::prelude::InstructionV1 as ScryptoInstruction;
+
+/// A deposit into an account whose amount is only known once the transaction
+/// is executed, typically found using [`TransactionManifest::summary`] and
+/// estimated using a preview of the transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct EstimatedDeposit {
+    pub account_address: AccountAddress,
+
+    pub resource_address: ResourceAddress,
+
+    /// The index of the instruction at which the resource leaves the
+    /// worktop, see [`DepositAmount::Estimated`].
+    pub instruction_index: u64,
+
+    /// The amount deposited according to the preview of the transaction.
+    pub estimated_amount: Decimal,
+
+    /// The divisibility of the resource, `None` means the maximum of 18
+    /// decimal places.
+    pub divisibility: Option<u8>,
+
+    /// Overrides the `default_deposit_guarantee` of the user for this
+    /// deposit if set.
+    pub guarantee: Option<Decimal>,
+}
+
+/// The maximum number of decimal places of a resource.
+const MAX_DIVISIBILITY: u8 = 18;
+
+impl EstimatedDeposit {
+    /// Throws an error if `divisibility` is more than 18.
+    pub fn new(
+        account_address: AccountAddress,
+        resource_address: ResourceAddress,
+        instruction_index: u64,
+        estimated_amount: Decimal,
+        divisibility: impl Into<Option<u8>>,
+    ) -> Result<Self> {
+        let divisibility = divisibility.into();
+        Self::validate_divisibility(divisibility)?;
+        Ok(Self {
+            account_address,
+            resource_address,
+            instruction_index,
+            estimated_amount,
+            divisibility,
+            guarantee: None,
+        })
+    }
+
+    fn validate_divisibility(divisibility: Option<u8>) -> Result<()> {
+        match divisibility {
+            Some(d) if d > MAX_DIVISIBILITY => {
+                Err(CommonError::InvalidDivisibility(d))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The amount to assert on the worktop, `estimated_amount * guarantee`
+    /// rounded down to the divisibility of the resource, so that the
+    /// assertion never asks for more than what the user accepted.
+    pub fn guaranteed_amount(
+        &self,
+        default_guarantee: &Decimal,
+    ) -> Result<Decimal> {
+        // Checked again since the record might not have been created using
+        // `new`, e.g. by the host.
+        Self::validate_divisibility(self.divisibility)?;
+        let guarantee = self.guarantee.as_ref().unwrap_or(default_guarantee);
+        if guarantee.is_negative() || guarantee > &Decimal::one() {
+            return Err(CommonError::InvalidDepositGuarantee(
+                guarantee.clone(),
+            ));
+        }
+        let decimal_places = self.divisibility.unwrap_or(MAX_DIVISIBILITY);
+        (self.estimated_amount.native() * guarantee.native())
+            .checked_round(decimal_places, ScryptoRoundingMode::ToZero)
+            .map(Decimal::from_native)
+            .ok_or(CommonError::DecimalError)
+    }
+}
+
+/// Adds worktop assertions to `manifest`, guaranteeing each deposit in
+/// `deposits` at least `estimated_amount * guarantee`.
+#[uniffi::export]
+pub fn modify_manifest_add_guarantees(
+    manifest: &TransactionManifest,
+    deposits: Vec<EstimatedDeposit>,
+    default_guarantee: Decimal,
+) -> Result<TransactionManifest> {
+    manifest.adding_guarantees(deposits, &default_guarantee)
+}
+
+impl TransactionManifest {
+    /// Returns a validated copy of this manifest with an
+    /// `ASSERT_WORKTOP_CONTAINS` instruction inserted right before the
+    /// instruction at which each of the `deposits` leaves the worktop,
+    /// asserting the amount of [`EstimatedDeposit::guaranteed_amount`].
+    ///
+    /// Deposits with a guaranteed amount of zero are skipped since asserting
+    /// them would be pointless.
+    pub fn adding_guarantees(
+        &self,
+        deposits: impl IntoIterator<Item = EstimatedDeposit>,
+        default_guarantee: &Decimal,
+    ) -> Result<Self> {
+        let mut scrypto_manifest = self.scrypto_manifest()?;
+        let count = scrypto_manifest.instructions.len() as u64;
+
+        let mut assertions = Vec::new();
+        for deposit in deposits {
+            for network_id in [
+                deposit.account_address.network_id,
+                deposit.resource_address.network_id,
+            ] {
+                if network_id != self.network_id {
+                    return Err(CommonError::ManifestAddressOnWrongNetwork {
+                        expected: self.network_id,
+                        found: network_id,
+                    });
+                }
+            }
+            if deposit.instruction_index >= count {
+                return Err(CommonError::ManifestInstructionIndexOutOfBounds {
+                    index: deposit.instruction_index,
+                    count,
+                });
+            }
+            let amount = deposit.guaranteed_amount(default_guarantee)?;
+            if !amount.is_positive() {
+                continue;
+            }
+            assertions.push((
+                deposit.instruction_index as usize,
+                ScryptoInstruction::AssertWorktopContains {
+                    resource_address: deposit.resource_address.into(),
+                    amount: amount.native(),
+                },
+            ));
+        }
+
+        // Insert from the back so that the indices of the remaining
+        // assertions stay valid, keeping the order of assertions at the
+        // same index.
+        assertions.sort_by_key(|(index, _)| *index);
+        for (index, assertion) in assertions.into_iter().rev() {
+            scrypto_manifest.instructions.insert(index, assertion);
+        }
+
+        Self::from_scrypto(scrypto_manifest, self.network_id)
+    }
+}
+
+impl Profile {
+    /// Adds worktop assertions to `manifest` guaranteeing each deposit in
+    /// `deposits`, using the `default_deposit_guarantee` of the user for
+    /// deposits without a guarantee of their own.
+    pub fn manifest_adding_guarantees(
+        &self,
+        manifest: &TransactionManifest,
+        deposits: impl IntoIterator<Item = EstimatedDeposit>,
+    ) -> Result<TransactionManifest> {
+        manifest.adding_guarantees(
+            deposits,
+            &self.app_preferences.transaction.default_deposit_guarantee,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use transaction::prelude::{
+        ComponentAddress as ScryptoComponentAddress,
+        ManifestBuilder as ScryptoManifestBuilder,
+    };
+
+    fn carol() -> AccountAddress {
+        AccountAddress::placeholder_stokenet()
+    }
+
+    fn amount(s: &str) -> Decimal {
+        Decimal::try_from_str(s).unwrap()
+    }
+
+    /// `CALL_METHOD` faucet "free", then deposit of entire worktop at
+    /// instruction index 1.
+    fn manifest() -> TransactionManifest {
+        let receiver: ScryptoComponentAddress = carol().into();
+        let scrypto_manifest = ScryptoManifestBuilder::new()
+            .get_free_xrd_from_faucet()
+            .try_deposit_entire_worktop_or_abort(receiver, None)
+            .build();
+        TransactionManifest::from_scrypto(scrypto_manifest, NetworkID::Stokenet)
+            .unwrap()
+    }
+
+    fn deposit(divisibility: impl Into<Option<u8>>) -> EstimatedDeposit {
+        EstimatedDeposit::new(
+            carol(),
+            ResourceAddress::placeholder_stokenet_xrd(),
+            1,
+            amount("100.55"),
+            divisibility,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn guaranteed_amount_uses_default() {
+        assert_eq!(
+            deposit(None).guaranteed_amount(&amount("0.5")),
+            Ok(amount("50.275"))
+        );
+    }
+
+    #[test]
+    fn guaranteed_amount_rounds_down_to_divisibility() {
+        assert_eq!(
+            deposit(1).guaranteed_amount(&amount("0.5")),
+            Ok(amount("50.2"))
+        );
+        assert_eq!(
+            deposit(0).guaranteed_amount(&Decimal::one()),
+            Ok(amount("100"))
+        );
+    }
+
+    #[test]
+    fn divisibility_above_18_is_invalid() {
+        assert_eq!(
+            EstimatedDeposit::new(
+                carol(),
+                ResourceAddress::placeholder_stokenet_xrd(),
+                1,
+                amount("100.55"),
+                19,
+            ),
+            Err(CommonError::InvalidDivisibility(19))
+        );
+        assert!(EstimatedDeposit::new(
+            carol(),
+            ResourceAddress::placeholder_stokenet_xrd(),
+            1,
+            amount("100.55"),
+            18,
+        )
+        .is_ok());
+
+        let mut sut = deposit(None);
+        sut.divisibility = Some(u8::MAX);
+        assert_eq!(
+            sut.guaranteed_amount(&Decimal::one()),
+            Err(CommonError::InvalidDivisibility(u8::MAX))
+        );
+    }
+
+    #[test]
+    fn guaranteed_amount_override() {
+        let mut sut = deposit(None);
+        sut.guarantee = Some(Decimal::one());
+        assert_eq!(sut.guaranteed_amount(&amount("0.5")), Ok(amount("100.55")));
+    }
+
+    #[test]
+    fn guarantee_out_of_range() {
+        assert_eq!(
+            deposit(None).guaranteed_amount(&amount("1.01")),
+            Err(CommonError::InvalidDepositGuarantee(amount("1.01")))
+        );
+        assert_eq!(
+            deposit(None).guaranteed_amount(&amount("-0.1")),
+            Err(CommonError::InvalidDepositGuarantee(amount("-0.1")))
+        );
+    }
+
+    #[test]
+    fn assertion_inserted_before_deposit() {
+        let sut = modify_manifest_add_guarantees(
+            &manifest(),
+            vec![deposit(None)],
+            amount("0.9"),
+        )
+        .unwrap()
+        .instructions;
+
+        let assertion = sut.find("ASSERT_WORKTOP_CONTAINS").unwrap();
+        let deposit = sut.find("\"try_deposit_batch_or_abort\"").unwrap();
+        assert!(assertion < deposit);
+        assert!(sut.contains("Decimal(\"90.495\")"));
+        assert!(
+            sut.contains(&ResourceAddress::placeholder_stokenet_xrd().address)
+        );
+    }
+
+    #[test]
+    fn summary_of_modified_manifest_has_guaranteed_minimum() {
+        let sut = manifest()
+            .adding_guarantees([deposit(None)], &amount("0.9"))
+            .unwrap()
+            .summary()
+            .unwrap();
+        assert_eq!(
+            sut.account(&carol()).unwrap().deposits,
+            vec![AccountDeposit {
+                resource_address: ResourceAddress::placeholder_stokenet_xrd(),
+                amount: DepositAmount::Estimated {
+                    guaranteed_minimum: Some(amount("90.495")),
+                    instruction_index: 2
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn zero_guarantee_adds_no_assertion() {
+        let manifest = manifest();
+        assert_eq!(
+            manifest.adding_guarantees([deposit(None)], &Decimal::zero()),
+            Ok(manifest)
+        );
+    }
+
+    #[test]
+    fn profile_uses_default_deposit_guarantee() {
+        let profile = Profile::placeholder();
+        let guaranteed = deposit(None)
+            .guaranteed_amount(
+                &profile
+                    .app_preferences
+                    .transaction
+                    .default_deposit_guarantee,
+            )
+            .unwrap();
+        let sut = profile
+            .manifest_adding_guarantees(&manifest(), [deposit(None)])
+            .unwrap();
+        assert!(sut
+            .instructions
+            .contains(&format!("Decimal(\"{}\")", guaranteed)));
+    }
+
+    #[test]
+    fn instruction_index_out_of_bounds() {
+        let mut deposit = deposit(None);
+        deposit.instruction_index = 2;
+        assert_eq!(
+            manifest().adding_guarantees([deposit], &Decimal::one()),
+            Err(CommonError::ManifestInstructionIndexOutOfBounds {
+                index: 2,
+                count: 2
+            })
+        );
+    }
+
+    #[test]
+    fn deposit_on_wrong_network() {
+        let mut deposit = deposit(None);
+        deposit.resource_address = ResourceAddress::placeholder_mainnet_xrd();
+        assert_eq!(
+            manifest().adding_guarantees([deposit], &Decimal::one()),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+}
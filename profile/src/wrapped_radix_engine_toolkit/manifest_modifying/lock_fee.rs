@@ -0,0 +1,106 @@
+use crate::prelude::*;
+
+use transaction::prelude::{
+    ComponentAddress as ScryptoComponentAddress,
+    ManifestBuilder as ScryptoManifestBuilder,
+};
+
+/// Adds an instruction locking `fee` from the account `fee_payer` as the
+/// first instruction of `manifest`.
+#[uniffi::export]
+pub fn modify_manifest_lock_fee(
+    manifest: &TransactionManifest,
+    fee_payer: AccountAddress,
+    fee: Decimal,
+) -> Result<TransactionManifest> {
+    manifest.adding_lock_fee(&fee_payer, fee)
+}
+
+impl TransactionManifest {
+    /// Returns a validated copy of this manifest with a `lock_fee` call on
+    /// the account `fee_payer` inserted as the first instruction, which
+    /// requires the signature of `fee_payer`.
+    ///
+    /// Since every other instruction moves one index down, instruction
+    /// indices, e.g. those of [`EstimatedDeposit`], refer to the manifest
+    /// without the fee lock. Add guarantees first, then lock the fee.
+    pub fn adding_lock_fee(
+        &self,
+        fee_payer: &AccountAddress,
+        fee: Decimal,
+    ) -> Result<Self> {
+        if fee_payer.network_id != self.network_id {
+            return Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: self.network_id,
+                found: fee_payer.network_id,
+            });
+        }
+        let account: ScryptoComponentAddress = fee_payer.clone().into();
+        let mut scrypto_manifest = self.scrypto_manifest()?;
+        let lock_fee = ScryptoManifestBuilder::new()
+            .lock_fee(account, fee.native())
+            .build()
+            .instructions;
+        scrypto_manifest.instructions.splice(0..0, lock_fee);
+
+        Self::from_scrypto(scrypto_manifest, self.network_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn manifest() -> TransactionManifest {
+        TransactionManifest::single_fungible_transfer(
+            &AccountAddress::placeholder_mainnet(),
+            AccountAddress::placeholder_mainnet_other(),
+            ResourceAddress::placeholder(),
+            Decimal::one(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn lock_fee_is_first_instruction() {
+        let fee_payer = AccountAddress::placeholder_mainnet_other();
+        let sut = modify_manifest_lock_fee(
+            &manifest(),
+            fee_payer.clone(),
+            Decimal::try_from_str("0.61").unwrap(),
+        )
+        .unwrap()
+        .instructions;
+
+        assert!(sut.starts_with("CALL_METHOD"));
+        let lock_fee = sut.find("\"lock_fee\"").unwrap();
+        assert!(lock_fee < sut.find("\"withdraw\"").unwrap());
+        assert!(sut[..lock_fee].contains(&fee_payer.address));
+        assert!(sut.contains("Decimal(\"0.61\")"));
+    }
+
+    #[test]
+    fn fee_payer_requires_signature() {
+        let fee_payer = AccountAddress::placeholder_mainnet_other();
+        let sut = manifest()
+            .adding_lock_fee(&fee_payer, Decimal::one())
+            .unwrap()
+            .summary()
+            .unwrap();
+        assert!(sut.account(&fee_payer).unwrap().requires_signature);
+    }
+
+    #[test]
+    fn fee_payer_on_wrong_network() {
+        assert_eq!(
+            manifest().adding_lock_fee(
+                &AccountAddress::placeholder_stokenet(),
+                Decimal::one()
+            ),
+            Err(CommonError::ManifestAddressOnWrongNetwork {
+                expected: NetworkID::Mainnet,
+                found: NetworkID::Stokenet
+            })
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod deposit_guarantees;
+mod lock_fee;
+
+pub use deposit_guarantees::*;
+pub use lock_fee::*;
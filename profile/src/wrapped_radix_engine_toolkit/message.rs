@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+use transaction::prelude::{
+    MessageContentsV1 as ScryptoMessageContents, MessageV1 as ScryptoMessage,
+    PlaintextMessageV1 as ScryptoPlaintextMessage,
+};
+
+/// An optional message attached to a transaction intent, e.g. a note from
+/// the sender to the recipient of a transfer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum Message {
+    PlainText { mime_type: String, message: String },
+    None,
+}
+
+impl Message {
+    /// A plaintext message with MIME type `text/plain`.
+    pub fn plain_text(message: impl AsRef<str>) -> Self {
+        Self::PlainText {
+            mime_type: "text/plain".to_owned(),
+            message: message.as_ref().to_owned(),
+        }
+    }
+
+    pub(crate) fn scrypto_message(&self) -> ScryptoMessage {
+        match self {
+            Self::PlainText { mime_type, message } => {
+                ScryptoMessage::Plaintext(ScryptoPlaintextMessage {
+                    mime_type: mime_type.clone(),
+                    message: ScryptoMessageContents::String(message.clone()),
+                })
+            }
+            Self::None => ScryptoMessage::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use transaction::prelude::MessageV1 as ScryptoMessage;
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(
+            Message::plain_text("Hello"),
+            Message::PlainText {
+                mime_type: "text/plain".to_owned(),
+                message: "Hello".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn none_to_scrypto() {
+        assert_eq!(Message::None.scrypto_message(), ScryptoMessage::None);
+    }
+
+    #[test]
+    fn plain_text_to_scrypto() {
+        assert!(matches!(
+            Message::plain_text("Hello").scrypto_message(),
+            ScryptoMessage::Plaintext(_)
+        ));
+    }
+}
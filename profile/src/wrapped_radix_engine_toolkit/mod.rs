@@ -0,0 +1,23 @@
+mod fees;
+mod intent_signature;
+mod manifest_analysis;
+mod manifest_building;
+mod manifest_modifying;
+mod message;
+mod notarized_transaction;
+mod transaction_hashes;
+mod transaction_header;
+mod transaction_intent;
+mod transaction_manifest;
+
+pub use fees::*;
+pub use intent_signature::*;
+pub use manifest_analysis::*;
+pub use manifest_building::*;
+pub use manifest_modifying::*;
+pub use message::*;
+pub use notarized_transaction::*;
+pub use transaction_hashes::*;
+pub use transaction_header::*;
+pub use transaction_intent::*;
+pub use transaction_manifest::*;
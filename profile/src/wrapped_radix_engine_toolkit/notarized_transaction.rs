@@ -0,0 +1,239 @@
+use crate::prelude::*;
+
+use transaction::prelude::{
+    HasSignedIntentHash as ScryptoHasSignedIntentHash,
+    IntentSignaturesV1 as ScryptoIntentSignatures,
+    NotarizedTransactionV1 as ScryptoNotarizedTransaction,
+    NotarySignatureV1 as ScryptoNotarySignature,
+    SignatureV1 as ScryptoSignature, SignedIntentV1 as ScryptoSignedIntent,
+    TransactionPayload as ScryptoTransactionPayload,
+};
+
+/// A transaction intent signed by the notary, ready to be submitted to the
+/// network using its `compiled` payload.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct NotarizedTransaction {
+    pub intent: TransactionIntent,
+
+    /// The identifier of the transaction, e.g. `txid_rdx1...`.
+    pub intent_hash: IntentHash,
+
+    /// The hash signed by the notary.
+    pub signed_intent_hash: SignedIntentHash,
+
+    /// The binary payload to submit to the network.
+    pub compiled: BagOfBytes,
+}
+
+/// A notary with an Ed25519 key generated by the kit, which is used to
+/// notarize a single transaction and then thrown away, meaning the key
+/// never needs to be stored.
+///
+/// Create the [`TransactionHeader`] using the [`EphemeralNotary::public_key`]
+/// with `notary_is_signatory` set if the notary should count as the signer
+/// of the intent.
+#[derive(Debug, uniffi::Object)]
+pub struct EphemeralNotary {
+    private_key: Ed25519PrivateKey,
+}
+
+#[uniffi::export]
+impl EphemeralNotary {
+    /// Generates a new notary with a random Ed25519 key.
+    #[uniffi::constructor]
+    pub fn generate() -> Self {
+        Self::with_private_key(Ed25519PrivateKey::generate())
+    }
+
+    /// The public key to use as `notary_public_key` of the header.
+    pub fn public_key(&self) -> PublicKey {
+        self.private_key.public_key().into()
+    }
+
+    /// Notarizes `intent`, which must have been created with
+    /// [`EphemeralNotary::public_key`] as notary public key, including the
+    /// `intent_signatures` of the entities whose auth the manifest requires.
+    ///
+    /// Fails if any of the `intent_signatures` is not a valid signature of
+    /// the intent hash of `intent`.
+    pub fn notarize(
+        &self,
+        intent: TransactionIntent,
+        intent_signatures: Vec<IntentSignature>,
+    ) -> Result<NotarizedTransaction> {
+        if intent.header.notary_public_key != self.public_key() {
+            return Err(CommonError::NotaryPublicKeyMismatch);
+        }
+
+        let intent_hash = intent.intent_hash()?;
+        let signatures = intent_signatures
+            .iter()
+            .map(|s| s.scrypto_signature(&intent_hash))
+            .collect::<Result<Vec<_>>>()?;
+        let signed_intent = ScryptoSignedIntent {
+            intent: intent.scrypto_intent()?,
+            intent_signatures: ScryptoIntentSignatures { signatures },
+        };
+        let scrypto_signed_intent_hash = signed_intent
+            .prepare()
+            .map_err(|e| {
+                CommonError::InvalidTransactionIntent(format!("{:?}", e))
+            })?
+            .signed_intent_hash();
+        let signed_intent_hash = SignedIntentHash::from_scrypto(
+            scrypto_signed_intent_hash,
+            intent.network_id(),
+        );
+
+        let notary_signature =
+            self.private_key.sign(&signed_intent_hash.scrypto_hash());
+        let notarized = ScryptoNotarizedTransaction {
+            signed_intent,
+            notary_signature: ScryptoNotarySignature(
+                ScryptoSignature::Ed25519(notary_signature),
+            ),
+        };
+        let compiled =
+            notarized.to_payload_bytes().map(BagOfBytes::from).map_err(
+                |e| CommonError::InvalidTransactionIntent(format!("{:?}", e)),
+            )?;
+
+        Ok(NotarizedTransaction {
+            intent_hash,
+            signed_intent_hash,
+            compiled,
+            intent,
+        })
+    }
+}
+
+impl EphemeralNotary {
+    pub(crate) fn with_private_key(private_key: Ed25519PrivateKey) -> Self {
+        Self { private_key }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn intent(notary: &EphemeralNotary) -> TransactionIntent {
+        let mut header = TransactionHeader::placeholder();
+        header.notary_public_key = notary.public_key();
+        TransactionIntent::new(
+            header,
+            TransactionManifest::set_account_metadata(
+                &AccountAddress::placeholder_mainnet(),
+                "name",
+                MetadataValue::Str {
+                    value: "Savings".to_owned(),
+                },
+            )
+            .unwrap(),
+            Message::None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn generated_notaries_differ() {
+        assert_ne!(
+            EphemeralNotary::generate().public_key(),
+            EphemeralNotary::generate().public_key()
+        );
+    }
+
+    #[test]
+    fn public_key_is_ed25519() {
+        assert_eq!(
+            EphemeralNotary::generate().public_key().curve(),
+            SLIP10Curve::Curve25519
+        );
+    }
+
+    #[test]
+    fn notarize() {
+        let notary = EphemeralNotary::generate();
+        let intent = intent(&notary);
+        let sut = notary.notarize(intent.clone(), Vec::new()).unwrap();
+        assert_eq!(sut.intent_hash, intent.intent_hash().unwrap());
+        assert_eq!(sut.signed_intent_hash.network_id, NetworkID::Mainnet);
+        assert!(sut.compiled.len() > intent.compile().unwrap().len());
+    }
+
+    #[test]
+    fn notarize_is_deterministic_for_same_key() {
+        let notary =
+            EphemeralNotary::with_private_key(Ed25519PrivateKey::placeholder());
+        let intent = intent(&notary);
+        assert_eq!(
+            notary.notarize(intent.clone(), Vec::new()),
+            notary.notarize(intent, Vec::new())
+        );
+    }
+
+    #[test]
+    fn notarize_with_other_notary_fails() {
+        let intent = intent(&EphemeralNotary::generate());
+        assert_eq!(
+            EphemeralNotary::generate().notarize(intent, Vec::new()),
+            Err(CommonError::NotaryPublicKeyMismatch)
+        );
+    }
+
+    fn transfer(
+        notary: &EphemeralNotary,
+        sender_key: &PrivateKey,
+    ) -> TransactionIntent {
+        let mut header = TransactionHeader::placeholder();
+        header.notary_public_key = notary.public_key();
+        TransactionIntent::new(
+            header,
+            TransactionManifest::single_fungible_transfer(
+                &AccountAddress::new(
+                    sender_key.public_key(),
+                    NetworkID::Mainnet,
+                ),
+                AccountAddress::placeholder_mainnet_other(),
+                ResourceAddress::placeholder_mainnet_xrd(),
+                Decimal::try_from_str("10").unwrap(),
+            )
+            .unwrap(),
+            Message::None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn notarize_transfer_with_account_signature() {
+        let notary = EphemeralNotary::generate();
+        let sender_key: PrivateKey = Ed25519PrivateKey::placeholder().into();
+        let intent = transfer(&notary, &sender_key);
+        let signature =
+            IntentSignature::sign(&sender_key, &intent.intent_hash().unwrap());
+
+        let unsigned = notary.notarize(intent.clone(), Vec::new()).unwrap();
+        let signed = notary.notarize(intent.clone(), vec![signature]).unwrap();
+
+        assert_eq!(signed.intent_hash, unsigned.intent_hash);
+        assert_ne!(signed.signed_intent_hash, unsigned.signed_intent_hash);
+        assert!(signed.compiled.len() > unsigned.compiled.len());
+    }
+
+    #[test]
+    fn notarize_with_signature_of_other_intent_fails() {
+        let notary = EphemeralNotary::generate();
+        let sender_key: PrivateKey = Ed25519PrivateKey::placeholder().into();
+        let other_intent = intent(&notary);
+        let signature = IntentSignature::sign(
+            &sender_key,
+            &other_intent.intent_hash().unwrap(),
+        );
+        assert_eq!(
+            notary.notarize(transfer(&notary, &sender_key), vec![signature]),
+            Err(CommonError::InvalidIntentSignature {
+                public_key: sender_key.public_key().to_hex()
+            })
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::Hash as ScryptoHash;
+use transaction::prelude::{
+    IntentHash as ScryptoIntentHash,
+    SignedIntentHash as ScryptoSignedIntentHash,
+    TransactionHashBech32Decoder as ScryptoTransactionHashBech32Decoder,
+    TransactionHashBech32Encoder as ScryptoTransactionHashBech32Encoder,
+};
+
+/// Finds the network of the bech32 encoded transaction hash `s`, using the
+/// human readable part, e.g. `txid_rdx` for Mainnet.
+fn network_of_bech32_hash(prefix: &str, s: &str) -> Option<NetworkID> {
+    let (hrp, _) = s.rsplit_once('1')?;
+    enum_iterator::all::<NetworkID>().find(|n| {
+        format!("{}{}", prefix, n.network_definition().hrp_suffix) == hrp
+    })
+}
+
+// We have to use macros since UniFFI does not support generics, that is the
+// only reason this macro exists.
+macro_rules! declare_transaction_hash {
+    (
+        $(#[doc = $expr: expr])*
+        $struct_name:ident,
+        $scrypto_hash:ty,
+        $hrp_prefix:literal,
+        $error:ident
+    ) => {
+        $(#[doc = $expr])*
+        #[derive(
+            Clone,
+            PartialEq,
+            Eq,
+            Hash,
+            SerializeDisplay,
+            DeserializeFromStr,
+            derive_more::Display,
+            derive_more::Debug,
+            uniffi::Record,
+        )]
+        #[display("{bech32_encoded_tx_id}")]
+        #[debug("{bech32_encoded_tx_id}")]
+        pub struct $struct_name {
+            /// Which network this transaction hash is used on.
+            pub network_id: NetworkID,
+
+            /// The hash of the transaction.
+            pub hash: Hex32Bytes,
+
+            /// Bech32 encoded transaction hash, e.g. `txid_rdx1...`.
+            pub bech32_encoded_tx_id: String,
+        }
+
+        impl $struct_name {
+            pub(crate) fn from_scrypto(
+                scrypto: $scrypto_hash,
+                network_id: NetworkID,
+            ) -> Self {
+                let bech32_encoded_tx_id =
+                    ScryptoTransactionHashBech32Encoder::new(
+                        &network_id.network_definition(),
+                    )
+                    .encode(&scrypto)
+                    .expect("Bech32 encoding of a hash should never fail.");
+                Self {
+                    network_id,
+                    hash: Hex32Bytes::from_bytes(&scrypto.0 .0),
+                    bech32_encoded_tx_id,
+                }
+            }
+
+            /// Decodes the bech32 encoded transaction hash `s`, the
+            /// network is derived from its human readable part.
+            pub fn from_bech32(s: &str) -> Result<Self> {
+                let network_id = network_of_bech32_hash($hrp_prefix, s)
+                    .ok_or(CommonError::$error(s.to_owned()))?;
+                ScryptoTransactionHashBech32Decoder::new(
+                    &network_id.network_definition(),
+                )
+                .validate_and_decode::<$scrypto_hash>(s)
+                .map(|h| Self::from_scrypto(h, network_id))
+                .map_err(|_| CommonError::$error(s.to_owned()))
+            }
+
+            pub(crate) fn scrypto_hash(&self) -> ScryptoHash {
+                ScryptoHash(
+                    self.hash
+                        .to_vec()
+                        .try_into()
+                        .expect("Hex32Bytes to always be 32 bytes."),
+                )
+            }
+        }
+
+        impl FromStr for $struct_name {
+            type Err = CommonError;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::from_bech32(s)
+            }
+        }
+    };
+}
+
+declare_transaction_hash!(
+    /// The hash of a transaction intent, which is the identifier of the
+    /// transaction, Bech32 encoded with human readable part `txid_`, e.g.
+    /// `txid_rdx1...` on Mainnet.
+    IntentHash,
+    ScryptoIntentHash,
+    "txid_",
+    InvalidIntentHash
+);
+
+declare_transaction_hash!(
+    /// The hash of a transaction intent and its signatures, which is what
+    /// the notary signs, Bech32 encoded with human readable part
+    /// `signedintent_`, e.g. `signedintent_rdx1...` on Mainnet.
+    SignedIntentHash,
+    ScryptoSignedIntentHash,
+    "signedintent_",
+    InvalidSignedIntentHash
+);
+
+#[uniffi::export]
+pub fn new_intent_hash_from_string(string: String) -> Result<IntentHash> {
+    IntentHash::from_bech32(&string)
+}
+
+#[uniffi::export]
+pub fn new_signed_intent_hash_from_string(
+    string: String,
+) -> Result<SignedIntentHash> {
+    SignedIntentHash::from_bech32(&string)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use radix_engine_common::crypto::Hash as ScryptoHash;
+    use transaction::prelude::IntentHash as ScryptoIntentHash;
+
+    fn hash() -> ScryptoIntentHash {
+        ScryptoIntentHash(ScryptoHash([0xab; 32]))
+    }
+
+    #[test]
+    fn intent_hash_bech32_prefix() {
+        let sut = IntentHash::from_scrypto(hash(), NetworkID::Mainnet);
+        assert!(sut.bech32_encoded_tx_id.starts_with("txid_rdx1"));
+        let sut = IntentHash::from_scrypto(hash(), NetworkID::Stokenet);
+        assert!(sut.bech32_encoded_tx_id.starts_with("txid_tdx_2_1"));
+    }
+
+    #[test]
+    fn intent_hash_roundtrip() {
+        for network_id in [NetworkID::Mainnet, NetworkID::Stokenet] {
+            let sut = IntentHash::from_scrypto(hash(), network_id);
+            assert_eq!(
+                new_intent_hash_from_string(sut.to_string()),
+                Ok(sut.clone())
+            );
+            assert_eq!(sut.network_id, network_id);
+            assert_eq!(sut.scrypto_hash(), hash().0);
+        }
+    }
+
+    #[test]
+    fn intent_hash_json_roundtrip() {
+        let sut = IntentHash::from_scrypto(hash(), NetworkID::Mainnet);
+        let json = serde_json::to_value(&sut).unwrap();
+        assert_eq!(json, serde_json::json!(sut.bech32_encoded_tx_id));
+        assert_eq!(serde_json::from_value::<IntentHash>(json).unwrap(), sut);
+    }
+
+    #[test]
+    fn intent_hash_invalid() {
+        let s = "txid_rdx1invalid";
+        assert_eq!(
+            IntentHash::from_str(s),
+            Err(CommonError::InvalidIntentHash(s.to_owned()))
+        );
+    }
+
+    #[test]
+    fn signed_intent_hash_is_not_intent_hash() {
+        let signed = SignedIntentHash::from_bech32(
+            &IntentHash::from_scrypto(hash(), NetworkID::Mainnet).to_string(),
+        );
+        assert!(matches!(
+            signed,
+            Err(CommonError::InvalidSignedIntentHash(_))
+        ));
+    }
+
+    #[test]
+    fn signed_intent_hash_roundtrip() {
+        let sut = SignedIntentHash::from_scrypto(
+            transaction::prelude::SignedIntentHash(ScryptoHash([1; 32])),
+            NetworkID::Stokenet,
+        );
+        assert!(sut.bech32_encoded_tx_id.starts_with("signedintent_tdx_2_1"));
+        assert_eq!(
+            new_signed_intent_hash_from_string(sut.to_string()),
+            Ok(sut)
+        );
+    }
+}
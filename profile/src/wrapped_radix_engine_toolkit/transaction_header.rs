@@ -0,0 +1,192 @@
+use crate::prelude::*;
+
+use radix_engine_common::types::Epoch as ScryptoEpoch;
+use transaction::prelude::TransactionHeaderV1 as ScryptoTransactionHeader;
+
+/// The header of a transaction intent, specifying the network and the epoch
+/// window during which the transaction can be committed, the notary and the
+/// tip paid to the validator.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct TransactionHeader {
+    pub network_id: NetworkID,
+
+    /// The first epoch in which the transaction can be committed.
+    pub start_epoch_inclusive: u64,
+
+    /// The epoch from which the transaction no longer can be committed.
+    pub end_epoch_exclusive: u64,
+
+    /// Random number making the intent hash unique, two otherwise identical
+    /// intents would result in the same hash.
+    pub nonce: u32,
+
+    /// The public key of the notary, the notary signs the signed intent,
+    /// see [`EphemeralNotary`].
+    pub notary_public_key: PublicKey,
+
+    /// If `true` the notary counts as a signatory of the intent, allowing
+    /// transactions with a single signer to skip the intent signatures.
+    pub notary_is_signatory: bool,
+
+    /// Percentage of the fee paid as a tip to the validator.
+    pub tip_percentage: u16,
+}
+
+#[uniffi::export]
+pub fn new_transaction_header(
+    network_id: NetworkID,
+    start_epoch_inclusive: u64,
+    end_epoch_exclusive: u64,
+    notary_public_key: PublicKey,
+    notary_is_signatory: bool,
+    tip_percentage: u16,
+) -> Result<TransactionHeader> {
+    TransactionHeader::new(
+        network_id,
+        start_epoch_inclusive,
+        end_epoch_exclusive,
+        notary_public_key,
+        notary_is_signatory,
+        tip_percentage,
+    )
+}
+
+impl TransactionHeader {
+    /// Creates a new header with a random nonce, the epoch window must not
+    /// be empty.
+    pub fn new(
+        network_id: NetworkID,
+        start_epoch_inclusive: u64,
+        end_epoch_exclusive: u64,
+        notary_public_key: PublicKey,
+        notary_is_signatory: bool,
+        tip_percentage: u16,
+    ) -> Result<Self> {
+        if end_epoch_exclusive <= start_epoch_inclusive {
+            return Err(CommonError::InvalidEpochWindow {
+                start: start_epoch_inclusive,
+                end: end_epoch_exclusive,
+            });
+        }
+        Ok(Self {
+            network_id,
+            start_epoch_inclusive,
+            end_epoch_exclusive,
+            nonce: Self::generate_nonce(),
+            notary_public_key,
+            notary_is_signatory,
+            tip_percentage,
+        })
+    }
+
+    fn generate_nonce() -> u32 {
+        u32::from_be_bytes(
+            generate_bytes::<4>()
+                .try_into()
+                .expect("Should have generated 4 bytes"),
+        )
+    }
+
+    pub(crate) fn scrypto_header(&self) -> ScryptoTransactionHeader {
+        ScryptoTransactionHeader {
+            network_id: self.network_id.discriminant(),
+            start_epoch_inclusive: ScryptoEpoch::of(self.start_epoch_inclusive),
+            end_epoch_exclusive: ScryptoEpoch::of(self.end_epoch_exclusive),
+            nonce: self.nonce,
+            notary_public_key: self.notary_public_key.clone().into(),
+            notary_is_signatory: self.notary_is_signatory,
+            tip_percentage: self.tip_percentage,
+        }
+    }
+}
+
+impl HasPlaceholder for TransactionHeader {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self {
+            network_id: NetworkID::Mainnet,
+            start_epoch_inclusive: 76935,
+            end_epoch_exclusive: 76945,
+            nonce: 2371337,
+            notary_public_key: PublicKey::placeholder_ed25519(),
+            notary_is_signatory: true,
+            tip_percentage: 0,
+        }
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self {
+            network_id: NetworkID::Stokenet,
+            start_epoch_inclusive: 42,
+            end_epoch_exclusive: 52,
+            nonce: 1,
+            notary_public_key: PublicKey::placeholder_secp256k1(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionHeader;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn new_uses_random_nonce() {
+        let n = 100;
+        let nonces = (0..n)
+            .map(|_| {
+                new_transaction_header(
+                    NetworkID::Mainnet,
+                    1,
+                    2,
+                    PublicKey::placeholder_ed25519(),
+                    true,
+                    0,
+                )
+                .unwrap()
+                .nonce
+            })
+            .collect::<HashSet<_>>();
+        assert!(nonces.len() > n - 5);
+    }
+
+    #[test]
+    fn empty_epoch_window_is_invalid() {
+        assert_eq!(
+            SUT::new(
+                NetworkID::Mainnet,
+                10,
+                10,
+                PublicKey::placeholder_ed25519(),
+                true,
+                0
+            ),
+            Err(CommonError::InvalidEpochWindow { start: 10, end: 10 })
+        );
+    }
+
+    #[test]
+    fn scrypto_header() {
+        let sut = SUT::placeholder_other().scrypto_header();
+        assert_eq!(sut.network_id, 2);
+        assert_eq!(sut.nonce, 1);
+        assert_eq!(sut.tip_percentage, 5);
+        assert!(!sut.notary_is_signatory);
+    }
+}
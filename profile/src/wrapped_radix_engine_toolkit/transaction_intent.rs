@@ -0,0 +1,178 @@
+use crate::prelude::*;
+
+use transaction::prelude::{
+    BlobV1 as ScryptoBlob, BlobsV1 as ScryptoBlobs,
+    HasIntentHash as ScryptoHasIntentHash,
+    InstructionsV1 as ScryptoInstructions, IntentV1 as ScryptoIntent,
+    TransactionPayload as ScryptoTransactionPayload,
+};
+
+/// A transaction manifest along with the header and an optional message,
+/// this is what is signed by the signers of a transaction and the hash of
+/// which identifies the transaction, see [`TransactionIntent::intent_hash`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct TransactionIntent {
+    pub header: TransactionHeader,
+    pub manifest: TransactionManifest,
+    pub message: Message,
+}
+
+#[uniffi::export]
+pub fn new_transaction_intent(
+    header: TransactionHeader,
+    manifest: TransactionManifest,
+    message: Message,
+) -> Result<TransactionIntent> {
+    TransactionIntent::new(header, manifest, message)
+}
+
+#[uniffi::export]
+pub fn transaction_intent_hash(
+    intent: &TransactionIntent,
+) -> Result<IntentHash> {
+    intent.intent_hash()
+}
+
+#[uniffi::export]
+pub fn transaction_intent_compile(
+    intent: &TransactionIntent,
+) -> Result<BagOfBytes> {
+    intent.compile()
+}
+
+impl TransactionIntent {
+    /// Creates a new intent, the network of `header` and `manifest` must
+    /// be the same.
+    pub fn new(
+        header: TransactionHeader,
+        manifest: TransactionManifest,
+        message: Message,
+    ) -> Result<Self> {
+        if header.network_id != manifest.network_id {
+            return Err(CommonError::TransactionNetworkMismatch {
+                header: header.network_id,
+                manifest: manifest.network_id,
+            });
+        }
+        Ok(Self {
+            header,
+            manifest,
+            message,
+        })
+    }
+
+    pub fn network_id(&self) -> NetworkID {
+        self.header.network_id
+    }
+
+    pub(crate) fn scrypto_intent(&self) -> Result<ScryptoIntent> {
+        let scrypto_manifest = self.manifest.scrypto_manifest()?;
+        Ok(ScryptoIntent {
+            header: self.header.scrypto_header(),
+            instructions: ScryptoInstructions(scrypto_manifest.instructions),
+            blobs: ScryptoBlobs {
+                blobs: scrypto_manifest
+                    .blobs
+                    .into_values()
+                    .map(ScryptoBlob)
+                    .collect(),
+            },
+            message: self.message.scrypto_message(),
+        })
+    }
+
+    /// The hash identifying this transaction, e.g. `txid_rdx1...`.
+    pub fn intent_hash(&self) -> Result<IntentHash> {
+        self.scrypto_intent()?
+            .prepare()
+            .map(|p| {
+                IntentHash::from_scrypto(p.intent_hash(), self.network_id())
+            })
+            .map_err(|e| {
+                CommonError::InvalidTransactionIntent(format!("{:?}", e))
+            })
+    }
+
+    /// Compiles this intent into its binary payload format.
+    pub fn compile(&self) -> Result<BagOfBytes> {
+        self.scrypto_intent()?
+            .to_payload_bytes()
+            .map(BagOfBytes::from)
+            .map_err(|e| {
+                CommonError::InvalidTransactionIntent(format!("{:?}", e))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn manifest(network_id: NetworkID) -> TransactionManifest {
+        TransactionManifest::set_account_metadata(
+            &match network_id {
+                NetworkID::Mainnet => AccountAddress::placeholder_mainnet(),
+                _ => AccountAddress::placeholder_stokenet(),
+            },
+            "name",
+            MetadataValue::Str {
+                value: "Savings".to_owned(),
+            },
+        )
+        .unwrap()
+    }
+
+    fn intent() -> TransactionIntent {
+        new_transaction_intent(
+            TransactionHeader::placeholder(),
+            manifest(NetworkID::Mainnet),
+            Message::plain_text("Hello"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn network_mismatch() {
+        assert_eq!(
+            TransactionIntent::new(
+                TransactionHeader::placeholder(),
+                manifest(NetworkID::Stokenet),
+                Message::None,
+            ),
+            Err(CommonError::TransactionNetworkMismatch {
+                header: NetworkID::Mainnet,
+                manifest: NetworkID::Stokenet
+            })
+        );
+    }
+
+    #[test]
+    fn intent_hash_is_txid_on_network() {
+        let sut = transaction_intent_hash(&intent()).unwrap();
+        assert!(sut.bech32_encoded_tx_id.starts_with("txid_rdx1"));
+        assert_eq!(sut.network_id, NetworkID::Mainnet);
+    }
+
+    #[test]
+    fn intent_hash_is_deterministic() {
+        assert_eq!(intent().intent_hash(), intent().intent_hash());
+    }
+
+    #[test]
+    fn intent_hash_depends_on_nonce_and_message() {
+        let mut other_nonce = intent();
+        other_nonce.header.nonce += 1;
+        assert_ne!(intent().intent_hash(), other_nonce.intent_hash());
+
+        let mut other_message = intent();
+        other_message.message = Message::None;
+        assert_ne!(intent().intent_hash(), other_message.intent_hash());
+    }
+
+    #[test]
+    fn compile() {
+        let sut = transaction_intent_compile(&intent()).unwrap();
+        assert!(!sut.is_empty());
+        assert_eq!(intent().compile(), Ok(sut));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::prelude::*;
+
+use radix_engine_toolkit::functions::manifest::statically_validate as ret_statically_validate_manifest;
+use transaction::manifest::{
+    compile as scrypto_compile, decompile as scrypto_decompile,
+    BlobProvider as ScryptoBlobProvider,
+};
+use transaction::prelude::TransactionManifestV1 as ScryptoTransactionManifest;
+
+/// A statically validated transaction manifest for a specific network,
+/// the instructions are kept in the canonical textual manifest format
+/// (as output by the Radix Engine Toolkit), e.g.:
+///
+/// ```text
+/// CALL_METHOD
+///     Address("account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease")
+///     "lock_fee"
+///     Decimal("0.61")
+/// ;
+/// ```
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, derive_more::Display, uniffi::Record,
+)]
+#[display("{instructions}")]
+pub struct TransactionManifest {
+    /// The instructions of this manifest in the canonical textual format.
+    pub instructions: String,
+
+    /// The network the addresses in the instructions are on.
+    pub network_id: NetworkID,
+
+    /// Blobs referenced by the instructions, e.g. WASM code of packages.
+    pub blobs: Vec<BagOfBytes>,
+}
+
+#[uniffi::export]
+pub fn new_transaction_manifest_from_instructions_string_and_blobs(
+    instructions_string: String,
+    network_id: NetworkID,
+    blobs: Vec<BagOfBytes>,
+) -> Result<TransactionManifest> {
+    TransactionManifest::new(instructions_string, network_id, blobs)
+}
+
+impl TransactionManifest {
+    /// Compiles and statically validates `instructions_string` for the
+    /// network `network_id`, the instructions are normalized into the
+    /// canonical textual format.
+    pub fn new(
+        instructions_string: impl AsRef<str>,
+        network_id: NetworkID,
+        blobs: Vec<BagOfBytes>,
+    ) -> Result<Self> {
+        let scrypto_manifest = scrypto_compile(
+            instructions_string.as_ref(),
+            &network_id.network_definition(),
+            ScryptoBlobProvider::new_with_blobs(
+                blobs.iter().map(|b| b.to_vec()).collect(),
+            ),
+        )
+        .map_err(|e| {
+            CommonError::InvalidTransactionManifest(format!("{:?}", e))
+        })?;
+        Self::from_scrypto(scrypto_manifest, network_id)
+    }
+
+    /// Statically validates `scrypto_manifest` using the Radix Engine
+    /// Toolkit and decompiles it into its canonical textual format.
+    pub(crate) fn from_scrypto(
+        scrypto_manifest: ScryptoTransactionManifest,
+        network_id: NetworkID,
+    ) -> Result<Self> {
+        ret_statically_validate_manifest(&scrypto_manifest).map_err(|e| {
+            CommonError::InvalidTransactionManifest(format!("{:?}", e))
+        })?;
+
+        let instructions = scrypto_decompile(
+            &scrypto_manifest.instructions,
+            &network_id.network_definition(),
+        )
+        .map_err(|e| {
+            CommonError::InvalidTransactionManifest(format!("{:?}", e))
+        })?;
+
+        let blobs = scrypto_manifest
+            .blobs
+            .into_values()
+            .map(BagOfBytes::from)
+            .collect_vec();
+
+        Ok(Self {
+            instructions,
+            network_id,
+            blobs,
+        })
+    }
+
+    /// Compiles the instructions back into a statically validated Scrypto
+    /// manifest. Since the fields are public, the host can create a manifest
+    /// without [`TransactionManifest::new`], so this can fail.
+    pub(crate) fn scrypto_manifest(
+        &self,
+    ) -> Result<ScryptoTransactionManifest> {
+        let scrypto_manifest = scrypto_compile(
+            &self.instructions,
+            &self.network_id.network_definition(),
+            ScryptoBlobProvider::new_with_blobs(
+                self.blobs.iter().map(|b| b.to_vec()).collect(),
+            ),
+        )
+        .map_err(|e| {
+            CommonError::InvalidTransactionManifest(format!("{:?}", e))
+        })?;
+        ret_statically_validate_manifest(&scrypto_manifest).map_err(|e| {
+            CommonError::InvalidTransactionManifest(format!("{:?}", e))
+        })?;
+        Ok(scrypto_manifest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionManifest;
+
+    const LOCK_FEE: &str = r#"
+    CALL_METHOD
+        Address("account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease")
+        "lock_fee"
+        Decimal("0.61")
+    ;
+    "#;
+
+    #[test]
+    fn new_normalizes_instructions() {
+        let sut = SUT::new(LOCK_FEE, NetworkID::Mainnet, Vec::new()).unwrap();
+        assert!(sut.instructions.starts_with("CALL_METHOD"));
+        assert!(sut.instructions.contains("\"lock_fee\""));
+        assert_eq!(
+            SUT::new(&sut.instructions, NetworkID::Mainnet, Vec::new()),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn display_is_instructions() {
+        let sut = SUT::new(LOCK_FEE, NetworkID::Mainnet, Vec::new()).unwrap();
+        assert_eq!(format!("{sut}"), sut.instructions);
+    }
+
+    #[test]
+    fn scrypto_manifest_roundtrip() {
+        let sut = SUT::new(LOCK_FEE, NetworkID::Mainnet, Vec::new()).unwrap();
+        assert_eq!(
+            SUT::from_scrypto(
+                sut.scrypto_manifest().unwrap(),
+                NetworkID::Mainnet
+            ),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn scrypto_manifest_of_unvalidated_manifest_fails() {
+        let sut = SUT {
+            instructions: "CALL_METHOD;".to_owned(),
+            network_id: NetworkID::Mainnet,
+            blobs: Vec::new(),
+        };
+        assert!(matches!(
+            sut.scrypto_manifest(),
+            Err(CommonError::InvalidTransactionManifest(_))
+        ));
+    }
+
+    #[test]
+    fn scrypto_manifest_on_wrong_network_fails() {
+        let mut sut =
+            SUT::new(LOCK_FEE, NetworkID::Mainnet, Vec::new()).unwrap();
+        sut.network_id = NetworkID::Stokenet;
+        assert!(matches!(
+            sut.scrypto_manifest(),
+            Err(CommonError::InvalidTransactionManifest(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_instructions() {
+        assert!(matches!(
+            SUT::new("CALL_METHOD;", NetworkID::Mainnet, Vec::new()),
+            Err(CommonError::InvalidTransactionManifest(_))
+        ));
+    }
+
+    #[test]
+    fn address_on_wrong_network_is_invalid() {
+        assert!(matches!(
+            SUT::new(LOCK_FEE, NetworkID::Stokenet, Vec::new()),
+            Err(CommonError::InvalidTransactionManifest(_))
+        ));
+    }
+}
@@ -26,7 +26,7 @@ func test() throws {
 	let initialNameOfFirstAccount = "Alice"
 	// Not created any account yet...
 	assert(!keychain.contains(value: initialNameOfFirstAccount))
-	assert(wallet.profile().networks.count == 0)
+	assert(try! wallet.profile().networks.count == 0)
 	var main0 = try wallet.createAndSaveNewAccount(
 		networkId: .mainnet,
 		name: DisplayName(
@@ -34,10 +34,10 @@ func test() throws {
 		)
 	)
 	assert(main0.networkId == .mainnet)
-	assert(wallet.profile().networks.count == 1)
-	assert(wallet.profile().networks[0].accounts.count == 1)
+	assert(try! wallet.profile().networks.count == 1)
+	assert(try! wallet.profile().networks[0].accounts.count == 1)
 	assert(
-		wallet.profile().networks[0].accounts[0].displayName.value
+		try! wallet.profile().networks[0].accounts[0].displayName.value
 			== initialNameOfFirstAccount
 	)
 	assert(keychain.contains(value: initialNameOfFirstAccount))
@@ -50,11 +50,11 @@ func test() throws {
 	let main0Updated = try wallet.updateAccount(to: main0)
 	assert(main0Updated == main0)
 	assert(
-		wallet.profile().networks[0].accounts[0].displayName.value
+		try! wallet.profile().networks[0].accounts[0].displayName.value
 			== updatedNameOfFirstAccount
 	)
 	assert(
-		wallet.profile().networks[0].accounts[0].appearanceId
+		try! wallet.profile().networks[0].accounts[0].appearanceId
 			== .placeholderOther
 	)
 
@@ -73,7 +73,7 @@ func test() throws {
 			validating: updatedNameOfFirstAccount
 		))
 	assert(
-		wallet.profile().networks[0].accounts[0].displayName.value
+		try! wallet.profile().networks[0].accounts[0].displayName.value
 			== updatedNameOfFirstAccount
 	)
 	assert(
@@ -93,8 +93,8 @@ func test() throws {
 	assert(
 		main0.networkId == main1.networkId
 	)
-	assert(wallet.profile().networks.count == 1)
-	assert(wallet.profile().networks[0].accounts == [main0, main1])
+	assert(try! wallet.profile().networks.count == 1)
+	assert(try! wallet.profile().networks[0].accounts == [main0, main1])
 	print("✨ Successfully created second mainnet account ✅")
 
 	// MARK: ==================================
@@ -106,14 +106,14 @@ func test() throws {
 			validating: testnetAccountName
 		)
 	)
-	assert(wallet.profile().networks.count == 2)
-	assert(wallet.profile().networks[1].accounts == [test0])
+	assert(try! wallet.profile().networks.count == 2)
+	assert(try! wallet.profile().networks[1].accounts == [test0])
 	assert(
-		wallet.profile().networks[1].accounts[0].displayName.value
+		try! wallet.profile().networks[1].accounts[0].displayName.value
 			== testnetAccountName
 	)
 	assert(
-		wallet.profile().networks[1].accounts[0].networkId
+		try! wallet.profile().networks[1].accounts[0].networkId
 			== .stokenet
 	)
 	assert(keychain.contains(value: testnetAccountName))
@@ -76,6 +76,18 @@ mod profile_snapshot_tests {
         .expect("V100 Profile to deserialize");
         assert_json_value_eq_after_roundtrip(&profile, json)
     }
+
+    #[test]
+    fn v100_100_conforms_to_json_schema() {
+        let (_, json) = fixture_and_json::<Profile>(
+            "only_plaintext_profile_snapshot_version_100",
+        )
+        .expect("V100 Profile to deserialize");
+        assert_eq!(
+            profile_snapshot_schema_violations(json.to_string()),
+            Ok(Vec::new())
+        );
+    }
 }
 
 #[cfg(test)]